@@ -0,0 +1,283 @@
+use std::{fmt::Debug, mem};
+
+use super::{Displaced, KeyIndex, Keyed, ScanIndex, Slots};
+
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    referenced: bool,
+}
+
+impl<K, V> Keyed for Entry<K, V> {
+    type Key = K;
+
+    fn key(&self) -> &K {
+        &self.key
+    }
+}
+
+/// `ClockCache` is a fixed-capacity cache using the CLOCK (second chance) eviction algorithm.
+///
+/// Entries are held in a cyclic list of `SIZE` slots - each with a reference bit, which is set whenever the entry is read. When a new key is inserted into a full cache, a hand sweeps around the slots. Clearing the reference bit of each referenced entry it passes, and evicting the first entry which has not been referenced since the hand last passed it.
+///
+/// Keys are located using the index `I` - see [`KeyIndex`].
+///
+/// ```
+/// # use cyclic_data_types::cache::{ClockCache, Displaced};
+/// let mut cache: ClockCache<3, i64, &str> = ClockCache::new();
+///
+/// assert_eq!(cache.insert(1, "one"), None);
+/// assert_eq!(cache.insert(2, "two"), None);
+/// assert_eq!(cache.insert(3, "three"), None);
+///
+/// assert_eq!(cache.get(&1), Some(&"one"));
+/// assert_eq!(cache.get(&3), Some(&"three"));
+///
+/// // 2 is the only entry which has not been referenced
+/// assert_eq!(cache.insert(4, "four"), Some(Displaced::Evicted(2, "two")));
+/// ```
+pub struct ClockCache<const SIZE: usize, K, V, I = ScanIndex>
+where
+    I: KeyIndex<K>,
+{
+    slots: Slots<SIZE, Entry<K, V>, I>,
+    hand: usize,
+}
+
+impl<const SIZE: usize, K, V, I> ClockCache<SIZE, K, V, I>
+where
+    I: KeyIndex<K>,
+{
+    /// Creates an empty cache.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::ClockCache;
+    /// let cache: ClockCache<3, i64, i64> = ClockCache::new();
+    ///
+    /// assert!(cache.is_empty());
+    /// ```
+    ///
+    /// *NOTE:* The index must be able to hold `SIZE` keys - which is checked at compile time.
+    pub fn new() -> Self {
+        Self {
+            slots: Slots::new(),
+            hand: 0,
+        }
+    }
+
+    /// Returns a reference to the value of `key` - setting the entry's reference bit.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::ClockCache;
+    /// let mut cache: ClockCache<3, i64, i64> = ClockCache::new();
+    ///
+    /// assert_eq!(cache.insert(1, 10), None);
+    ///
+    /// assert_eq!(cache.get(&1), Some(&10));
+    /// assert_eq!(cache.get(&2), None);
+    /// ```
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        self.get_mut(key).map(|value| &*value)
+    }
+
+    /// Returns a mutable reference to the value of `key` - setting the entry's reference bit.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::ClockCache;
+    /// let mut cache: ClockCache<3, i64, i64> = ClockCache::new();
+    ///
+    /// assert_eq!(cache.insert(1, 10), None);
+    ///
+    /// if let Some(value) = cache.get_mut(&1) {
+    ///     *value += 1;
+    /// }
+    ///
+    /// assert_eq!(cache.get(&1), Some(&11));
+    /// ```
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let slot = self.slots.find(key)?;
+        let entry = self.slots.get_mut(slot)?;
+
+        entry.referenced = true;
+
+        Some(&mut entry.value)
+    }
+
+    /// Returns a reference to the value of `key` - without setting the entry's reference bit.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::{ClockCache, Displaced};
+    /// let mut cache: ClockCache<1, i64, i64> = ClockCache::new();
+    ///
+    /// assert_eq!(cache.insert(1, 10), None);
+    /// assert_eq!(cache.peek(&1), Some(&10));
+    ///
+    /// assert_eq!(cache.insert(2, 20), Some(Displaced::Evicted(1, 10)));
+    /// ```
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        let slot = self.slots.find(key)?;
+
+        self.slots.get(slot).map(|entry| &entry.value)
+    }
+
+    /// Returns `true` if the cache holds `key` - without setting the entry's reference bit.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::ClockCache;
+    /// let mut cache: ClockCache<3, i64, i64> = ClockCache::new();
+    ///
+    /// assert_eq!(cache.insert(1, 10), None);
+    ///
+    /// assert!(cache.contains_key(&1));
+    /// assert!(!cache.contains_key(&2));
+    /// ```
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.slots.find(key).is_some()
+    }
+
+    /// Inserts a value into the cache.
+    /// * If `key` is already in the cache - its value is replaced in place and the entry's reference bit is set.
+    /// * If the cache is full - the hand sweeps around the cache to evict an entry.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::{ClockCache, Displaced};
+    /// let mut cache: ClockCache<2, i64, i64> = ClockCache::new();
+    ///
+    /// assert_eq!(cache.insert(1, 10), None);
+    /// assert_eq!(cache.insert(1, 11), Some(Displaced::Replaced(10)));
+    ///
+    /// assert_eq!(cache.insert(2, 20), None);
+    /// assert_eq!(cache.insert(3, 30), Some(Displaced::Evicted(2, 20)));
+    /// ```
+    ///
+    /// # Returns
+    /// * `None` if the entry was inserted into a vacant slot
+    /// * [`Displaced::Replaced`] with the previous value if `key` was already in the cache
+    /// * [`Displaced::Evicted`] with the evicted entry if the cache was full
+    pub fn insert(&mut self, key: K, value: V) -> Option<Displaced<K, V>> {
+        if let Some(slot) = self.slots.find(&key) {
+            let entry = self.slots.get_mut(slot)?;
+
+            entry.referenced = true;
+
+            return Some(Displaced::Replaced(mem::replace(&mut entry.value, value)));
+        }
+
+        if SIZE == 0 {
+            return Some(Displaced::Evicted(key, value));
+        }
+
+        let evicted = if self.slots.len() == SIZE {
+            let slot = self.sweep();
+
+            self.slots.take(slot)
+        } else {
+            None
+        };
+
+        self.slots.insert(Entry {
+            key,
+            value,
+            referenced: false,
+        });
+
+        evicted.map(|entry| Displaced::Evicted(entry.key, entry.value))
+    }
+
+    /// Removes `key` from the cache - returning its value.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::ClockCache;
+    /// let mut cache: ClockCache<3, i64, i64> = ClockCache::new();
+    ///
+    /// assert_eq!(cache.insert(1, 10), None);
+    ///
+    /// assert_eq!(cache.remove(&1), Some(10));
+    /// assert_eq!(cache.remove(&1), None);
+    /// ```
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let slot = self.slots.find(key)?;
+
+        self.slots.take(slot).map(|entry| entry.value)
+    }
+
+    /// Returns the number of entries in the cache.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::ClockCache;
+    /// let mut cache: ClockCache<2, i64, i64> = ClockCache::new();
+    ///
+    /// assert_eq!(cache.insert(1, 10), None);
+    /// assert_eq!(cache.insert(2, 20), None);
+    /// assert!(cache.insert(3, 30).is_some());
+    ///
+    /// assert_eq!(cache.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns `true` if the cache holds no entries.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::ClockCache;
+    /// let cache: ClockCache<2, i64, i64> = ClockCache::new();
+    ///
+    /// assert!(cache.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes every entry from the cache.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::ClockCache;
+    /// let mut cache: ClockCache<2, i64, i64> = ClockCache::new();
+    ///
+    /// assert_eq!(cache.insert(1, 10), None);
+    /// cache.clear();
+    ///
+    /// assert!(cache.is_empty());
+    /// assert_eq!(cache.get(&1), None);
+    /// ```
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.hand = 0;
+    }
+
+    /// Sweeps the hand around a full cache - returning the first slot whose entry has not been referenced
+    fn sweep(&mut self) -> usize {
+        loop {
+            let slot = self.hand;
+            self.hand = (self.hand + 1) % SIZE;
+
+            match self.slots.get_mut(slot) {
+                Some(entry) if entry.referenced => entry.referenced = false,
+                _ => return slot,
+            }
+        }
+    }
+}
+
+impl<const SIZE: usize, K, V, I> Default for ClockCache<SIZE, K, V, I>
+where
+    I: KeyIndex<K>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const SIZE: usize, K, V, I> Debug for ClockCache<SIZE, K, V, I>
+where
+    K: Debug,
+    V: Debug,
+    I: KeyIndex<K>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.slots.iter().map(|entry| (&entry.key, &entry.value)))
+            .finish()
+    }
+}
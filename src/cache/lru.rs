@@ -0,0 +1,413 @@
+use std::{fmt::Debug, mem};
+
+use super::{Displaced, KeyIndex, Keyed, ScanIndex, Slots};
+
+struct Node<K, V> {
+    key: K,
+    value: V,
+    prev: usize,
+    next: usize,
+}
+
+impl<K, V> Keyed for Node<K, V> {
+    type Key = K;
+
+    fn key(&self) -> &K {
+        &self.key
+    }
+}
+
+/// `LruCache` is a fixed-capacity cache which evicts the least recently used entry.
+///
+/// Entries are stored inline in `SIZE` slots, and are linked into a cyclic recency ring using intrusive `prev`/`next` indices. The head of the ring is the most recently used entry - so the entry before the head is the least recently used. Therefore, every operation is O(1) (on top of locating the key with the index `I` - see [`KeyIndex`]), and no allocation is needed when using a [`ScanIndex`].
+///
+/// ```
+/// # use cyclic_data_types::cache::{Displaced, LruCache};
+/// let mut cache: LruCache<2, i64, &str> = LruCache::new();
+///
+/// assert_eq!(cache.put(1, "one"), None);
+/// assert_eq!(cache.put(2, "two"), None);
+///
+/// assert_eq!(cache.get(&1), Some(&"one"));
+///
+/// assert_eq!(cache.put(3, "three"), Some(Displaced::Evicted(2, "two")));
+/// assert_eq!(cache.iter().map(|(key, _)| *key).collect::<Vec<i64>>(), vec![3, 1]);
+/// ```
+pub struct LruCache<const SIZE: usize, K, V, I = ScanIndex>
+where
+    I: KeyIndex<K>,
+{
+    slots: Slots<SIZE, Node<K, V>, I>,
+    head: Option<usize>,
+}
+
+impl<const SIZE: usize, K, V, I> LruCache<SIZE, K, V, I>
+where
+    I: KeyIndex<K>,
+{
+    /// Creates an empty cache.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::LruCache;
+    /// let cache: LruCache<3, i64, i64> = LruCache::new();
+    ///
+    /// assert!(cache.is_empty());
+    /// ```
+    ///
+    /// *NOTE:* The index must be able to hold `SIZE` keys - which is checked at compile time.
+    pub fn new() -> Self {
+        Self {
+            slots: Slots::new(),
+            head: None,
+        }
+    }
+
+    /// Returns a reference to the value of `key` - marking the entry as the most recently used.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::{Displaced, LruCache};
+    /// let mut cache: LruCache<2, i64, i64> = LruCache::new();
+    ///
+    /// assert_eq!(cache.put(1, 10), None);
+    /// assert_eq!(cache.put(2, 20), None);
+    ///
+    /// assert_eq!(cache.get(&1), Some(&10));
+    /// assert_eq!(cache.put(3, 30), Some(Displaced::Evicted(2, 20)));
+    /// ```
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        self.get_mut(key).map(|value| &*value)
+    }
+
+    /// Returns a mutable reference to the value of `key` - marking the entry as the most recently used.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::LruCache;
+    /// let mut cache: LruCache<2, i64, i64> = LruCache::new();
+    ///
+    /// assert_eq!(cache.put(1, 10), None);
+    ///
+    /// if let Some(value) = cache.get_mut(&1) {
+    ///     *value += 1;
+    /// }
+    ///
+    /// assert_eq!(cache.peek(&1), Some(&11));
+    /// ```
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let slot = self.slots.find(key)?;
+
+        self.unlink(slot);
+        self.link_front(slot);
+
+        self.slots.get_mut(slot).map(|node| &mut node.value)
+    }
+
+    /// Returns a reference to the value of `key` - without changing the recency of the entry.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::{Displaced, LruCache};
+    /// let mut cache: LruCache<2, i64, i64> = LruCache::new();
+    ///
+    /// assert_eq!(cache.put(1, 10), None);
+    /// assert_eq!(cache.put(2, 20), None);
+    ///
+    /// assert_eq!(cache.peek(&1), Some(&10));
+    /// assert_eq!(cache.put(3, 30), Some(Displaced::Evicted(1, 10)));
+    /// ```
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        let slot = self.slots.find(key)?;
+
+        self.slots.get(slot).map(|node| &node.value)
+    }
+
+    /// Returns `true` if the cache holds `key` - without changing the recency of the entry.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::LruCache;
+    /// let mut cache: LruCache<2, i64, i64> = LruCache::new();
+    ///
+    /// assert_eq!(cache.put(1, 10), None);
+    ///
+    /// assert!(cache.contains_key(&1));
+    /// assert!(!cache.contains_key(&2));
+    /// ```
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.slots.find(key).is_some()
+    }
+
+    /// Puts a value into the cache - as the most recently used entry.
+    /// * If `key` is already in the cache - its value is replaced in place.
+    /// * If the cache is full - the least recently used entry is evicted.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::{Displaced, LruCache};
+    /// let mut cache: LruCache<2, i64, i64> = LruCache::new();
+    ///
+    /// assert_eq!(cache.put(1, 10), None);
+    /// assert_eq!(cache.put(2, 20), None);
+    /// assert_eq!(cache.put(1, 11), Some(Displaced::Replaced(10)));
+    ///
+    /// assert_eq!(cache.put(3, 30), Some(Displaced::Evicted(2, 20)));
+    /// ```
+    ///
+    /// # Returns
+    /// * `None` if the entry was put into a vacant slot
+    /// * [`Displaced::Replaced`] with the previous value if `key` was already in the cache
+    /// * [`Displaced::Evicted`] with the evicted entry if the cache was full
+    pub fn put(&mut self, key: K, value: V) -> Option<Displaced<K, V>> {
+        if let Some(slot) = self.slots.find(&key) {
+            self.unlink(slot);
+            self.link_front(slot);
+
+            let node = self.slots.get_mut(slot)?;
+
+            return Some(Displaced::Replaced(mem::replace(&mut node.value, value)));
+        }
+
+        if SIZE == 0 {
+            return Some(Displaced::Evicted(key, value));
+        }
+
+        let evicted = if self.slots.len() == SIZE {
+            self.pop_lru()
+        } else {
+            None
+        };
+
+        // the links are set once the node is linked into the recency ring
+        let slot = self.slots.insert(Node {
+            key,
+            value,
+            prev: 0,
+            next: 0,
+        });
+        self.link_front(slot);
+
+        evicted.map(|(key, value)| Displaced::Evicted(key, value))
+    }
+
+    /// Removes the least recently used entry from the cache.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::LruCache;
+    /// let mut cache: LruCache<3, i64, i64> = LruCache::new();
+    ///
+    /// assert_eq!(cache.put(1, 10), None);
+    /// assert_eq!(cache.put(2, 20), None);
+    ///
+    /// assert_eq!(cache.pop_lru(), Some((1, 10)));
+    /// assert_eq!(cache.pop_lru(), Some((2, 20)));
+    /// assert_eq!(cache.pop_lru(), None);
+    /// ```
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        let head = self.head?;
+        let lru = self.slots.get(head)?.prev;
+
+        self.take(lru).map(|node| (node.key, node.value))
+    }
+
+    /// Removes `key` from the cache - returning its value.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::LruCache;
+    /// let mut cache: LruCache<3, i64, i64> = LruCache::new();
+    ///
+    /// assert_eq!(cache.put(1, 10), None);
+    ///
+    /// assert_eq!(cache.remove(&1), Some(10));
+    /// assert_eq!(cache.remove(&1), None);
+    /// ```
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let slot = self.slots.find(key)?;
+
+        self.take(slot).map(|node| node.value)
+    }
+
+    /// Returns an iterator over the entries of the cache - from the most recently used to the least recently used.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::LruCache;
+    /// let mut cache: LruCache<3, i64, i64> = LruCache::new();
+    ///
+    /// assert_eq!(cache.put(1, 10), None);
+    /// assert_eq!(cache.put(2, 20), None);
+    /// assert_eq!(cache.put(3, 30), None);
+    ///
+    /// assert_eq!(cache.get(&2), Some(&20));
+    ///
+    /// assert_eq!(
+    ///     cache.iter().map(|(key, value)| (*key, *value)).collect::<Vec<(i64, i64)>>(),
+    ///     vec![(2, 20), (3, 30), (1, 10)]
+    /// );
+    /// ```
+    pub fn iter(&self) -> LruIter<'_, SIZE, K, V, I> {
+        LruIter {
+            cache: self,
+            next: self.head,
+            remaining: self.len(),
+        }
+    }
+
+    /// Returns the number of entries in the cache.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::LruCache;
+    /// let mut cache: LruCache<2, i64, i64> = LruCache::new();
+    ///
+    /// assert_eq!(cache.put(1, 10), None);
+    /// assert_eq!(cache.put(2, 20), None);
+    /// assert!(cache.put(3, 30).is_some());
+    ///
+    /// assert_eq!(cache.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns `true` if the cache holds no entries.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::LruCache;
+    /// let cache: LruCache<2, i64, i64> = LruCache::new();
+    ///
+    /// assert!(cache.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes every entry from the cache.
+    ///
+    /// ```
+    /// # use cyclic_data_types::cache::LruCache;
+    /// let mut cache: LruCache<2, i64, i64> = LruCache::new();
+    ///
+    /// assert_eq!(cache.put(1, 10), None);
+    /// cache.clear();
+    ///
+    /// assert!(cache.is_empty());
+    /// assert_eq!(cache.get(&1), None);
+    /// ```
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.head = None;
+    }
+
+    /// Returns the `(prev, next)` links of the node in `slot`
+    fn links(&self, slot: usize) -> (usize, usize) {
+        self.slots
+            .get(slot)
+            .map_or((slot, slot), |node| (node.prev, node.next))
+    }
+
+    /// Sets the `(prev, next)` links of the node in `slot`
+    fn set_links(&mut self, slot: usize, prev: Option<usize>, next: Option<usize>) {
+        if let Some(node) = self.slots.get_mut(slot) {
+            if let Some(prev) = prev {
+                node.prev = prev;
+            }
+            if let Some(next) = next {
+                node.next = next;
+            }
+        }
+    }
+
+    /// Removes the node in `slot` from the recency ring
+    fn unlink(&mut self, slot: usize) {
+        let (prev, next) = self.links(slot);
+
+        if next == slot {
+            self.head = None;
+            return;
+        }
+
+        self.set_links(prev, None, Some(next));
+        self.set_links(next, Some(prev), None);
+
+        if self.head == Some(slot) {
+            self.head = Some(next);
+        }
+    }
+
+    /// Inserts the node in `slot` into the recency ring - as the most recently used node
+    fn link_front(&mut self, slot: usize) {
+        let Some(head) = self.head else {
+            self.set_links(slot, Some(slot), Some(slot));
+            self.head = Some(slot);
+            return;
+        };
+
+        let (tail, _) = self.links(head);
+
+        self.set_links(slot, Some(tail), Some(head));
+        self.set_links(tail, None, Some(slot));
+        self.set_links(head, Some(slot), None);
+
+        self.head = Some(slot);
+    }
+
+    /// Removes the node in `slot` from the cache - freeing the slot
+    fn take(&mut self, slot: usize) -> Option<Node<K, V>> {
+        self.unlink(slot);
+
+        self.slots.take(slot)
+    }
+}
+
+impl<const SIZE: usize, K, V, I> Default for LruCache<SIZE, K, V, I>
+where
+    I: KeyIndex<K>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const SIZE: usize, K, V, I> Debug for LruCache<SIZE, K, V, I>
+where
+    K: Debug,
+    V: Debug,
+    I: KeyIndex<K>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// `LruIter` iterates over the entries of an [`LruCache`] - from the most recently used to the least recently used.
+pub struct LruIter<'a, const SIZE: usize, K, V, I>
+where
+    I: KeyIndex<K>,
+{
+    cache: &'a LruCache<SIZE, K, V, I>,
+    next: Option<usize>,
+    remaining: usize,
+}
+
+impl<'a, const SIZE: usize, K, V, I> Iterator for LruIter<'a, SIZE, K, V, I>
+where
+    I: KeyIndex<K>,
+{
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = self.cache.slots.get(self.next?)?;
+
+        self.next = Some(node.next);
+        self.remaining -= 1;
+
+        Some((&node.key, &node.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<const SIZE: usize, K, V, I> ExactSizeIterator for LruIter<'_, SIZE, K, V, I> where
+    I: KeyIndex<K>
+{
+}
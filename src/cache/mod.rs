@@ -0,0 +1,404 @@
+//! The cache module contains fixed-capacity caches. Which store their entries in a cyclic list - evicting an entry when a new key is inserted into a full cache.
+//!
+//! * [`ClockCache`] approximates least recently used eviction - with a hand sweeping around the entries, giving each referenced entry a second chance.
+//! * [`LruCache`] evicts the exact least recently used entry - keeping its entries in a recency ring.
+//!
+//! Keys are located using a [`KeyIndex`].
+//! * [`ScanIndex`] compares the key against every entry of the cache - so needs no memory beyond the cache itself, and is fast for very small caches.
+//! * [`ProbeIndex`] is an open addressing hash table of `N` entries, resolving collisions by linear probing - for fast lookup without allocating.
+//! * `HashIndex` keeps a std `HashMap` from key to entry - for O(1) lookup in larger caches. It is only available with the `hash_index` feature (enabled by default).
+//!
+//! ```
+//! # use cyclic_data_types::cache::{ClockCache, Displaced};
+//! let mut cache: ClockCache<2, &str, i64> = ClockCache::new();
+//!
+//! assert_eq!(cache.insert("a", 1), None);
+//! assert_eq!(cache.insert("b", 2), None);
+//!
+//! // "a" is given a second chance
+//! assert_eq!(cache.get(&"a"), Some(&1));
+//!
+//! assert_eq!(cache.insert("c", 3), Some(Displaced::Evicted("b", 2)));
+//! assert_eq!(cache.insert("a", 10), Some(Displaced::Replaced(1)));
+//! ```
+
+#[cfg(feature = "hash_index")]
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use crate::list::List;
+
+pub use self::clock::ClockCache;
+pub use self::lru::{LruCache, LruIter};
+
+mod clock;
+mod lru;
+
+#[cfg(test)]
+mod tests;
+
+/// `Displaced` is the entry pushed out of a cache by inserting a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Displaced<K, V> {
+    /// The key was already in the cache - and its previous value was replaced in place
+    Replaced(V),
+    /// The cache was full - and this entry was evicted to make room for the key
+    Evicted(K, V),
+}
+
+/// `Keyed` is implemented by the entries a cache stores in its slots
+trait Keyed {
+    /// The type of key the entry is stored under
+    type Key;
+
+    /// Returns the key of the entry
+    fn key(&self) -> &Self::Key;
+}
+
+/// `Slots` holds the entries of a cache in `SIZE` slots - keeping track of the vacant slots, and of the slot holding each key using the index `I`.
+struct Slots<const SIZE: usize, E, I>
+where
+    E: Keyed,
+    I: KeyIndex<E::Key>,
+{
+    entries: List<SIZE, Option<E>, false>,
+    free: List<SIZE, usize, false>,
+    index: I,
+}
+
+impl<const SIZE: usize, E, I> Slots<SIZE, E, I>
+where
+    E: Keyed,
+    I: KeyIndex<E::Key>,
+{
+    /// Creates `SIZE` vacant slots
+    fn new() -> Self {
+        const { assert!(I::CAPACITY >= SIZE, "the index cannot hold SIZE keys") };
+
+        let mut slots = Self {
+            entries: List::empty(),
+            free: List::empty(),
+            index: I::default(),
+        };
+
+        for slot in 0..SIZE {
+            slots
+                .entries
+                .push_back(None)
+                .expect("the cache holds exactly SIZE slots");
+            slots
+                .free
+                .push_back(slot)
+                .expect("the cache holds exactly SIZE slots");
+        }
+
+        slots
+    }
+
+    /// Returns the number of occupied slots
+    fn len(&self) -> usize {
+        SIZE - self.free.len()
+    }
+
+    /// Returns the slot holding `key`
+    fn find(&self, key: &E::Key) -> Option<usize> {
+        self.index
+            .find(key, SIZE, |slot| self.get(slot).map(Keyed::key))
+    }
+
+    /// Returns the entry in `slot`
+    fn get(&self, slot: usize) -> Option<&E> {
+        self.entries[slot].as_ref()
+    }
+
+    /// Returns the entry in `slot` mutably
+    fn get_mut(&mut self, slot: usize) -> Option<&mut E> {
+        self.entries[slot].as_mut()
+    }
+
+    /// Returns an iterator over the entries - in the order of their slots
+    fn iter(&self) -> impl Iterator<Item = &E> {
+        self.entries.iter().flatten()
+    }
+
+    /// Writes `entry` to the first vacant slot - returning the slot
+    ///
+    /// *NOTE:* Panics if every slot is occupied - so an entry must be evicted from a full cache first.
+    fn insert(&mut self, entry: E) -> usize {
+        let slot = self
+            .free
+            .remove_front()
+            .expect("an entry has been evicted if the cache was full");
+
+        self.index.insert(entry.key(), slot);
+        self.entries[slot] = Some(entry);
+
+        slot
+    }
+
+    /// Moves the entry out of `slot` - making the slot vacant
+    fn take(&mut self, slot: usize) -> Option<E> {
+        let entry = self.entries[slot].take()?;
+
+        self.index.remove(entry.key(), slot);
+        self.free
+            .push_back(slot)
+            .expect("a slot is only freed once");
+
+        Some(entry)
+    }
+
+    /// Makes every slot vacant
+    fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// `KeyIndex` locates the slot holding a key in a cache.
+///
+/// The cache tells the index every time a key is written to or removed from a slot - so an index can either keep its own mapping from key to slot, or search the keys held by the cache.
+pub trait KeyIndex<K>: Default {
+    /// The maximum number of keys the index can hold. A cache holding more than `CAPACITY` entries fails to compile.
+    const CAPACITY: usize = usize::MAX;
+
+    /// Returns the slot holding `key`. Where `key_at` returns the key held by each of the cache's `slots` slots - or `None` if the slot is vacant.
+    fn find<'a>(
+        &self,
+        key: &K,
+        slots: usize,
+        key_at: impl Fn(usize) -> Option<&'a K>,
+    ) -> Option<usize>
+    where
+        K: 'a;
+
+    /// Records that `key` has been written to `slot`
+    fn insert(&mut self, key: &K, slot: usize);
+
+    /// Records that `key` has been removed from `slot`
+    fn remove(&mut self, key: &K, slot: usize);
+
+    /// Records that every key has been removed from the cache
+    fn clear(&mut self);
+}
+
+/// `ScanIndex` finds a key by comparing it against every key in the cache. Therefore, lookups are O(`SIZE`) - but need no memory, nor any bound on the key beyond [`Eq`].
+///
+/// *NOTE:* `ScanIndex` keeps no state of its own. Use a [`ProbeIndex`] once the cache is large enough for the scan to matter.
+///
+/// ```
+/// # use cyclic_data_types::cache::{ClockCache, ScanIndex};
+/// let mut cache: ClockCache<4, i64, i64, ScanIndex> = ClockCache::new();
+///
+/// assert_eq!(cache.insert(1, 10), None);
+///
+/// assert_eq!(cache.get(&1), Some(&10));
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ScanIndex;
+
+impl<K: Eq> KeyIndex<K> for ScanIndex {
+    fn find<'a>(
+        &self,
+        key: &K,
+        slots: usize,
+        key_at: impl Fn(usize) -> Option<&'a K>,
+    ) -> Option<usize>
+    where
+        K: 'a,
+    {
+        (0..slots).find(|&slot| key_at(slot) == Some(key))
+    }
+
+    fn insert(&mut self, _key: &K, _slot: usize) {}
+
+    fn remove(&mut self, _key: &K, _slot: usize) {}
+
+    fn clear(&mut self) {}
+}
+
+/// `ProbeIndex` is an open addressing hash table of `N` entries - each holding the hash of a key and the slot holding it. Collisions are resolved by linear probing, so a key is found by walking forward from the entry its hash maps to.
+///
+/// Keys are hashed with FNV-1a - which needs no random state. Therefore, the index needs no allocation, nor anything beyond `core`.
+///
+/// One entry is always kept vacant - so that probing for a missing key ends. As a result, `N` must be greater than the `SIZE` of the cache, which is checked at compile time. Probing stays short while the table is at most half full - so an `N` of twice the `SIZE` is a good default.
+///
+/// ```
+/// # use cyclic_data_types::cache::{ClockCache, ProbeIndex};
+/// let mut cache: ClockCache<4, i64, i64, ProbeIndex<8>> = ClockCache::new();
+///
+/// assert_eq!(cache.insert(1, 10), None);
+///
+/// assert_eq!(cache.get(&1), Some(&10));
+/// ```
+///
+/// ```compile_fail
+/// # use cyclic_data_types::cache::{ClockCache, ProbeIndex};
+/// let cache: ClockCache<4, i64, i64, ProbeIndex<4>> = ClockCache::new();
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ProbeIndex<const N: usize> {
+    table: [Option<(u64, usize)>; N],
+}
+
+impl<const N: usize> ProbeIndex<N> {
+    /// Returns the FNV-1a hash of `key`
+    fn hash<K: Hash>(key: &K) -> u64 {
+        let mut hasher = Fnv::default();
+        key.hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    /// Returns the entry of the table `hash` maps to
+    fn home(hash: u64) -> usize {
+        (hash % N as u64) as usize
+    }
+
+    /// Returns every entry of the table - in the order they are probed for `hash`
+    fn probe(hash: u64) -> impl Iterator<Item = usize> {
+        let home = Self::home(hash);
+
+        (0..N).map(move |offset| (home + offset) % N)
+    }
+}
+
+impl<const N: usize> Default for ProbeIndex<N> {
+    fn default() -> Self {
+        Self { table: [None; N] }
+    }
+}
+
+impl<K: Hash + Eq, const N: usize> KeyIndex<K> for ProbeIndex<N> {
+    const CAPACITY: usize = N.saturating_sub(1);
+
+    fn find<'a>(
+        &self,
+        key: &K,
+        _slots: usize,
+        key_at: impl Fn(usize) -> Option<&'a K>,
+    ) -> Option<usize>
+    where
+        K: 'a,
+    {
+        let hash = Self::hash(key);
+
+        Self::probe(hash)
+            .map_while(|entry| self.table[entry])
+            .find(|&(other, slot)| other == hash && key_at(slot) == Some(key))
+            .map(|(_, slot)| slot)
+    }
+
+    fn insert(&mut self, key: &K, slot: usize) {
+        let hash = Self::hash(key);
+
+        if let Some(entry) = Self::probe(hash).find(|&entry| self.table[entry].is_none()) {
+            self.table[entry] = Some((hash, slot));
+        }
+    }
+
+    fn remove(&mut self, key: &K, slot: usize) {
+        let hash = Self::hash(key);
+
+        let Some(mut vacant) = Self::probe(hash)
+            .take_while(|&entry| self.table[entry].is_some())
+            .find(|&entry| self.table[entry] == Some((hash, slot)))
+        else {
+            return;
+        };
+
+        self.table[vacant] = None;
+
+        // shift back every following entry which can no longer be reached by probing past the vacant entry
+        let mut entry = (vacant + 1) % N;
+        while let Some((other, _)) = self.table[entry] {
+            let home = Self::home(other);
+
+            if (vacant + N - home) % N < (entry + N - home) % N {
+                self.table[vacant] = self.table[entry].take();
+                vacant = entry;
+            }
+
+            entry = (entry + 1) % N;
+        }
+    }
+
+    fn clear(&mut self) {
+        self.table = [None; N];
+    }
+}
+
+/// `Fnv` is the FNV-1a hasher
+struct Fnv(u64);
+
+impl Default for Fnv {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+/// `HashIndex` keeps a [`HashMap`] from each key to its slot. Therefore, lookups are O(1) - at the cost of an allocation, and a clone of each key.
+///
+/// *NOTE:* `HashIndex` requires the `hash_index` feature - which is enabled by default.
+///
+/// ```
+/// # use cyclic_data_types::cache::{ClockCache, HashIndex};
+/// let mut cache: ClockCache<64, String, i64, HashIndex<String>> = ClockCache::new();
+///
+/// assert_eq!(cache.insert(String::from("key"), 10), None);
+///
+/// assert_eq!(cache.get(&String::from("key")), Some(&10));
+/// ```
+#[cfg(feature = "hash_index")]
+#[derive(Debug, Clone)]
+pub struct HashIndex<K> {
+    slots: HashMap<K, usize>,
+}
+
+#[cfg(feature = "hash_index")]
+impl<K> Default for HashIndex<K> {
+    fn default() -> Self {
+        Self {
+            slots: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(feature = "hash_index")]
+impl<K: Hash + Eq + Clone> KeyIndex<K> for HashIndex<K> {
+    fn find<'a>(
+        &self,
+        key: &K,
+        _slots: usize,
+        _key_at: impl Fn(usize) -> Option<&'a K>,
+    ) -> Option<usize>
+    where
+        K: 'a,
+    {
+        self.slots.get(key).copied()
+    }
+
+    fn insert(&mut self, key: &K, slot: usize) {
+        self.slots.insert(key.clone(), slot);
+    }
+
+    fn remove(&mut self, key: &K, _slot: usize) {
+        self.slots.remove(key);
+    }
+
+    fn clear(&mut self) {
+        self.slots.clear();
+    }
+}
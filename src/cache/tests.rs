@@ -0,0 +1,351 @@
+mod clock_cache {
+    #[cfg(feature = "hash_index")]
+    use crate::cache::HashIndex;
+    use crate::cache::{ClockCache, Displaced, KeyIndex, ProbeIndex, ScanIndex};
+
+    const SIZE: usize = 4;
+
+    fn second_chance<I: KeyIndex<i64>>() {
+        let mut cache: ClockCache<SIZE, i64, i64, I> = ClockCache::new();
+
+        for key in 0..4 {
+            assert_eq!(cache.insert(key, key * 10), None);
+        }
+
+        assert_eq!(cache.get(&0), Some(&0));
+        assert_eq!(cache.get(&2), Some(&20));
+
+        // the hand clears 0 and evicts 1
+        assert_eq!(cache.insert(4, 40), Some(Displaced::Evicted(1, 10)));
+        // the hand clears 2 and evicts 3
+        assert_eq!(cache.insert(5, 50), Some(Displaced::Evicted(3, 30)));
+        // the hand wraps around to 0 - which has been cleared
+        assert_eq!(cache.insert(6, 60), Some(Displaced::Evicted(0, 0)));
+
+        assert_eq!(cache.len(), SIZE);
+        for key in [2, 4, 5, 6] {
+            assert!(cache.contains_key(&key));
+        }
+    }
+
+    fn every_entry_referenced<I: KeyIndex<i64>>() {
+        let mut cache: ClockCache<SIZE, i64, i64, I> = ClockCache::new();
+
+        for key in 0..4 {
+            assert_eq!(cache.insert(key, key), None);
+            assert_eq!(cache.get(&key), Some(&key));
+        }
+
+        // a full sweep clears every bit - evicting the entry under the hand
+        assert_eq!(cache.insert(4, 4), Some(Displaced::Evicted(0, 0)));
+        assert_eq!(cache.insert(5, 5), Some(Displaced::Evicted(1, 1)));
+    }
+
+    fn remove<I: KeyIndex<i64>>() {
+        let mut cache: ClockCache<SIZE, i64, i64, I> = ClockCache::new();
+
+        for key in 0..4 {
+            assert_eq!(cache.insert(key, key), None);
+        }
+
+        assert_eq!(cache.remove(&1), Some(1));
+        assert_eq!(cache.remove(&1), None);
+        assert_eq!(cache.len(), 3);
+
+        // the removed slot is reused before evicting
+        assert_eq!(cache.insert(7, 7), None);
+        assert_eq!(cache.peek(&7), Some(&7));
+
+        assert_eq!(cache.insert(8, 8), Some(Displaced::Evicted(0, 0)));
+        assert_eq!(cache.peek(&0), None);
+    }
+
+    fn replace<I: KeyIndex<i64>>() {
+        let mut cache: ClockCache<SIZE, i64, i64, I> = ClockCache::new();
+
+        for key in 0..4 {
+            assert_eq!(cache.insert(key, key), None);
+        }
+
+        assert_eq!(cache.insert(0, 100), Some(Displaced::Replaced(0)));
+        assert_eq!(cache.len(), SIZE);
+
+        // replacing sets the reference bit
+        assert_eq!(cache.insert(4, 4), Some(Displaced::Evicted(1, 1)));
+        assert_eq!(cache.peek(&0), Some(&100));
+    }
+
+    fn clear<I: KeyIndex<i64>>() {
+        let mut cache: ClockCache<SIZE, i64, i64, I> = ClockCache::new();
+
+        for key in 0..6 {
+            cache.insert(key, key);
+        }
+
+        cache.clear();
+
+        assert!(cache.is_empty());
+        for key in 0..6 {
+            assert!(!cache.contains_key(&key));
+        }
+
+        for key in 0..4 {
+            assert_eq!(cache.insert(key, key), None);
+        }
+    }
+
+    macro_rules! index_tests {
+        ($($index:ident => $ty:ty),*) => {
+            $(
+                mod $index {
+                    use super::*;
+
+                    #[test]
+                    fn second_chance() {
+                        super::second_chance::<$ty>();
+                    }
+
+                    #[test]
+                    fn every_entry_referenced() {
+                        super::every_entry_referenced::<$ty>();
+                    }
+
+                    #[test]
+                    fn remove() {
+                        super::remove::<$ty>();
+                    }
+
+                    #[test]
+                    fn replace() {
+                        super::replace::<$ty>();
+                    }
+
+                    #[test]
+                    fn clear() {
+                        super::clear::<$ty>();
+                    }
+                }
+            )*
+        };
+    }
+
+    index_tests!(scan => ScanIndex, probe => ProbeIndex<8>);
+    #[cfg(feature = "hash_index")]
+    index_tests!(hash => HashIndex<i64>);
+
+    #[test]
+    fn zero_size() {
+        let mut cache: ClockCache<0, i64, i64> = ClockCache::new();
+
+        assert_eq!(cache.insert(1, 1), Some(Displaced::Evicted(1, 1)));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn debug() {
+        let mut cache: ClockCache<SIZE, i64, i64> = ClockCache::new();
+
+        cache.insert(1, 10);
+
+        assert_eq!(format!("{cache:?}"), "{1: 10}");
+    }
+}
+
+mod lru_cache {
+    #[cfg(feature = "hash_index")]
+    use crate::cache::HashIndex;
+    use crate::cache::{Displaced, KeyIndex, LruCache, ProbeIndex, ScanIndex};
+
+    const SIZE: usize = 4;
+
+    fn keys<I: KeyIndex<i64>>(cache: &LruCache<SIZE, i64, i64, I>) -> Vec<i64> {
+        cache.iter().map(|(key, _)| *key).collect()
+    }
+
+    fn recency<I: KeyIndex<i64>>() {
+        let mut cache: LruCache<SIZE, i64, i64, I> = LruCache::new();
+
+        for key in 0..4 {
+            assert_eq!(cache.put(key, key * 10), None);
+        }
+        assert_eq!(keys(&cache), vec![3, 2, 1, 0]);
+
+        assert_eq!(cache.get(&0), Some(&0));
+        assert_eq!(cache.get(&2), Some(&20));
+        assert_eq!(cache.peek(&1), Some(&10));
+        assert_eq!(keys(&cache), vec![2, 0, 3, 1]);
+
+        assert_eq!(cache.put(4, 40), Some(Displaced::Evicted(1, 10)));
+        assert_eq!(cache.put(5, 50), Some(Displaced::Evicted(3, 30)));
+        assert_eq!(keys(&cache), vec![5, 4, 2, 0]);
+        assert_eq!(cache.iter().len(), SIZE);
+    }
+
+    fn replace<I: KeyIndex<i64>>() {
+        let mut cache: LruCache<SIZE, i64, i64, I> = LruCache::new();
+
+        for key in 0..4 {
+            assert_eq!(cache.put(key, key), None);
+        }
+
+        assert_eq!(cache.put(0, 100), Some(Displaced::Replaced(0)));
+        assert_eq!(cache.len(), SIZE);
+        assert_eq!(keys(&cache), vec![0, 3, 2, 1]);
+
+        assert_eq!(cache.put(4, 4), Some(Displaced::Evicted(1, 1)));
+        assert_eq!(cache.peek(&0), Some(&100));
+    }
+
+    fn pop_and_remove<I: KeyIndex<i64>>() {
+        let mut cache: LruCache<SIZE, i64, i64, I> = LruCache::new();
+
+        for key in 0..4 {
+            assert_eq!(cache.put(key, key), None);
+        }
+
+        assert_eq!(cache.remove(&2), Some(2));
+        assert_eq!(cache.remove(&2), None);
+        assert_eq!(keys(&cache), vec![3, 1, 0]);
+
+        // removing the head and the tail
+        assert_eq!(cache.remove(&3), Some(3));
+        assert_eq!(cache.pop_lru(), Some((0, 0)));
+        assert_eq!(keys(&cache), vec![1]);
+
+        assert_eq!(cache.pop_lru(), Some((1, 1)));
+        assert_eq!(cache.pop_lru(), None);
+        assert!(cache.is_empty());
+
+        // freed slots are reused
+        for key in 4..8 {
+            assert_eq!(cache.put(key, key), None);
+        }
+        assert_eq!(keys(&cache), vec![7, 6, 5, 4]);
+    }
+
+    fn clear<I: KeyIndex<i64>>() {
+        let mut cache: LruCache<SIZE, i64, i64, I> = LruCache::new();
+
+        for key in 0..6 {
+            cache.put(key, key);
+        }
+
+        cache.clear();
+
+        assert!(cache.is_empty());
+        assert_eq!(cache.iter().next(), None);
+        for key in 0..6 {
+            assert!(!cache.contains_key(&key));
+        }
+
+        for key in 0..4 {
+            assert_eq!(cache.put(key, key), None);
+        }
+    }
+
+    macro_rules! index_tests {
+        ($($index:ident => $ty:ty),*) => {
+            $(
+                mod $index {
+                    use super::*;
+
+                    #[test]
+                    fn recency() {
+                        super::recency::<$ty>();
+                    }
+
+                    #[test]
+                    fn replace() {
+                        super::replace::<$ty>();
+                    }
+
+                    #[test]
+                    fn pop_and_remove() {
+                        super::pop_and_remove::<$ty>();
+                    }
+
+                    #[test]
+                    fn clear() {
+                        super::clear::<$ty>();
+                    }
+                }
+            )*
+        };
+    }
+
+    index_tests!(scan => ScanIndex, probe => ProbeIndex<8>);
+    #[cfg(feature = "hash_index")]
+    index_tests!(hash => HashIndex<i64>);
+
+    #[test]
+    fn single_slot() {
+        let mut cache: LruCache<1, i64, i64> = LruCache::new();
+
+        assert_eq!(cache.put(1, 1), None);
+        assert_eq!(cache.get(&1), Some(&1));
+        assert_eq!(cache.put(2, 2), Some(Displaced::Evicted(1, 1)));
+        assert_eq!(cache.iter().collect::<Vec<(&i64, &i64)>>(), vec![(&2, &2)]);
+    }
+
+    #[test]
+    fn zero_size() {
+        let mut cache: LruCache<0, i64, i64> = LruCache::new();
+
+        assert_eq!(cache.put(1, 1), Some(Displaced::Evicted(1, 1)));
+        assert_eq!(cache.pop_lru(), None);
+    }
+
+    #[test]
+    fn debug() {
+        let mut cache: LruCache<SIZE, i64, i64> = LruCache::new();
+
+        cache.put(1, 10);
+        cache.put(2, 20);
+
+        assert_eq!(format!("{cache:?}"), "{2: 20, 1: 10}");
+    }
+}
+
+mod probe_index {
+    use crate::cache::{KeyIndex, ProbeIndex};
+
+    const SIZE: usize = 4;
+
+    #[test]
+    fn remove_keeps_probe_chains() {
+        // a nearly full table - so keys collide, and removing a key shifts back the keys probed past it
+        let mut index: ProbeIndex<{ SIZE + 1 }> = ProbeIndex::default();
+        let mut slots: [Option<i64>; SIZE] = [None; SIZE];
+
+        for (slot, key) in (0..SIZE).zip(0..) {
+            index.insert(&key, slot);
+            slots[slot] = Some(key);
+        }
+
+        for key in SIZE as i64..64 {
+            let slot = (key * 3 % SIZE as i64) as usize;
+            let removed = slots[slot].take().unwrap();
+
+            index.remove(&removed, slot);
+            assert_eq!(
+                index.find(&removed, SIZE, |slot| slots[slot].as_ref()),
+                None
+            );
+
+            index.insert(&key, slot);
+            slots[slot] = Some(key);
+
+            for (slot, key) in slots.iter().enumerate() {
+                let key = key.unwrap();
+
+                assert_eq!(
+                    index.find(&key, SIZE, |slot| slots[slot].as_ref()),
+                    Some(slot)
+                );
+            }
+        }
+
+        KeyIndex::<i64>::clear(&mut index);
+        assert_eq!(index.find(&63, SIZE, |slot| slots[slot].as_ref()), None);
+    }
+}
@@ -0,0 +1,511 @@
+//! The asynchronous module contains an async variant of the bounded channel - which has no runtime dependency.
+//!
+//! The [`Sender::send`] and [`Receiver::recv`] futures register their [`Waker`] while the channel is full or empty, and are woken by the opposite operation (or by the channel disconnecting). As a result, the channel can be driven by any executor.
+//!
+//! ```
+//! # use cyclic_data_types::channel::asynchronous;
+//! # use cyclic_data_types::error::Error;
+//! # use std::{future::Future, pin::pin, sync::Arc, task::{Context, Poll, Wake, Waker}, thread::{self, Thread}};
+//! # struct Unpark(Thread);
+//! # impl Wake for Unpark {
+//! #     fn wake(self: Arc<Self>) {
+//! #         self.0.unpark();
+//! #     }
+//! # }
+//! # fn block_on<F: Future>(future: F) -> F::Output {
+//! #     let waker = Waker::from(Arc::new(Unpark(thread::current())));
+//! #     let mut cx = Context::from_waker(&waker);
+//! #     let mut future = pin!(future);
+//! #     loop {
+//! #         match future.as_mut().poll(&mut cx) {
+//! #             Poll::Ready(output) => return output,
+//! #             Poll::Pending => thread::park(),
+//! #         }
+//! #     }
+//! # }
+//! let (sender, receiver) = asynchronous::channel::<2, i64>();
+//!
+//! let producer = thread::spawn(move || {
+//!     block_on(async {
+//!         for i in 0..10 {
+//!             sender.send(i).await.unwrap();
+//!         }
+//!     })
+//! });
+//!
+//! let received = block_on(async {
+//!     let mut received = Vec::new();
+//!     while let Ok(elem) = receiver.recv().await {
+//!         received.push(elem);
+//!     }
+//!     received
+//! });
+//! producer.join().unwrap();
+//!
+//! assert_eq!(received, (0..10).collect::<Vec<i64>>());
+//! ```
+
+use std::{
+    fmt::Debug,
+    future::Future,
+    mem,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    task::{Context, Poll, Waker},
+};
+
+use crate::{
+    error::{Error, SendError},
+    list::List,
+    queue::Queue,
+};
+
+/// Creates a new async channel - returning a cloneable sender and receiver to the channel.
+///
+/// Sending to a full channel waits until a message has been received.
+///
+/// ```
+/// # use cyclic_data_types::channel::asynchronous;
+/// # use std::{future::Future, pin::pin, task::{Context, Poll, Waker}};
+/// let (sender, receiver) = asynchronous::channel::<1, i64>();
+/// let mut cx = Context::from_waker(Waker::noop());
+///
+/// assert_eq!(pin!(sender.send(1)).poll(&mut cx), Poll::Ready(Ok(())));
+/// assert_eq!(pin!(sender.send(2)).poll(&mut cx), Poll::Pending);
+///
+/// assert_eq!(pin!(receiver.recv()).poll(&mut cx), Poll::Ready(Ok(1)));
+/// ```
+///
+/// A channel must be able to hold at least one message - which is checked at compile time.
+///
+/// ```compile_fail
+/// # use cyclic_data_types::channel::asynchronous;
+/// let (sender, receiver) = asynchronous::channel::<0, i64>();
+/// ```
+pub fn channel<const SIZE: usize, T>() -> (Sender<SIZE, T, false>, Receiver<SIZE, T, false>) {
+    new_channel()
+}
+
+/// Creates a new async write over channel - returning a cloneable sender and receiver to the channel.
+///
+/// Sending is always ready. Instead of waiting, sending to a full channel causes the oldest message to be lost.
+///
+/// ```
+/// # use cyclic_data_types::channel::asynchronous;
+/// # use std::{future::Future, pin::pin, task::{Context, Poll, Waker}};
+/// let (sender, receiver) = asynchronous::write_over_channel::<1, i64>();
+/// let mut cx = Context::from_waker(Waker::noop());
+///
+/// assert_eq!(pin!(sender.send(1)).poll(&mut cx), Poll::Ready(Ok(())));
+/// assert_eq!(pin!(sender.send(2)).poll(&mut cx), Poll::Ready(Ok(())));
+///
+/// assert_eq!(pin!(receiver.recv()).poll(&mut cx), Poll::Ready(Ok(2)));
+/// ```
+///
+/// A channel must be able to hold at least one message - which is checked at compile time.
+///
+/// ```compile_fail
+/// # use cyclic_data_types::channel::asynchronous;
+/// let (sender, receiver) = asynchronous::write_over_channel::<0, i64>();
+/// ```
+pub fn write_over_channel<const SIZE: usize, T>() -> (Sender<SIZE, T, true>, Receiver<SIZE, T, true>)
+{
+    new_channel()
+}
+
+fn new_channel<const SIZE: usize, T, const WRITE_OVER: bool>(
+) -> (Sender<SIZE, T, WRITE_OVER>, Receiver<SIZE, T, WRITE_OVER>) {
+    const { assert!(SIZE > 0, "a channel requires a SIZE of at least 1") };
+
+    let shared = Arc::new(Mutex::new(State {
+        queue: List::empty().into(),
+        senders: 1,
+        receivers: 1,
+        send_wakers: Vec::new(),
+        recv_wakers: Vec::new(),
+    }));
+
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    )
+}
+
+struct State<const SIZE: usize, T, const WRITE_OVER: bool> {
+    queue: Queue<SIZE, T, WRITE_OVER>,
+    senders: usize,
+    receivers: usize,
+    send_wakers: Vec<Waker>,
+    recv_wakers: Vec<Waker>,
+}
+
+type Shared<const SIZE: usize, T, const WRITE_OVER: bool> = Arc<Mutex<State<SIZE, T, WRITE_OVER>>>;
+
+/// Locks the state of the channel. A panic while holding the lock cannot leave the queue in an invalid state - so poisoning is ignored.
+fn lock<const SIZE: usize, T, const WRITE_OVER: bool>(
+    shared: &Shared<SIZE, T, WRITE_OVER>,
+) -> MutexGuard<'_, State<SIZE, T, WRITE_OVER>> {
+    shared.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Registers `waker` unless an equivalent waker has already been registered
+fn register(wakers: &mut Vec<Waker>, waker: &Waker) {
+    if !wakers.iter().any(|registered| registered.will_wake(waker)) {
+        wakers.push(waker.clone());
+    }
+}
+
+/// Wakes every waker taken from the state of the channel. Every waiting future is woken - as a woken future may be dropped before it is polled again.
+///
+/// The wakers must be taken out of the state, and the lock released, before waking - as a waker may poll the woken task inline, which would lock the state again.
+fn wake_all(wakers: Vec<Waker>) {
+    wakers.into_iter().for_each(Waker::wake);
+}
+
+/// `Sender` is a cloneable handle used to send messages into an async channel.
+///
+/// The channel is disconnected for receivers once every sender has been dropped.
+pub struct Sender<const SIZE: usize, T, const WRITE_OVER: bool> {
+    shared: Shared<SIZE, T, WRITE_OVER>,
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool> Sender<SIZE, T, WRITE_OVER> {
+    /// Returns a future which sends a message into the channel. The future waits while the channel is full, unless `WRITE_OVER` is set to `true` - in which case the oldest message is lost.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel::asynchronous;
+    /// # use cyclic_data_types::error::{Error, SendError};
+    /// # use std::{future::Future, pin::pin, task::{Context, Poll, Waker}};
+    /// let (sender, receiver) = asynchronous::channel::<1, i64>();
+    /// let mut cx = Context::from_waker(Waker::noop());
+    ///
+    /// assert_eq!(pin!(sender.send(1)).poll(&mut cx), Poll::Ready(Ok(())));
+    ///
+    /// drop(receiver);
+    ///
+    /// assert_eq!(
+    ///     pin!(sender.send(2)).poll(&mut cx),
+    ///     Poll::Ready(Err(SendError { error: Error::Disconnected, elem: 2 }))
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(())` if the message was sent
+    /// * [`SendError`] holding [`Error::Disconnected`] and the message if every receiver has been dropped
+    pub fn send(&self, elem: T) -> SendFuture<'_, SIZE, T, WRITE_OVER> {
+        SendFuture {
+            sender: self,
+            elem: Some(elem),
+        }
+    }
+
+    /// Returns the number of messages in the channel.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel::asynchronous;
+    /// # use std::{future::Future, pin::pin, task::{Context, Poll, Waker}};
+    /// let (sender, _receiver) = asynchronous::channel::<2, i64>();
+    /// let mut cx = Context::from_waker(Waker::noop());
+    ///
+    /// assert_eq!(pin!(sender.send(1)).poll(&mut cx), Poll::Ready(Ok(())));
+    ///
+    /// assert_eq!(sender.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        lock(&self.shared).queue.len()
+    }
+
+    /// Returns `true` if the channel contains no messages.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel::asynchronous;
+    /// let (sender, _receiver) = asynchronous::channel::<2, i64>();
+    ///
+    /// assert!(sender.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Polls to send the message held in `elem` - registering the waker of `cx` while the channel is full. Allowing the sender to be adapted into a sink.
+    ///
+    /// The message is taken out of `elem` once it has been sent. Otherwise, the message is left in `elem` - so it can be sent again later, or recovered if the channel has been disconnected. Polling with an empty `elem` is always ready.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel::asynchronous;
+    /// # use cyclic_data_types::error::Error;
+    /// # use std::task::{Context, Poll, Waker};
+    /// let (sender, receiver) = asynchronous::channel::<1, i64>();
+    /// let mut cx = Context::from_waker(Waker::noop());
+    ///
+    /// let mut elem = Some(1);
+    /// assert_eq!(sender.poll_send(&mut elem, &mut cx), Poll::Ready(Ok(())));
+    /// assert_eq!(elem, None);
+    ///
+    /// let mut elem = Some(2);
+    /// assert_eq!(sender.poll_send(&mut elem, &mut cx), Poll::Pending);
+    /// assert_eq!(elem, Some(2));
+    ///
+    /// drop(receiver);
+    ///
+    /// assert_eq!(sender.poll_send(&mut elem, &mut cx), Poll::Ready(Err(Error::Disconnected)));
+    /// assert_eq!(elem, Some(2));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Poll::Ready(Ok(()))` if the message was sent
+    /// * `Poll::Ready(Err(Error::Disconnected))` if every receiver has been dropped
+    /// * `Poll::Pending` if the channel is full
+    pub fn poll_send(&self, elem: &mut Option<T>, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        if elem.is_none() {
+            return Poll::Ready(Ok(()));
+        }
+
+        let mut state = lock(&self.shared);
+
+        if state.receivers == 0 {
+            return Poll::Ready(Err(Error::Disconnected));
+        }
+
+        if !WRITE_OVER && state.queue.len() == SIZE {
+            register(&mut state.send_wakers, cx.waker());
+            return Poll::Pending;
+        }
+
+        if let Some(elem) = elem.take() {
+            state
+                .queue
+                .enqueue(elem)
+                .expect("the queue has space, or writes over the oldest message");
+
+            let wakers = mem::take(&mut state.recv_wakers);
+            drop(state);
+            wake_all(wakers);
+        }
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Clone for Sender<SIZE, T, W> {
+    fn clone(&self) -> Self {
+        lock(&self.shared).senders += 1;
+
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Drop for Sender<SIZE, T, W> {
+    fn drop(&mut self) {
+        let mut state = lock(&self.shared);
+        state.senders -= 1;
+
+        if state.senders == 0 {
+            let wakers = mem::take(&mut state.recv_wakers);
+            drop(state);
+            wake_all(wakers);
+        }
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Debug for Sender<SIZE, T, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = lock(&self.shared);
+
+        f.debug_struct("Sender")
+            .field("size", &state.queue.len())
+            .field("senders", &state.senders)
+            .field("receivers", &state.receivers)
+            .finish()
+    }
+}
+
+/// `SendFuture` is the future returned by [`Sender::send`].
+///
+/// Dropping the future before it completes drops the message without sending it. If the channel has been disconnected, the future completes with a [`SendError`] - handing the message back.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SendFuture<'a, const SIZE: usize, T, const WRITE_OVER: bool> {
+    sender: &'a Sender<SIZE, T, WRITE_OVER>,
+    elem: Option<T>,
+}
+
+// The message is never pinned - it is only moved into the queue.
+impl<const SIZE: usize, T, const W: bool> Unpin for SendFuture<'_, SIZE, T, W> {}
+
+impl<const SIZE: usize, T, const W: bool> Future for SendFuture<'_, SIZE, T, W> {
+    type Output = Result<(), SendError<T>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        this.sender
+            .poll_send(&mut this.elem, cx)
+            .map_err(|error| SendError {
+                error,
+                elem: this
+                    .elem
+                    .take()
+                    .expect("a message which was not sent is left in the future"),
+            })
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Debug for SendFuture<'_, SIZE, T, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SendFuture")
+            .field("sent", &self.elem.is_none())
+            .finish()
+    }
+}
+
+/// `Receiver` is a cloneable handle used to receive messages from an async channel.
+///
+/// The channel is disconnected for senders once every receiver has been dropped.
+pub struct Receiver<const SIZE: usize, T, const WRITE_OVER: bool> {
+    shared: Shared<SIZE, T, WRITE_OVER>,
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool> Receiver<SIZE, T, WRITE_OVER> {
+    /// Returns a future which receives the oldest message in the channel. The future waits while the channel is empty.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel::asynchronous;
+    /// # use cyclic_data_types::error::Error;
+    /// # use std::{future::Future, pin::pin, task::{Context, Poll, Waker}};
+    /// let (sender, receiver) = asynchronous::channel::<1, i64>();
+    /// let mut cx = Context::from_waker(Waker::noop());
+    ///
+    /// assert_eq!(pin!(receiver.recv()).poll(&mut cx), Poll::Pending);
+    /// assert_eq!(pin!(sender.send(1)).poll(&mut cx), Poll::Ready(Ok(())));
+    ///
+    /// drop(sender);
+    ///
+    /// assert_eq!(pin!(receiver.recv()).poll(&mut cx), Poll::Ready(Ok(1)));
+    /// assert_eq!(pin!(receiver.recv()).poll(&mut cx), Poll::Ready(Err(Error::Disconnected)));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(T)` with the oldest message in the channel
+    /// * [`Error::Disconnected`] if the channel is empty and every sender has been dropped
+    pub fn recv(&self) -> RecvFuture<'_, SIZE, T, WRITE_OVER> {
+        RecvFuture { receiver: self }
+    }
+
+    /// Polls for the oldest message in the channel - registering the waker of `cx` while the channel is empty. Allowing the receiver to be adapted into a stream.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel::asynchronous;
+    /// # use std::{future::Future, pin::pin, task::{Context, Poll, Waker}};
+    /// let (sender, receiver) = asynchronous::channel::<1, i64>();
+    /// let mut cx = Context::from_waker(Waker::noop());
+    ///
+    /// assert_eq!(receiver.poll_recv(&mut cx), Poll::Pending);
+    /// assert_eq!(pin!(sender.send(1)).poll(&mut cx), Poll::Ready(Ok(())));
+    /// assert_eq!(receiver.poll_recv(&mut cx), Poll::Ready(Ok(1)));
+    /// ```
+    pub fn poll_recv(&self, cx: &mut Context<'_>) -> Poll<Result<T, Error>> {
+        let mut state = lock(&self.shared);
+
+        if let Some(elem) = state.queue.dequeue() {
+            let wakers = mem::take(&mut state.send_wakers);
+            drop(state);
+            wake_all(wakers);
+
+            return Poll::Ready(Ok(elem));
+        }
+
+        if state.senders == 0 {
+            return Poll::Ready(Err(Error::Disconnected));
+        }
+
+        register(&mut state.recv_wakers, cx.waker());
+        Poll::Pending
+    }
+
+    /// Returns the number of messages in the channel.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel::asynchronous;
+    /// # use std::{future::Future, pin::pin, task::{Context, Poll, Waker}};
+    /// let (sender, receiver) = asynchronous::channel::<2, i64>();
+    /// let mut cx = Context::from_waker(Waker::noop());
+    ///
+    /// assert_eq!(pin!(sender.send(1)).poll(&mut cx), Poll::Ready(Ok(())));
+    ///
+    /// assert_eq!(receiver.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        lock(&self.shared).queue.len()
+    }
+
+    /// Returns `true` if the channel contains no messages.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel::asynchronous;
+    /// let (_sender, receiver) = asynchronous::channel::<2, i64>();
+    ///
+    /// assert!(receiver.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Clone for Receiver<SIZE, T, W> {
+    fn clone(&self) -> Self {
+        lock(&self.shared).receivers += 1;
+
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Drop for Receiver<SIZE, T, W> {
+    fn drop(&mut self) {
+        let mut state = lock(&self.shared);
+        state.receivers -= 1;
+
+        if state.receivers == 0 {
+            let wakers = mem::take(&mut state.send_wakers);
+            drop(state);
+            wake_all(wakers);
+        }
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Debug for Receiver<SIZE, T, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = lock(&self.shared);
+
+        f.debug_struct("Receiver")
+            .field("size", &state.queue.len())
+            .field("senders", &state.senders)
+            .field("receivers", &state.receivers)
+            .finish()
+    }
+}
+
+/// `RecvFuture` is the future returned by [`Receiver::recv`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct RecvFuture<'a, const SIZE: usize, T, const WRITE_OVER: bool> {
+    receiver: &'a Receiver<SIZE, T, WRITE_OVER>,
+}
+
+impl<const SIZE: usize, T, const W: bool> Future for RecvFuture<'_, SIZE, T, W> {
+    type Output = Result<T, Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Debug for RecvFuture<'_, SIZE, T, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecvFuture").finish()
+    }
+}
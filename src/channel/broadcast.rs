@@ -0,0 +1,365 @@
+//! The broadcast module contains a fan-out channel. Where a single [`Writer`] pushes messages into a fixed-size write over [`SeqList`], and any number of [`Reader`]s consume every message at their own pace.
+//!
+//! Every message is given a sequence number, and every reader keeps the sequence number of the next message it will read. As the writer never blocks, the oldest messages are written over once the list is full - and a reader that has fallen behind receives [`Error::Lagged`] with the number of messages it missed, before continuing from the oldest message still in the list.
+//!
+//! ```
+//! # use cyclic_data_types::channel::broadcast;
+//! # use cyclic_data_types::error::Error;
+//! let (mut writer, mut fast) = broadcast::broadcast::<2, i64>();
+//! let mut slow = fast.clone();
+//!
+//! writer.send(1);
+//! assert_eq!(fast.try_recv(), Ok(Some(1)));
+//!
+//! writer.send(2);
+//! writer.send(3);
+//! assert_eq!(fast.try_recv(), Ok(Some(2)));
+//! assert_eq!(fast.try_recv(), Ok(Some(3)));
+//!
+//! assert_eq!(slow.try_recv(), Err(Error::Lagged(1)));
+//! assert_eq!(slow.try_recv(), Ok(Some(2)));
+//! assert_eq!(slow.try_recv(), Ok(Some(3)));
+//! assert_eq!(slow.try_recv(), Ok(None));
+//! ```
+
+use std::{
+    fmt::Debug,
+    sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use crate::{error::Error, list::SeqList};
+
+/// Creates a new broadcast channel - returning the writer and a reader to the channel. Further readers are created by cloning a reader or with [`Writer::subscribe`].
+///
+/// ```
+/// # use cyclic_data_types::channel::broadcast;
+/// let (mut writer, mut reader) = broadcast::broadcast::<4, i64>();
+///
+/// assert_eq!(writer.send(1), 0);
+///
+/// assert_eq!(reader.try_recv(), Ok(Some(1)));
+/// ```
+///
+/// A broadcast channel must be able to hold at least one message - which is checked at compile time.
+///
+/// ```compile_fail
+/// # use cyclic_data_types::channel::broadcast;
+/// let (writer, reader) = broadcast::broadcast::<0, i64>();
+/// ```
+pub fn broadcast<const SIZE: usize, T: Clone>() -> (Writer<SIZE, T>, Reader<SIZE, T>) {
+    const {
+        assert!(
+            SIZE > 0,
+            "a broadcast channel requires a SIZE of at least 1"
+        )
+    };
+
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            list: SeqList::new(),
+            writer: true,
+        }),
+        sent: Condvar::new(),
+    });
+
+    (
+        Writer {
+            shared: shared.clone(),
+        },
+        Reader { shared, next: 0 },
+    )
+}
+
+struct State<const SIZE: usize, T> {
+    list: SeqList<SIZE, T, true>,
+    writer: bool,
+}
+
+impl<const SIZE: usize, T: Clone> State<SIZE, T> {
+    /// Reads the message with the sequence number `next` - moving `next` on to the following message
+    fn read(&self, next: &mut u64) -> Result<Option<T>, Error> {
+        match self.list.get_by_seq(*next) {
+            Ok(elem) => {
+                *next += 1;
+
+                Ok(Some(elem.clone()))
+            }
+            Err(Error::Overwritten) => {
+                // the list is never empty once a message has been written over
+                let first_seq = self.list.first_seq().unwrap_or(self.list.next_seq());
+                let lagged = first_seq - *next;
+                *next = first_seq;
+
+                Err(Error::Lagged(lagged))
+            }
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+struct Shared<const SIZE: usize, T> {
+    state: Mutex<State<SIZE, T>>,
+    sent: Condvar,
+}
+
+impl<const SIZE: usize, T> Shared<SIZE, T> {
+    /// Locks the state of the channel. A panic while holding the lock cannot leave the list in an invalid state - so poisoning is ignored.
+    fn lock(&self) -> MutexGuard<'_, State<SIZE, T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// `Writer` is the single handle used to send messages into a broadcast channel. Sending never blocks - once the channel is full, the oldest message is written over.
+///
+/// The channel is disconnected for readers once the writer has been dropped.
+pub struct Writer<const SIZE: usize, T> {
+    shared: Arc<Shared<SIZE, T>>,
+}
+
+impl<const SIZE: usize, T: Clone> Writer<SIZE, T> {
+    /// Sends a message to every reader - writing over the oldest message if the channel is full.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel::broadcast;
+    /// let (mut writer, mut reader) = broadcast::broadcast::<4, i64>();
+    ///
+    /// assert_eq!(writer.send(1), 0);
+    /// assert_eq!(writer.send(2), 1);
+    ///
+    /// assert_eq!(reader.try_recv(), Ok(Some(1)));
+    /// assert_eq!(reader.try_recv(), Ok(Some(2)));
+    /// ```
+    ///
+    /// # Returns
+    /// * The sequence number of the message
+    pub fn send(&mut self, elem: T) -> u64 {
+        let mut state = self.shared.lock();
+
+        let seq = state
+            .list
+            .push_back(elem)
+            .expect("write-over SeqList cannot overflow");
+
+        drop(state);
+        self.shared.sent.notify_all();
+
+        seq
+    }
+
+    /// Creates a new reader - which receives every message sent after it was created.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel::broadcast;
+    /// let (mut writer, _reader) = broadcast::broadcast::<4, i64>();
+    ///
+    /// writer.send(1);
+    /// let mut reader = writer.subscribe();
+    /// writer.send(2);
+    ///
+    /// assert_eq!(reader.try_recv(), Ok(Some(2)));
+    /// ```
+    pub fn subscribe(&self) -> Reader<SIZE, T> {
+        Reader {
+            shared: self.shared.clone(),
+            next: self.shared.lock().list.next_seq(),
+        }
+    }
+
+    /// Returns the number of messages held by the channel.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel::broadcast;
+    /// let (mut writer, _reader) = broadcast::broadcast::<2, i64>();
+    ///
+    /// writer.send(1);
+    /// writer.send(2);
+    /// writer.send(3);
+    ///
+    /// assert_eq!(writer.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.shared.lock().list.len()
+    }
+
+    /// Returns `true` if the channel holds no messages.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel::broadcast;
+    /// let (writer, _reader) = broadcast::broadcast::<2, i64>();
+    ///
+    /// assert!(writer.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<const SIZE: usize, T> Drop for Writer<SIZE, T> {
+    fn drop(&mut self) {
+        self.shared.lock().writer = false;
+        self.shared.sent.notify_all();
+    }
+}
+
+impl<const SIZE: usize, T> Debug for Writer<SIZE, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.shared.lock();
+
+        f.debug_struct("Writer")
+            .field("size", &state.list.len())
+            .field("next_seq", &state.list.next_seq())
+            .finish()
+    }
+}
+
+/// `Reader` is a handle used to receive every message sent into a broadcast channel. Each reader keeps its own cursor - so cloning a reader creates a new reader at the same position.
+pub struct Reader<const SIZE: usize, T> {
+    shared: Arc<Shared<SIZE, T>>,
+    next: u64,
+}
+
+impl<const SIZE: usize, T: Clone> Reader<SIZE, T> {
+    /// Receives the next message - without blocking.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel::broadcast;
+    /// # use cyclic_data_types::error::Error;
+    /// let (mut writer, mut reader) = broadcast::broadcast::<1, i64>();
+    ///
+    /// assert_eq!(reader.try_recv(), Ok(None));
+    ///
+    /// writer.send(1);
+    /// writer.send(2);
+    ///
+    /// assert_eq!(reader.try_recv(), Err(Error::Lagged(1)));
+    /// assert_eq!(reader.try_recv(), Ok(Some(2)));
+    ///
+    /// drop(writer);
+    ///
+    /// assert_eq!(reader.try_recv(), Err(Error::Disconnected));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(Some(T))` with the next message
+    /// * `Ok(None)` if the reader has received every message sent
+    /// * [`Error::Lagged`] with the number of messages written over before the reader could receive them. The reader then continues from the oldest message in the channel
+    /// * [`Error::Disconnected`] if the reader has received every message sent and the writer has been dropped
+    pub fn try_recv(&mut self) -> Result<Option<T>, Error> {
+        let state = self.shared.lock();
+
+        match state.read(&mut self.next)? {
+            None if !state.writer => Err(Error::Disconnected),
+            elem => Ok(elem),
+        }
+    }
+
+    /// Receives the next message - blocking until a message is sent.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel::broadcast;
+    /// # use cyclic_data_types::error::Error;
+    /// # use std::thread;
+    /// let (mut writer, mut reader) = broadcast::broadcast::<4, i64>();
+    ///
+    /// let handle = thread::spawn(move || reader.recv());
+    /// writer.send(1);
+    ///
+    /// assert_eq!(handle.join().unwrap(), Ok(1));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(T)` with the next message
+    /// * [`Error::Lagged`] with the number of messages written over before the reader could receive them
+    /// * [`Error::Disconnected`] if the reader has received every message sent and the writer has been dropped
+    pub fn recv(&mut self) -> Result<T, Error> {
+        self.recv_until(None)
+    }
+
+    /// Receives the next message - blocking until a message is sent for at most `timeout`. A `timeout` too large to be represented as a deadline blocks like [`Reader::recv`].
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel::broadcast;
+    /// # use cyclic_data_types::error::Error;
+    /// # use std::time::Duration;
+    /// let (mut writer, mut reader) = broadcast::broadcast::<4, i64>();
+    ///
+    /// writer.send(1);
+    ///
+    /// assert_eq!(reader.recv_timeout(Duration::from_millis(10)), Ok(1));
+    /// assert_eq!(reader.recv_timeout(Duration::from_millis(10)), Err(Error::Timeout));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(T)` with the next message
+    /// * [`Error::Lagged`] with the number of messages written over before the reader could receive them
+    /// * [`Error::Timeout`] if no message was sent within `timeout`
+    /// * [`Error::Disconnected`] if the reader has received every message sent and the writer has been dropped
+    pub fn recv_timeout(&mut self, timeout: Duration) -> Result<T, Error> {
+        self.recv_until(Instant::now().checked_add(timeout))
+    }
+
+    /// Returns the number of messages the reader has yet to receive - including messages which have been written over.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel::broadcast;
+    /// let (mut writer, mut reader) = broadcast::broadcast::<2, i64>();
+    ///
+    /// writer.send(1);
+    /// writer.send(2);
+    /// writer.send(3);
+    ///
+    /// assert_eq!(reader.pending(), 3);
+    /// ```
+    pub fn pending(&self) -> u64 {
+        self.shared.lock().list.next_seq() - self.next
+    }
+
+    fn recv_until(&mut self, deadline: Option<Instant>) -> Result<T, Error> {
+        let mut state = self.shared.lock();
+
+        loop {
+            if let Some(elem) = state.read(&mut self.next)? {
+                return Ok(elem);
+            }
+
+            if !state.writer {
+                return Err(Error::Disconnected);
+            }
+
+            state = match deadline {
+                None => self
+                    .shared
+                    .sent
+                    .wait(state)
+                    .unwrap_or_else(PoisonError::into_inner),
+                Some(deadline) => {
+                    let timeout = deadline
+                        .checked_duration_since(Instant::now())
+                        .ok_or(Error::Timeout)?;
+
+                    self.shared
+                        .sent
+                        .wait_timeout(state, timeout)
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .0
+                }
+            };
+        }
+    }
+}
+
+impl<const SIZE: usize, T> Clone for Reader<SIZE, T> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+            next: self.next,
+        }
+    }
+}
+
+impl<const SIZE: usize, T> Debug for Reader<SIZE, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Reader").field("next", &self.next).finish()
+    }
+}
@@ -0,0 +1,443 @@
+//! The channel module contains a blocking bounded channel built on top of [`Queue`]. Using only the standard library's [`Mutex`] and [`Condvar`].
+//!
+//! A channel is created with either [`channel`] or [`write_over_channel`]. Which return a cloneable [`Sender`] and [`Receiver`] pair.
+//! * [`channel`] blocks senders while the channel is full.
+//! * [`write_over_channel`] never blocks senders - instead the oldest message in the channel is lost.
+//!
+//! For async code, the [`asynchronous`] module contains the same channel with `send` and `recv` futures. For fan-out, the [`broadcast`] module contains a channel where every reader receives every message.
+//!
+//! Once every sender has been dropped, receivers are able to receive the remaining messages before [`Error::Disconnected`] is returned. Once every receiver has been dropped, sending returns [`Error::Disconnected`].
+//!
+//! ```
+//! # use cyclic_data_types::channel;
+//! # use cyclic_data_types::error::Error;
+//! # use std::thread;
+//! let (sender, receiver) = channel::channel::<4, i64>();
+//!
+//! let producer = thread::spawn(move || {
+//!     for i in 0..10 {
+//!         sender.send(i).unwrap();
+//!     }
+//! });
+//!
+//! let mut received = Vec::new();
+//! while let Ok(elem) = receiver.recv() {
+//!     received.push(elem);
+//! }
+//! producer.join().unwrap();
+//!
+//! assert_eq!(received, (0..10).collect::<Vec<i64>>());
+//! assert_eq!(receiver.recv(), Err(Error::Disconnected));
+//! ```
+
+use std::{
+    fmt::Debug,
+    sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::{Error, SendError},
+    list::List,
+    queue::Queue,
+};
+
+pub mod asynchronous;
+pub mod broadcast;
+
+#[cfg(test)]
+mod tests;
+
+/// Creates a new blocking channel - returning a cloneable sender and receiver to the channel.
+///
+/// Sending to a full channel blocks until a message has been received.
+///
+/// ```
+/// # use cyclic_data_types::channel;
+/// let (sender, receiver) = channel::channel::<2, i64>();
+///
+/// assert_eq!(sender.send(1), Ok(()));
+/// assert_eq!(sender.send(2), Ok(()));
+///
+/// assert_eq!(receiver.recv(), Ok(1));
+/// assert_eq!(receiver.recv(), Ok(2));
+/// ```
+///
+/// A channel must be able to hold at least one message - which is checked at compile time.
+///
+/// ```compile_fail
+/// # use cyclic_data_types::channel;
+/// let (sender, receiver) = channel::channel::<0, i64>();
+/// ```
+pub fn channel<const SIZE: usize, T>() -> (Sender<SIZE, T, false>, Receiver<SIZE, T, false>) {
+    new_channel()
+}
+
+/// Creates a new write over channel - returning a cloneable sender and receiver to the channel.
+///
+/// Sending never blocks. Instead, sending to a full channel causes the oldest message to be lost.
+///
+/// ```
+/// # use cyclic_data_types::channel;
+/// let (sender, receiver) = channel::write_over_channel::<2, i64>();
+///
+/// assert_eq!(sender.send(1), Ok(()));
+/// assert_eq!(sender.send(2), Ok(()));
+/// assert_eq!(sender.send(3), Ok(()));
+///
+/// assert_eq!(receiver.recv(), Ok(2));
+/// assert_eq!(receiver.recv(), Ok(3));
+/// ```
+///
+/// A channel must be able to hold at least one message - which is checked at compile time.
+///
+/// ```compile_fail
+/// # use cyclic_data_types::channel;
+/// let (sender, receiver) = channel::write_over_channel::<0, i64>();
+/// ```
+pub fn write_over_channel<const SIZE: usize, T>() -> (Sender<SIZE, T, true>, Receiver<SIZE, T, true>)
+{
+    new_channel()
+}
+
+fn new_channel<const SIZE: usize, T, const WRITE_OVER: bool>(
+) -> (Sender<SIZE, T, WRITE_OVER>, Receiver<SIZE, T, WRITE_OVER>) {
+    const { assert!(SIZE > 0, "a channel requires a SIZE of at least 1") };
+
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: List::empty().into(),
+            senders: 1,
+            receivers: 1,
+        }),
+        not_empty: Condvar::new(),
+        not_full: Condvar::new(),
+    });
+
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    )
+}
+
+struct State<const SIZE: usize, T, const WRITE_OVER: bool> {
+    queue: Queue<SIZE, T, WRITE_OVER>,
+    senders: usize,
+    receivers: usize,
+}
+
+struct Shared<const SIZE: usize, T, const WRITE_OVER: bool> {
+    state: Mutex<State<SIZE, T, WRITE_OVER>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool> Shared<SIZE, T, WRITE_OVER> {
+    /// Locks the state of the channel. A panic while holding the lock cannot leave the queue in an invalid state - so poisoning is ignored.
+    fn lock(&self) -> MutexGuard<'_, State<SIZE, T, WRITE_OVER>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Waits on `condvar` until it is notified or `deadline` has passed
+    fn wait<'a>(
+        &self,
+        condvar: &Condvar,
+        state: MutexGuard<'a, State<SIZE, T, WRITE_OVER>>,
+        deadline: Option<Instant>,
+    ) -> Result<MutexGuard<'a, State<SIZE, T, WRITE_OVER>>, Error> {
+        match deadline {
+            None => Ok(condvar.wait(state).unwrap_or_else(PoisonError::into_inner)),
+            Some(deadline) => {
+                let timeout = deadline
+                    .checked_duration_since(Instant::now())
+                    .ok_or(Error::Timeout)?;
+
+                Ok(condvar
+                    .wait_timeout(state, timeout)
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .0)
+            }
+        }
+    }
+
+    fn send(&self, elem: T, deadline: Option<Instant>) -> Result<(), SendError<T>> {
+        let mut state = self.lock();
+
+        loop {
+            if state.receivers == 0 {
+                return Err(SendError {
+                    error: Error::Disconnected,
+                    elem,
+                });
+            }
+
+            if WRITE_OVER || state.queue.len() < SIZE {
+                break;
+            }
+
+            state = match self.wait(&self.not_full, state, deadline) {
+                Ok(state) => state,
+                Err(error) => return Err(SendError { error, elem }),
+            };
+        }
+
+        state
+            .queue
+            .enqueue(elem)
+            .expect("a write over queue or a queue with space cannot overflow");
+        drop(state);
+        self.not_empty.notify_one();
+
+        Ok(())
+    }
+
+    fn recv(&self, deadline: Option<Instant>) -> Result<T, Error> {
+        let mut state = self.lock();
+
+        loop {
+            if let Some(elem) = state.queue.dequeue() {
+                drop(state);
+                self.not_full.notify_one();
+
+                return Ok(elem);
+            }
+
+            if state.senders == 0 {
+                return Err(Error::Disconnected);
+            }
+
+            state = self.wait(&self.not_empty, state, deadline)?;
+        }
+    }
+}
+
+/// `Sender` is a cloneable handle used to send messages into a channel.
+///
+/// The channel is disconnected for receivers once every sender has been dropped.
+pub struct Sender<const SIZE: usize, T, const WRITE_OVER: bool> {
+    shared: Arc<Shared<SIZE, T, WRITE_OVER>>,
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool> Sender<SIZE, T, WRITE_OVER> {
+    /// Sends a message into the channel. Blocking while the channel is full, unless `WRITE_OVER` is set to `true` - in which case the oldest message is lost.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel;
+    /// # use cyclic_data_types::error::Error;
+    /// let (sender, receiver) = channel::channel::<2, i64>();
+    ///
+    /// assert_eq!(sender.send(1), Ok(()));
+    ///
+    /// drop(receiver);
+    ///
+    /// let unsent = sender.send(2).unwrap_err();
+    ///
+    /// assert_eq!(unsent.error, Error::Disconnected);
+    /// assert_eq!(unsent.elem, 2);
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(())` if the message was sent
+    /// * [`SendError`] with [`Error::Disconnected`] and the unsent message - if every receiver has been dropped
+    pub fn send(&self, elem: T) -> Result<(), SendError<T>> {
+        self.shared.send(elem, None)
+    }
+
+    /// Sends a message into the channel. Blocking while the channel is full for at most `timeout`. A `timeout` too large to be represented as a deadline blocks like [`Sender::send`].
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel;
+    /// # use cyclic_data_types::error::{Error, SendError};
+    /// # use std::time::Duration;
+    /// let (sender, _receiver) = channel::channel::<1, i64>();
+    ///
+    /// assert_eq!(sender.send_timeout(1, Duration::from_millis(10)), Ok(()));
+    /// assert_eq!(
+    ///     sender.send_timeout(2, Duration::from_millis(10)),
+    ///     Err(SendError { error: Error::Timeout, elem: 2 })
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(())` if the message was sent
+    /// * [`SendError`] with [`Error::Timeout`] and the unsent message - if the channel stayed full for `timeout`
+    /// * [`SendError`] with [`Error::Disconnected`] and the unsent message - if every receiver has been dropped
+    pub fn send_timeout(&self, elem: T, timeout: Duration) -> Result<(), SendError<T>> {
+        self.shared.send(elem, Instant::now().checked_add(timeout))
+    }
+
+    /// Returns the number of messages in the channel.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel;
+    /// let (sender, _receiver) = channel::channel::<2, i64>();
+    ///
+    /// assert_eq!(sender.send(1), Ok(()));
+    ///
+    /// assert_eq!(sender.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.shared.lock().queue.len()
+    }
+
+    /// Returns `true` if the channel contains no messages.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel;
+    /// let (sender, _receiver) = channel::channel::<2, i64>();
+    ///
+    /// assert!(sender.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Clone for Sender<SIZE, T, W> {
+    fn clone(&self) -> Self {
+        self.shared.lock().senders += 1;
+
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Drop for Sender<SIZE, T, W> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.senders -= 1;
+
+        if state.senders == 0 {
+            drop(state);
+            self.shared.not_empty.notify_all();
+        }
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Debug for Sender<SIZE, T, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.shared.lock();
+
+        f.debug_struct("Sender")
+            .field("size", &state.queue.len())
+            .field("senders", &state.senders)
+            .field("receivers", &state.receivers)
+            .finish()
+    }
+}
+
+/// `Receiver` is a cloneable handle used to receive messages from a channel.
+///
+/// The channel is disconnected for senders once every receiver has been dropped.
+pub struct Receiver<const SIZE: usize, T, const WRITE_OVER: bool> {
+    shared: Arc<Shared<SIZE, T, WRITE_OVER>>,
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool> Receiver<SIZE, T, WRITE_OVER> {
+    /// Receives the oldest message in the channel. Blocking while the channel is empty.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel;
+    /// # use cyclic_data_types::error::Error;
+    /// let (sender, receiver) = channel::channel::<2, i64>();
+    ///
+    /// assert_eq!(sender.send(1), Ok(()));
+    ///
+    /// drop(sender);
+    ///
+    /// assert_eq!(receiver.recv(), Ok(1));
+    /// assert_eq!(receiver.recv(), Err(Error::Disconnected));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(T)` with the oldest message in the channel
+    /// * [`Error::Disconnected`] if the channel is empty and every sender has been dropped
+    pub fn recv(&self) -> Result<T, Error> {
+        self.shared.recv(None)
+    }
+
+    /// Receives the oldest message in the channel. Blocking while the channel is empty for at most `timeout`. A `timeout` too large to be represented as a deadline blocks like [`Receiver::recv`].
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel;
+    /// # use cyclic_data_types::error::Error;
+    /// # use std::time::Duration;
+    /// let (sender, receiver) = channel::channel::<2, i64>();
+    ///
+    /// assert_eq!(sender.send(1), Ok(()));
+    ///
+    /// assert_eq!(receiver.recv_timeout(Duration::from_millis(10)), Ok(1));
+    /// assert_eq!(receiver.recv_timeout(Duration::from_millis(10)), Err(Error::Timeout));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(T)` with the oldest message in the channel
+    /// * [`Error::Timeout`] if the channel stayed empty for `timeout`
+    /// * [`Error::Disconnected`] if the channel is empty and every sender has been dropped
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, Error> {
+        self.shared.recv(Instant::now().checked_add(timeout))
+    }
+
+    /// Returns the number of messages in the channel.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel;
+    /// let (sender, receiver) = channel::channel::<2, i64>();
+    ///
+    /// assert_eq!(sender.send(1), Ok(()));
+    ///
+    /// assert_eq!(receiver.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.shared.lock().queue.len()
+    }
+
+    /// Returns `true` if the channel contains no messages.
+    ///
+    /// ```
+    /// # use cyclic_data_types::channel;
+    /// let (_sender, receiver) = channel::channel::<2, i64>();
+    ///
+    /// assert!(receiver.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Clone for Receiver<SIZE, T, W> {
+    fn clone(&self) -> Self {
+        self.shared.lock().receivers += 1;
+
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Drop for Receiver<SIZE, T, W> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.receivers -= 1;
+
+        if state.receivers == 0 {
+            drop(state);
+            self.shared.not_full.notify_all();
+        }
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Debug for Receiver<SIZE, T, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.shared.lock();
+
+        f.debug_struct("Receiver")
+            .field("size", &state.queue.len())
+            .field("senders", &state.senders)
+            .field("receivers", &state.receivers)
+            .finish()
+    }
+}
@@ -0,0 +1,587 @@
+mod blocking {
+    use std::{
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use crate::{
+        channel::channel,
+        error::{Error, SendError},
+    };
+
+    const SIZE: usize = 4;
+
+    #[test]
+    fn send_recv() {
+        let (sender, receiver) = channel::<SIZE, i64>();
+
+        for i in 0..4 {
+            assert_eq!(sender.send(i), Ok(()));
+        }
+        assert_eq!(receiver.len(), SIZE);
+
+        for i in 0..4 {
+            assert_eq!(receiver.recv(), Ok(i));
+        }
+        assert!(sender.is_empty());
+    }
+
+    #[test]
+    fn blocks_when_full() {
+        let (sender, receiver) = channel::<SIZE, i64>();
+
+        for i in 0..4 {
+            assert_eq!(sender.send(i), Ok(()));
+        }
+
+        thread::scope(|scope| {
+            let blocked = scope.spawn(|| sender.send(4));
+
+            thread::sleep(Duration::from_millis(20));
+            assert_eq!(receiver.len(), SIZE);
+
+            assert_eq!(receiver.recv(), Ok(0));
+            assert_eq!(blocked.join().unwrap(), Ok(()));
+        });
+
+        for i in 1..5 {
+            assert_eq!(receiver.recv(), Ok(i));
+        }
+    }
+
+    #[test]
+    fn timeouts() {
+        let (sender, receiver) = channel::<1, i64>();
+
+        let start = Instant::now();
+        assert_eq!(
+            receiver.recv_timeout(Duration::from_millis(20)),
+            Err(Error::Timeout)
+        );
+        assert!(start.elapsed() >= Duration::from_millis(20));
+
+        assert_eq!(sender.send_timeout(1, Duration::from_millis(20)), Ok(()));
+
+        let start = Instant::now();
+        assert_eq!(
+            sender.send_timeout(2, Duration::from_millis(20)),
+            Err(SendError {
+                error: Error::Timeout,
+                elem: 2
+            })
+        );
+        assert!(start.elapsed() >= Duration::from_millis(20));
+
+        assert_eq!(receiver.recv_timeout(Duration::from_millis(20)), Ok(1));
+    }
+
+    #[test]
+    fn unbounded_timeouts() {
+        let (sender, receiver) = channel::<1, i64>();
+
+        assert_eq!(sender.send_timeout(1, Duration::MAX), Ok(()));
+        assert_eq!(receiver.recv_timeout(Duration::MAX), Ok(1));
+
+        thread::scope(|scope| {
+            let blocked = scope.spawn(|| receiver.recv_timeout(Duration::MAX));
+
+            thread::sleep(Duration::from_millis(20));
+            assert_eq!(sender.send_timeout(2, Duration::MAX), Ok(()));
+
+            assert_eq!(blocked.join().unwrap(), Ok(2));
+        });
+    }
+
+    #[test]
+    fn disconnect_senders() {
+        let (sender, receiver) = channel::<SIZE, i64>();
+        let cloned = sender.clone();
+
+        assert_eq!(sender.send(1), Ok(()));
+        drop(sender);
+        assert_eq!(cloned.send(2), Ok(()));
+
+        thread::scope(|scope| {
+            let receiver = &receiver;
+            let blocked = scope.spawn(move || {
+                (
+                    receiver.recv(),
+                    receiver.recv(),
+                    receiver.recv(),
+                    receiver.recv_timeout(Duration::from_secs(10)),
+                )
+            });
+
+            thread::sleep(Duration::from_millis(20));
+            drop(cloned);
+
+            assert_eq!(
+                blocked.join().unwrap(),
+                (
+                    Ok(1),
+                    Ok(2),
+                    Err(Error::Disconnected),
+                    Err(Error::Disconnected)
+                )
+            );
+        });
+    }
+
+    #[test]
+    fn disconnect_receivers() {
+        let (sender, receiver) = channel::<1, i64>();
+        let cloned = receiver.clone();
+
+        assert_eq!(sender.send(1), Ok(()));
+        drop(receiver);
+
+        thread::scope(|scope| {
+            let blocked = scope.spawn(|| sender.send(2));
+
+            thread::sleep(Duration::from_millis(20));
+            drop(cloned);
+
+            assert_eq!(
+                blocked.join().unwrap(),
+                Err(SendError {
+                    error: Error::Disconnected,
+                    elem: 2
+                })
+            );
+        });
+    }
+
+    #[test]
+    fn threads() {
+        const COUNT: i64 = 1_000;
+
+        let (sender, receiver) = channel::<SIZE, i64>();
+
+        let received: Vec<Vec<i64>> = thread::scope(|scope| {
+            for producer in 0..2 {
+                let sender = sender.clone();
+                scope.spawn(move || {
+                    for i in 0..COUNT {
+                        assert_eq!(sender.send(producer * COUNT + i), Ok(()));
+                    }
+                });
+            }
+            drop(sender);
+
+            let consumers: Vec<_> = (0..2)
+                .map(|_| {
+                    let receiver = receiver.clone();
+                    scope.spawn(move || {
+                        let mut received = Vec::new();
+                        while let Ok(elem) = receiver.recv() {
+                            received.push(elem);
+                        }
+                        received
+                    })
+                })
+                .collect();
+
+            consumers.into_iter().map(|c| c.join().unwrap()).collect()
+        });
+
+        let mut all: Vec<i64> = received.into_iter().flatten().collect();
+        all.sort_unstable();
+        assert_eq!(all, (0..2 * COUNT).collect::<Vec<i64>>());
+    }
+}
+
+mod write_over {
+    use crate::{channel::write_over_channel, error::Error};
+
+    const SIZE: usize = 4;
+
+    #[test]
+    fn never_blocks() {
+        let (sender, receiver) = write_over_channel::<SIZE, i64>();
+
+        for i in 0..10 {
+            assert_eq!(sender.send(i), Ok(()));
+        }
+        assert_eq!(receiver.len(), SIZE);
+
+        drop(sender);
+
+        for i in 6..10 {
+            assert_eq!(receiver.recv(), Ok(i));
+        }
+        assert_eq!(receiver.recv(), Err(Error::Disconnected));
+    }
+
+    #[test]
+    fn disconnect_receivers() {
+        let (sender, receiver) = write_over_channel::<SIZE, i64>();
+
+        drop(receiver);
+
+        let unsent = sender.send(1).unwrap_err();
+        assert_eq!(unsent.elem, 1);
+        assert_eq!(Error::from(unsent), Error::Disconnected);
+    }
+}
+
+mod asynchronous {
+    use std::{
+        future::Future,
+        pin::pin,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        task::{Context, Poll, Wake, Waker},
+        thread::{self, Thread},
+    };
+
+    use crate::{
+        channel::asynchronous::{channel, write_over_channel},
+        error::{Error, SendError},
+    };
+
+    const SIZE: usize = 2;
+
+    /// Wakes a parked thread - and counts the number of times it was woken
+    struct Unpark {
+        thread: Thread,
+        woken: AtomicUsize,
+    }
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.woken.fetch_add(1, Ordering::SeqCst);
+            self.thread.unpark();
+        }
+    }
+
+    fn unpark() -> Arc<Unpark> {
+        Arc::new(Unpark {
+            thread: thread::current(),
+            woken: AtomicUsize::new(0),
+        })
+    }
+
+    /// Minimal executor - polling the future on the current thread and parking while it is pending
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Waker::from(unpark());
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(future);
+
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn send_recv() {
+        let (sender, receiver) = channel::<SIZE, i64>();
+
+        block_on(async {
+            assert_eq!(sender.send(1).await, Ok(()));
+            assert_eq!(sender.send(2).await, Ok(()));
+            assert_eq!(receiver.len(), SIZE);
+
+            assert_eq!(receiver.recv().await, Ok(1));
+            assert_eq!(receiver.recv().await, Ok(2));
+            assert!(receiver.is_empty());
+        });
+    }
+
+    #[test]
+    fn recv_woken_by_send() {
+        let (sender, receiver) = channel::<SIZE, i64>();
+        let unpark = unpark();
+        let waker = Waker::from(unpark.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        let mut recv = pin!(receiver.recv());
+        assert_eq!(recv.as_mut().poll(&mut cx), Poll::Pending);
+        assert_eq!(recv.as_mut().poll(&mut cx), Poll::Pending);
+        assert_eq!(unpark.woken.load(Ordering::SeqCst), 0);
+
+        assert_eq!(block_on(sender.send(1)), Ok(()));
+        assert_eq!(unpark.woken.load(Ordering::SeqCst), 1);
+
+        assert_eq!(recv.as_mut().poll(&mut cx), Poll::Ready(Ok(1)));
+    }
+
+    #[test]
+    fn send_woken_by_recv() {
+        let (sender, receiver) = channel::<1, i64>();
+        let unpark = unpark();
+        let waker = Waker::from(unpark.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        assert_eq!(block_on(sender.send(1)), Ok(()));
+
+        let mut send = pin!(sender.send(2));
+        assert_eq!(send.as_mut().poll(&mut cx), Poll::Pending);
+        assert_eq!(unpark.woken.load(Ordering::SeqCst), 0);
+
+        assert_eq!(block_on(receiver.recv()), Ok(1));
+        assert_eq!(unpark.woken.load(Ordering::SeqCst), 1);
+
+        assert_eq!(send.as_mut().poll(&mut cx), Poll::Ready(Ok(())));
+        assert_eq!(block_on(receiver.recv()), Ok(2));
+    }
+
+    #[test]
+    fn disconnect() {
+        let (sender, receiver) = channel::<SIZE, i64>();
+        let unpark = unpark();
+        let waker = Waker::from(unpark.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        let mut recv = pin!(receiver.recv());
+        assert_eq!(recv.as_mut().poll(&mut cx), Poll::Pending);
+
+        drop(sender);
+        assert_eq!(unpark.woken.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            recv.as_mut().poll(&mut cx),
+            Poll::Ready(Err(Error::Disconnected))
+        );
+
+        let (sender, receiver) = channel::<1, i64>();
+        assert_eq!(block_on(sender.send(1)), Ok(()));
+
+        let mut send = pin!(sender.send(2));
+        assert_eq!(send.as_mut().poll(&mut cx), Poll::Pending);
+
+        drop(receiver);
+        assert_eq!(unpark.woken.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            send.as_mut().poll(&mut cx),
+            Poll::Ready(Err(SendError {
+                error: Error::Disconnected,
+                elem: 2
+            }))
+        );
+    }
+
+    /// Uses the channel while being woken - as an executor polling the woken task inline would
+    struct Reentrant<F: Fn() + Send + Sync>(F);
+
+    impl<F: Fn() + Send + Sync> Wake for Reentrant<F> {
+        fn wake(self: Arc<Self>) {
+            (self.0)();
+        }
+    }
+
+    #[test]
+    fn wake_reentrant() {
+        let (sender, receiver) = channel::<1, i64>();
+
+        let woken = Arc::new(AtomicUsize::new(0));
+        let (other, count) = (receiver.clone(), woken.clone());
+        let waker = Waker::from(Arc::new(Reentrant(move || {
+            count.fetch_add(other.len(), Ordering::SeqCst);
+        })));
+        let mut cx = Context::from_waker(&waker);
+
+        // woken by a send
+        assert_eq!(receiver.poll_recv(&mut cx), Poll::Pending);
+        assert_eq!(sender.poll_send(&mut Some(1), &mut cx), Poll::Ready(Ok(())));
+        assert_eq!(woken.load(Ordering::SeqCst), 1);
+
+        // woken by a receive
+        let mut elem = Some(2);
+        assert_eq!(sender.poll_send(&mut elem, &mut cx), Poll::Pending);
+        assert_eq!(receiver.poll_recv(&mut cx), Poll::Ready(Ok(1)));
+        assert_eq!(woken.load(Ordering::SeqCst), 1);
+
+        // woken by the senders disconnecting
+        assert_eq!(sender.poll_send(&mut elem, &mut cx), Poll::Ready(Ok(())));
+        assert_eq!(receiver.poll_recv(&mut cx), Poll::Ready(Ok(2)));
+        assert_eq!(receiver.poll_recv(&mut cx), Poll::Pending);
+        drop(sender);
+        assert_eq!(
+            receiver.poll_recv(&mut cx),
+            Poll::Ready(Err(Error::Disconnected))
+        );
+    }
+
+    #[test]
+    fn poll_send_keeps_message() {
+        let (sender, receiver) = channel::<1, i64>();
+        let mut cx = Context::from_waker(Waker::noop());
+
+        assert_eq!(sender.poll_send(&mut Some(1), &mut cx), Poll::Ready(Ok(())));
+
+        let mut elem = Some(2);
+        assert_eq!(sender.poll_send(&mut elem, &mut cx), Poll::Pending);
+        assert_eq!(elem, Some(2));
+
+        drop(receiver);
+        assert_eq!(
+            sender.poll_send(&mut elem, &mut cx),
+            Poll::Ready(Err(Error::Disconnected))
+        );
+        assert_eq!(elem, Some(2));
+    }
+
+    #[test]
+    fn write_over() {
+        let (sender, receiver) = write_over_channel::<SIZE, i64>();
+
+        block_on(async {
+            for i in 0..5 {
+                assert_eq!(sender.send(i).await, Ok(()));
+            }
+
+            assert_eq!(receiver.recv().await, Ok(3));
+            assert_eq!(receiver.recv().await, Ok(4));
+        });
+    }
+
+    #[test]
+    fn threads() {
+        const COUNT: i64 = 1_000;
+
+        let (sender, receiver) = channel::<SIZE, i64>();
+
+        let producer = thread::spawn(move || {
+            block_on(async {
+                for i in 0..COUNT {
+                    assert_eq!(sender.send(i).await, Ok(()));
+                }
+            })
+        });
+
+        let received = block_on(async {
+            let mut received = Vec::new();
+            while let Ok(elem) = receiver.recv().await {
+                received.push(elem);
+            }
+            received
+        });
+        producer.join().unwrap();
+
+        assert_eq!(received, (0..COUNT).collect::<Vec<i64>>());
+    }
+}
+
+mod broadcast {
+    use std::{thread, time::Duration};
+
+    use crate::{channel::broadcast::broadcast, error::Error};
+
+    const SIZE: usize = 4;
+
+    #[test]
+    fn every_reader_receives() {
+        let (mut writer, mut first) = broadcast::<SIZE, i64>();
+        let mut second = first.clone();
+
+        for i in 0..4 {
+            assert_eq!(writer.send(i), i as u64);
+        }
+
+        for i in 0..4 {
+            assert_eq!(first.try_recv(), Ok(Some(i)));
+        }
+        assert_eq!(first.try_recv(), Ok(None));
+
+        assert_eq!(second.try_recv(), Ok(Some(0)));
+        assert_eq!(second.pending(), 3);
+        assert_eq!(writer.len(), SIZE);
+    }
+
+    #[test]
+    fn lagged() {
+        let (mut writer, mut reader) = broadcast::<SIZE, i64>();
+
+        for i in 0..10 {
+            writer.send(i);
+        }
+        assert_eq!(reader.pending(), 10);
+
+        assert_eq!(reader.try_recv(), Err(Error::Lagged(6)));
+        for i in 6..10 {
+            assert_eq!(reader.try_recv(), Ok(Some(i)));
+        }
+        assert_eq!(reader.try_recv(), Ok(None));
+
+        writer.send(10);
+        assert_eq!(reader.recv(), Ok(10));
+    }
+
+    #[test]
+    fn subscribe() {
+        let (mut writer, _reader) = broadcast::<SIZE, i64>();
+
+        writer.send(0);
+        let mut reader = writer.subscribe();
+        assert_eq!(reader.try_recv(), Ok(None));
+
+        writer.send(1);
+        assert_eq!(reader.try_recv(), Ok(Some(1)));
+    }
+
+    #[test]
+    fn disconnect() {
+        let (mut writer, mut reader) = broadcast::<SIZE, i64>();
+
+        writer.send(1);
+        drop(writer);
+
+        assert_eq!(reader.try_recv(), Ok(Some(1)));
+        assert_eq!(reader.try_recv(), Err(Error::Disconnected));
+        assert_eq!(reader.recv(), Err(Error::Disconnected));
+        assert_eq!(
+            reader.recv_timeout(Duration::from_millis(10)),
+            Err(Error::Disconnected)
+        );
+        assert_eq!(reader.recv_timeout(Duration::MAX), Err(Error::Disconnected));
+    }
+
+    #[test]
+    fn threads() {
+        const COUNT: i64 = 1_000;
+
+        let (mut writer, reader) = broadcast::<SIZE, i64>();
+
+        let readers: Vec<_> = (0..3)
+            .map(|_| {
+                let mut reader = reader.clone();
+                thread::spawn(move || {
+                    let mut received = 0;
+                    let mut lagged = 0;
+                    let mut last = -1;
+
+                    loop {
+                        match reader.recv() {
+                            Ok(elem) => {
+                                assert!(elem > last);
+                                last = elem;
+                                received += 1;
+                            }
+                            Err(Error::Lagged(n)) => lagged += n,
+                            Err(error) => {
+                                assert_eq!(error, Error::Disconnected);
+                                break;
+                            }
+                        }
+                    }
+
+                    received + lagged
+                })
+            })
+            .collect();
+        drop(reader);
+
+        for i in 0..COUNT {
+            writer.send(i);
+        }
+        drop(writer);
+
+        for handle in readers {
+            assert_eq!(handle.join().unwrap(), COUNT as u64);
+        }
+    }
+}
@@ -0,0 +1,376 @@
+//! The collection module contains the [`CyclicCollection`] trait. Which allows generic code to accept any of the cyclic data types - rather than a single concrete type.
+//!
+//! ```
+//! # use cyclic_data_types::collection::CyclicCollection;
+//! # use cyclic_data_types::queue::Queue;
+//! # use cyclic_data_types::stack::Stack;
+//! fn fill<C: CyclicCollection<Item = i64>>(collection: &mut C) {
+//!     let mut i = 0;
+//!     while !collection.is_full() {
+//!         collection.push(i).unwrap();
+//!         i += 1;
+//!     }
+//! }
+//!
+//! let mut queue: Queue<3, i64, false> = Queue::default();
+//! let mut stack: Stack<3, i64, false> = Stack::default();
+//!
+//! fill(&mut queue);
+//! fill(&mut stack);
+//!
+//! assert_eq!(queue.pop(), Some(0));
+//! assert_eq!(stack.pop(), Some(2));
+//! ```
+
+use crate::{
+    error::Error,
+    list::{EvictingList, GrowableIter, GrowableList, Iter, List, SeqList},
+    queue::Queue,
+    stack::Stack,
+    storage::Storage,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// `CyclicCollection` is the trait implemented by every cyclic data type in the crate.
+///
+/// Each collection decides which end `push`, `pop` and `peek` operate on.
+/// * Lists and stacks push to and pop from the back - similarly to [`Vec`].
+/// * Queues and sequenced lists push to the back and pop from the front.
+///
+/// In all cases, `peek` returns a reference to the element `pop` would remove; and `iter` iterates from the front of the collection to the back.
+///
+/// # Excluded types
+/// Types which are built on a cyclic list - but cannot honour `push`, `pop`, `peek` and `iter` - deliberately do not implement the trait.
+/// * [`History`](crate::stack::History) moves actions between its undo and redo stacks - undoing an action does not remove it from the history.
+/// * [`WindowStats`](crate::stats::WindowStats) and [`MinMaxWindow`](crate::stats::MinMaxWindow) only lose values as newer values expire them - removing a value would break the running statistics.
+/// * [`TimeBuckets`](crate::time::TimeBuckets) is indexed by time - counts are added to the bucket covering a timestamp, rather than pushed.
+/// * [`TimerWheel`](crate::timer_wheel::TimerWheel) is ordered by deadline - timers are scheduled and fired, rather than pushed and popped.
+/// * [`ClockCache`](crate::cache::ClockCache) and [`LruCache`](crate::cache::LruCache) are keyed - entries are evicted by the cache, rather than popped.
+/// * [`SpscQueue`](crate::queue::spsc::SpscQueue) and [`MpmcQueue`](crate::queue::mpmc::MpmcQueue) are used from several threads at once - no single handle both pushes and pops, and the elements cannot be iterated while another thread is pushing or popping.
+pub trait CyclicCollection {
+    /// The type of element stored in the collection
+    type Item;
+
+    /// Iterator over the elements of the collection
+    type Iter<'a>: Iterator<Item = &'a Self::Item>
+    where
+        Self: 'a;
+
+    /// Returns the number of elements in the collection
+    ///
+    /// ```
+    /// # use cyclic_data_types::collection::CyclicCollection;
+    /// # use cyclic_data_types::list::List;
+    /// let list: List<5, i64, false> = [1, 2, 3, 4, 5].into();
+    ///
+    /// assert_eq!(CyclicCollection::len(&list), 5);
+    /// ```
+    fn len(&self) -> usize;
+
+    /// Returns the maximum number of elements the collection can hold before it overflows
+    ///
+    /// ```
+    /// # use cyclic_data_types::collection::CyclicCollection;
+    /// # use cyclic_data_types::list::List;
+    /// let list: List<5, i64, false> = List::default();
+    ///
+    /// assert_eq!(list.capacity(), 5);
+    /// ```
+    fn capacity(&self) -> usize;
+
+    /// Returns `true` if the collection contains no elements
+    ///
+    /// ```
+    /// # use cyclic_data_types::collection::CyclicCollection;
+    /// # use cyclic_data_types::list::List;
+    /// let list: List<5, i64, false> = List::default();
+    ///
+    /// assert!(list.is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if pushing another element would overflow the collection
+    ///
+    /// ```
+    /// # use cyclic_data_types::collection::CyclicCollection;
+    /// # use cyclic_data_types::list::List;
+    /// let list: List<5, i64, false> = [1, 2, 3, 4, 5].into();
+    ///
+    /// assert!(list.is_full());
+    /// ```
+    fn is_full(&self) -> bool {
+        self.len() >= self.capacity()
+    }
+
+    /// Adds an element to the collection. Overflow is handled using the `WRITE_OVER` flag of the collection.
+    ///
+    /// ```
+    /// # use cyclic_data_types::collection::CyclicCollection;
+    /// # use cyclic_data_types::error::Error;
+    /// # use cyclic_data_types::stack::Stack;
+    /// let mut stack: Stack<1, i64, false> = Stack::default();
+    ///
+    /// assert_eq!(CyclicCollection::push(&mut stack, 1), Ok(()));
+    /// assert_eq!(CyclicCollection::push(&mut stack, 2), Err(Error::Overflow));
+    /// ```
+    fn push(&mut self, elem: Self::Item) -> Result<(), Error>;
+
+    /// Removes an element from the collection
+    ///
+    /// ```
+    /// # use cyclic_data_types::collection::CyclicCollection;
+    /// # use cyclic_data_types::queue::Queue;
+    /// let mut queue: Queue<3, i64, false> = [1, 2, 3].into();
+    ///
+    /// assert_eq!(CyclicCollection::pop(&mut queue), Some(1));
+    /// ```
+    fn pop(&mut self) -> Option<Self::Item>;
+
+    /// Returns a reference to the element [`CyclicCollection::pop`] would remove
+    ///
+    /// ```
+    /// # use cyclic_data_types::collection::CyclicCollection;
+    /// # use cyclic_data_types::list::List;
+    /// let list: List<3, i64, false> = [1, 2, 3].into();
+    ///
+    /// assert_eq!(CyclicCollection::peek(&list), Some(&3));
+    /// ```
+    fn peek(&self) -> Option<&Self::Item>;
+
+    /// Creates an iterator object that iterates over the elements in the collection - from front to back
+    ///
+    /// ```
+    /// # use cyclic_data_types::collection::CyclicCollection;
+    /// # use cyclic_data_types::stack::Stack;
+    /// let stack: Stack<3, i64, false> = [1, 2, 3].into();
+    ///
+    /// assert_eq!(CyclicCollection::iter(&stack).copied().collect::<Vec<i64>>(), vec![1, 2, 3]);
+    /// ```
+    fn iter(&self) -> Self::Iter<'_>;
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool, S> CyclicCollection
+    for List<SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    type Item = T;
+
+    type Iter<'a>
+        = Iter<'a, SIZE, T, WRITE_OVER, S>
+    where
+        S: 'a,
+        T: 'a;
+
+    fn len(&self) -> usize {
+        List::len(self)
+    }
+
+    fn capacity(&self) -> usize {
+        List::capacity(self)
+    }
+
+    fn push(&mut self, elem: T) -> Result<(), Error> {
+        self.push_back(elem).map(|_| ())
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.remove_back()
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.get(-1)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        List::iter(self)
+    }
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool, S> CyclicCollection
+    for Queue<SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    type Item = T;
+
+    type Iter<'a>
+        = Iter<'a, SIZE, T, WRITE_OVER, S>
+    where
+        S: 'a,
+        T: 'a;
+
+    fn len(&self) -> usize {
+        Queue::len(self)
+    }
+
+    fn capacity(&self) -> usize {
+        List::capacity(self)
+    }
+
+    fn push(&mut self, elem: T) -> Result<(), Error> {
+        self.enqueue(elem).map(|_| ())
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.dequeue()
+    }
+
+    fn peek(&self) -> Option<&T> {
+        Queue::peek(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        List::iter(self)
+    }
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool, S> CyclicCollection
+    for Stack<SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    type Item = T;
+
+    type Iter<'a>
+        = Iter<'a, SIZE, T, WRITE_OVER, S>
+    where
+        S: 'a,
+        T: 'a;
+
+    fn len(&self) -> usize {
+        Stack::len(self)
+    }
+
+    fn capacity(&self) -> usize {
+        List::capacity(self)
+    }
+
+    fn push(&mut self, elem: T) -> Result<(), Error> {
+        Stack::push(self, elem).map(|_| ())
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        Stack::pop(self)
+    }
+
+    fn peek(&self) -> Option<&T> {
+        Stack::peek(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        List::iter(self)
+    }
+}
+
+impl<const SIZE: usize, T> CyclicCollection for GrowableList<SIZE, T> {
+    type Item = T;
+
+    type Iter<'a>
+        = GrowableIter<'a, SIZE, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        GrowableList::len(self)
+    }
+
+    /// Returns the current capacity of the list. As the list grows instead of overflowing, pushing to a full list always succeeds.
+    fn capacity(&self) -> usize {
+        GrowableList::capacity(self)
+    }
+
+    fn push(&mut self, elem: T) -> Result<(), Error> {
+        self.push_back(elem);
+
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.remove_back()
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.get(-1)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        GrowableList::iter(self)
+    }
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool, F> CyclicCollection
+    for EvictingList<SIZE, T, WRITE_OVER, F>
+where
+    F: FnMut(T),
+{
+    type Item = T;
+
+    type Iter<'a>
+        = Iter<'a, SIZE, T, WRITE_OVER>
+    where
+        Self: 'a;
+
+    fn len(&self) -> usize {
+        List::len(self)
+    }
+
+    fn capacity(&self) -> usize {
+        SIZE
+    }
+
+    fn push(&mut self, elem: T) -> Result<(), Error> {
+        self.push_back(elem).map(|_| ())
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.remove_back()
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.get(-1)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        List::iter(self)
+    }
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool> CyclicCollection
+    for SeqList<SIZE, T, WRITE_OVER>
+{
+    type Item = T;
+
+    type Iter<'a>
+        = Iter<'a, SIZE, T, WRITE_OVER>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        List::len(self)
+    }
+
+    fn capacity(&self) -> usize {
+        SIZE
+    }
+
+    fn push(&mut self, elem: T) -> Result<(), Error> {
+        self.push_back(elem).map(|_| ())
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.remove_front().map(|(_, elem)| elem)
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        List::iter(self)
+    }
+}
@@ -0,0 +1,116 @@
+mod generic {
+    use crate::{
+        collection::CyclicCollection,
+        error::Error,
+        list::{GrowableList, HeapList, List, SeqList},
+        queue::{HeapQueue, Queue},
+        stack::{HeapStack, Stack},
+    };
+
+    fn fill<C: CyclicCollection<Item = i64>>(collection: &mut C) -> Vec<i64> {
+        let mut i = 0;
+        while !collection.is_full() {
+            assert_eq!(collection.push(i), Ok(()));
+            i += 1;
+        }
+
+        collection.iter().copied().collect()
+    }
+
+    fn drain<C: CyclicCollection<Item = i64>>(collection: &mut C) -> Vec<i64> {
+        let mut popped = Vec::new();
+
+        while let Some(peeked) = collection.peek().copied() {
+            assert_eq!(collection.pop(), Some(peeked));
+            popped.push(peeked);
+        }
+        assert!(collection.is_empty());
+
+        popped
+    }
+
+    #[test]
+    fn list() {
+        let mut list: List<3, i64, false> = List::default();
+
+        assert_eq!(fill(&mut list), vec![0, 1, 2]);
+        assert_eq!(list.push(3), Err(Error::Overflow));
+        assert_eq!(drain(&mut list), vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn write_over_list() {
+        let mut list: List<3, i64, true> = [0, 1, 2].into();
+
+        assert!(list.is_full());
+        assert_eq!(list.push(3), Ok(()));
+        assert_eq!(CyclicCollection::len(&list), 3);
+        assert_eq!(drain(&mut list), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn queue() {
+        let mut queue: Queue<3, i64, false> = Queue::default();
+
+        assert_eq!(fill(&mut queue), vec![0, 1, 2]);
+        assert_eq!(CyclicCollection::push(&mut queue, 3), Err(Error::Overflow));
+        assert_eq!(drain(&mut queue), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn stack() {
+        let mut stack: Stack<3, i64, false> = Stack::default();
+
+        assert_eq!(fill(&mut stack), vec![0, 1, 2]);
+        assert_eq!(CyclicCollection::push(&mut stack, 3), Err(Error::Overflow));
+        assert_eq!(drain(&mut stack), vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn heap() {
+        let mut list: HeapList<i64, false> = HeapList::new(3).unwrap();
+        let mut queue: HeapQueue<i64, false> = HeapQueue::new(3).unwrap();
+        let mut stack: HeapStack<i64, false> = HeapStack::new(3).unwrap();
+
+        assert_eq!(fill(&mut list), vec![0, 1, 2]);
+        assert_eq!(fill(&mut queue), vec![0, 1, 2]);
+        assert_eq!(fill(&mut stack), vec![0, 1, 2]);
+
+        assert_eq!(drain(&mut list), vec![2, 1, 0]);
+        assert_eq!(drain(&mut queue), vec![0, 1, 2]);
+        assert_eq!(drain(&mut stack), vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn growable() {
+        let mut list: GrowableList<2, i64> = GrowableList::new();
+
+        assert_eq!(fill(&mut list), vec![0, 1]);
+        assert_eq!(list.push(2), Ok(()));
+        assert!(!list.is_full());
+        assert_eq!(CyclicCollection::capacity(&list), 4);
+        assert_eq!(drain(&mut list), vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn evicting() {
+        let mut evicted = Vec::new();
+        let mut list = List::<2, i64, true>::default().with_evict(|elem| evicted.push(elem));
+
+        assert_eq!(fill(&mut list), vec![0, 1]);
+        assert_eq!(CyclicCollection::push(&mut list, 2), Ok(()));
+        assert_eq!(drain(&mut list), vec![2, 1]);
+        assert_eq!(evicted, vec![0]);
+    }
+
+    #[test]
+    fn sequenced() {
+        let mut list: SeqList<3, i64, true> = SeqList::new();
+
+        assert_eq!(fill(&mut list), vec![0, 1, 2]);
+        assert_eq!(CyclicCollection::push(&mut list, 3), Ok(()));
+        assert_eq!(list.first_seq(), Some(1));
+        assert_eq!(drain(&mut list), vec![1, 2, 3]);
+        assert_eq!(list.next_seq(), 4);
+    }
+}
@@ -1,7 +1,23 @@
 //! This module contains all enums to the define errors that could occur at run time.
 
 /// Error enum defines all the possible errors that can occur on run time.
+///
+/// The enum is `#[non_exhaustive]` - as new data types add new errors. As a result, matching on an `Error` outside of this crate requires a wildcard arm.
+///
+/// ```
+/// # use cyclic_data_types::error::Error;
+/// fn describe(error: Error) -> &'static str {
+///     match error {
+///         Error::Overflow => "full",
+///         Error::Timeout => "timed out",
+///         _ => "other",
+///     }
+/// }
+///
+/// assert_eq!(describe(Error::Overflow), "full");
+/// ```
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Error {
     /// IndexOutOfRange is thrown when a retrieval function(`.get(index)` or `list[index]`) is run with an index that does not exist within the function co-domain.
     IndexOutOfRange,
@@ -11,4 +27,32 @@ pub enum Error {
 
     /// InvalidSize is thrown when a data structure or type is too large to be converted into any of the cyclic data type variant
     InvalidSize,
+
+    /// Disconnected is thrown when a channel is used after every handle on the other side of the channel has been dropped
+    Disconnected,
+
+    /// Timeout is thrown when a blocking operation could not be completed before its deadline
+    Timeout,
+
+    /// Lagged is thrown when a reader has fallen behind a write over data type - and the given number of elements were written over before they could be read
+    Lagged(u64),
+
+    /// Overwritten is thrown when an element is requested by a sequence number that has already been written over or removed
+    Overwritten,
+}
+
+/// SendError is returned when a message could not be sent into a channel - handing the unsent message back, so that it can be retried or recovered.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SendError<T> {
+    /// The reason the message could not be sent - either [`Error::Disconnected`] or [`Error::Timeout`]
+    pub error: Error,
+
+    /// The message which could not be sent
+    pub elem: T,
+}
+
+impl<T> From<SendError<T>> for Error {
+    fn from(value: SendError<T>) -> Self {
+        value.error
+    }
 }
@@ -6,34 +6,42 @@
 use std::{
     array,
     fmt::{Debug, Display},
-    mem::{self, MaybeUninit},
-    ops::{Index, IndexMut},
+    marker::PhantomData,
+    mem::{self, ManuallyDrop, MaybeUninit},
     ptr,
 };
 
+use storage::{Slot, Storage};
+
 pub mod list;
 pub mod queue;
 pub mod stack;
-
+pub mod stats;
+pub mod storage;
+pub mod time;
+pub mod timer_wheel;
+
+pub mod cache;
+pub mod channel;
+pub mod collection;
 pub mod error;
 
-#[derive(Clone, Eq)]
-pub(crate) struct CyclicList<const SIZE: usize, T: Sized, const WRITE_OVER: bool> {
-    pub list: [Option<T>; SIZE],
+pub(crate) struct CyclicList<
+    const SIZE: usize,
+    T: Sized,
+    const WRITE_OVER: bool,
+    S = [Option<T>; SIZE],
+> where
+    S: Storage<T>,
+{
+    pub list: S,
     pub(crate) start: usize,
     pub(crate) end: usize,
     pub(crate) empty: bool,
+    pub(crate) elem: PhantomData<T>,
 }
 
 impl<const SIZE: usize, T, const WRITE_OVER: bool> CyclicList<SIZE, T, WRITE_OVER> {
-    pub(crate) fn new(list: [Option<T>; SIZE], start: usize, end: usize, empty: bool) -> Self {
-        Self {
-            list,
-            start,
-            end,
-            empty,
-        }
-    }
     pub(crate) unsafe fn new_empty(initializer: fn() -> T) -> Self {
         let list: [Option<T>; SIZE] = {
             let mut list: [Option<T>; SIZE] = unsafe { MaybeUninit::uninit().assume_init() };
@@ -47,11 +55,21 @@ impl<const SIZE: usize, T, const WRITE_OVER: bool> CyclicList<SIZE, T, WRITE_OVE
             unsafe { mem::transmute::<_, [Option<T>; SIZE]>(list) }
         };
 
+        Self::new(list, 0, 0, true)
+    }
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool, S> CyclicList<SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    pub(crate) fn new(list: S, start: usize, end: usize, empty: bool) -> Self {
         Self {
             list,
-            start: 0,
-            end: 0,
-            empty: true,
+            start,
+            end,
+            empty,
+            elem: PhantomData,
         }
     }
 
@@ -61,43 +79,148 @@ impl<const SIZE: usize, T, const WRITE_OVER: bool> CyclicList<SIZE, T, WRITE_OVE
         }
 
         if self.end < self.start {
-            return SIZE - self.start + self.end + 1;
+            return self.capacity() - self.start + self.end + 1;
         }
 
         self.end - self.start + 1
     }
 
+    pub(crate) fn capacity(&self) -> usize {
+        self.list.capacity()
+    }
+
+    /// Returns `true` if the slot at `index` of the underlying storage holds an element
+    pub(crate) fn occupied(&self, index: usize) -> bool {
+        if self.empty {
+            return false;
+        }
+
+        if self.start <= self.end {
+            return self.start <= index && index <= self.end;
+        }
+
+        index <= self.end || self.start <= index
+    }
+
     pub unsafe fn get_unchecked(&self, index: usize) -> Option<&T> {
-        unsafe { self.list.get_unchecked(index).as_ref() }
+        match self.occupied(index) {
+            true => Some(unsafe { self.list.slots().get_unchecked(index).get() }),
+            false => None,
+        }
     }
 
     pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> Option<&mut T> {
-        unsafe { self.list.get_unchecked_mut(index).as_mut() }
+        match self.occupied(index) {
+            true => Some(unsafe { self.list.slots_mut().get_unchecked_mut(index).get_mut() }),
+            false => None,
+        }
+    }
+
+    /// Returns the element in the (occupied) slot at `index`
+    pub(crate) fn slot(&self, index: usize) -> &T {
+        debug_assert!(self.occupied(index));
+
+        unsafe { self.list.slots()[index].get() }
+    }
+
+    /// Returns the element in the (occupied) slot at `index` mutably
+    pub(crate) fn slot_mut(&mut self, index: usize) -> &mut T {
+        debug_assert!(self.occupied(index));
+
+        unsafe { self.list.slots_mut()[index].get_mut() }
+    }
+
+    /// Moves the element out of the slot at `index` - which the caller has just marked as vacant
+    pub(crate) unsafe fn take(&mut self, index: usize) -> T {
+        unsafe { self.list.slots_mut()[index].take() }
+    }
+
+    /// Writes an element into the slot at `index` - which the caller has just marked as occupied
+    pub(crate) unsafe fn write(&mut self, index: usize, elem: T) {
+        unsafe { self.list.slots_mut()[index].write(elem) }
+    }
+
+    /// Converts the list into a list with a different `WRITE_OVER` flag
+    pub(crate) fn into_write_over<const W: bool>(self) -> CyclicList<SIZE, T, W, S> {
+        let list = ManuallyDrop::new(self);
+
+        // `list` is never dropped - so the storage is only owned by the new list
+        CyclicList::new(
+            unsafe { ptr::read(&list.list) },
+            list.start,
+            list.end,
+            list.empty,
+        )
+    }
+
+    /// Drops every element in the list - returning the underlying storage
+    pub(crate) fn into_storage(mut self) -> S {
+        self.clear();
+
+        let list = ManuallyDrop::new(self);
+
+        // the list is empty - so the storage can be moved out without dropping any elements
+        unsafe { ptr::read(&list.list) }
+    }
+
+    /// Drops every element in the list
+    fn clear(&mut self) {
+        let len = self.len();
+        let start = self.start;
+        let capacity = self.capacity();
+
+        self.empty = true;
+
+        for offset in 0..len {
+            drop(unsafe { self.take((start + offset) % capacity) });
+        }
     }
 
     fn increment_start(&self) -> usize {
-        (self.start + 1) % SIZE
+        (self.start + 1) % self.capacity()
     }
     fn decrement_start(&self) -> usize {
         match self.start.checked_sub(1) {
             Some(start) => start,
-            None => SIZE - 1,
+            None => self.capacity() - 1,
         }
     }
 
     fn increment_end(&self) -> usize {
-        (self.end + 1) % SIZE
+        (self.end + 1) % self.capacity()
     }
     fn decrement_end(&self) -> usize {
         match self.end.checked_sub(1) {
             Some(end) => end,
-            None => SIZE - 1,
+            None => self.capacity() - 1,
+        }
+    }
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool, S> Drop for CyclicList<SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    fn drop(&mut self) {
+        if mem::needs_drop::<T>() {
+            self.clear();
         }
     }
 }
 
-impl<const SIZE: usize, T, const WRITE_OVER: bool> PartialEq for CyclicList<SIZE, T, WRITE_OVER>
+impl<const SIZE: usize, T, const WRITE_OVER: bool, S> Clone for CyclicList<SIZE, T, WRITE_OVER, S>
 where
+    S: Storage<T> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.list.clone(), self.start, self.end, self.empty)
+    }
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool, S> PartialEq
+    for CyclicList<SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
     T: PartialEq,
 {
     fn eq(&self, other: &Self) -> bool {
@@ -105,23 +228,23 @@ where
             return false;
         }
 
-        let mut p1 = self.start;
-        let mut p2 = other.start;
-        while (p1, p2) != (self.end, other.end) {
-            if self[p1] != other[p2] {
-                return false;
-            }
-
-            p1 = (p1 + 1) % SIZE;
-            p2 = (p2 + 1) % SIZE;
-        }
-
-        return true;
+        (0..self.len()).all(|offset| {
+            self.slot((self.start + offset) % self.capacity())
+                == other.slot((other.start + offset) % other.capacity())
+        })
     }
 }
 
-impl<const SIZE: usize, T, const WRITE_OVER: bool> Display for CyclicList<SIZE, T, WRITE_OVER>
+impl<const SIZE: usize, T, const WRITE_OVER: bool, S> Eq for CyclicList<SIZE, T, WRITE_OVER, S>
 where
+    S: Storage<T>,
+    T: Eq,
+{
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool, S> Display for CyclicList<SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
     T: Display,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -134,23 +257,31 @@ where
         let mut pointer = self.start;
 
         while pointer != self.end {
-            str.push_str(&format!("{}, ", self[pointer].as_ref().unwrap()));
-            pointer = (pointer + 1) % SIZE;
+            str.push_str(&format!("{}, ", self.slot(pointer)));
+            pointer = (pointer + 1) % self.capacity();
         }
 
-        str.push_str(&format!("{}", self[self.end].as_ref().unwrap()));
+        str.push_str(&format!("{}", self.slot(self.end)));
 
         write!(f, "[{}]", str)
     }
 }
 
-impl<const SIZE: usize, T, const WRITE_OVER: bool> Debug for CyclicList<SIZE, T, WRITE_OVER>
+impl<const SIZE: usize, T, const WRITE_OVER: bool, S> Debug for CyclicList<SIZE, T, WRITE_OVER, S>
 where
+    S: Storage<T>,
     T: Debug,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let slots: Vec<Option<&T>> = (0..self.capacity())
+            .map(|index| match self.occupied(index) {
+                true => Some(self.slot(index)),
+                false => None,
+            })
+            .collect();
+
         f.debug_struct("CyclicList")
-            .field("list", &self.list)
+            .field("list", &slots)
             .field("start", &self.start)
             .field("end", &self.end)
             .field("size", &self.len())
@@ -163,40 +294,7 @@ where
     T: Default,
 {
     fn default() -> Self {
-        let list: [Option<T>; SIZE] = array::from_fn(|_| None);
-
-        Self {
-            list,
-            start: 0,
-            end: 0,
-            empty: true,
-        }
-    }
-}
-
-impl<const SIZE: usize, T, const WRITE_OVER: bool> Index<usize>
-    for CyclicList<SIZE, T, WRITE_OVER>
-{
-    type Output = Option<T>;
-
-    fn index(&self, index: usize) -> &Self::Output {
-        // if self.len() <= index{
-        //     panic!("{:?}", Error::IndexOutOfRange);
-        // }
-
-        &self.list[index]
-    }
-}
-
-impl<const SIZE: usize, T, const WRITE_OVER: bool> IndexMut<usize>
-    for CyclicList<SIZE, T, WRITE_OVER>
-{
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        // if self.len() <= index{
-        //     panic!("{:?}", Error::IndexOutOfRange);
-        // }
-
-        &mut self.list[index]
+        Self::new(array::from_fn(|_| None), 0, 0, true)
     }
 }
 
@@ -212,27 +310,26 @@ impl<const LIST_SIZE: usize, T, const WRITE_OVER: bool> From<[T; LIST_SIZE]>
             .enumerate()
             .for_each(|(index, val)| list[index] = val);
 
-        CyclicList {
-            list,
-            start: 0,
-            end: LIST_SIZE - 1,
-            empty: false,
-        }
+        CyclicList::new(list, 0, LIST_SIZE - 1, false)
     }
 }
 
-impl<const LIST_SIZE: usize, T> From<CyclicList<LIST_SIZE, T, true>>
-    for CyclicList<LIST_SIZE, T, false>
+impl<const LIST_SIZE: usize, T, S> From<CyclicList<LIST_SIZE, T, true, S>>
+    for CyclicList<LIST_SIZE, T, false, S>
+where
+    S: Storage<T>,
 {
-    fn from(value: CyclicList<LIST_SIZE, T, true>) -> Self {
-        Self::new(value.list, value.start, value.end, value.empty)
+    fn from(value: CyclicList<LIST_SIZE, T, true, S>) -> Self {
+        value.into_write_over()
     }
 }
 
-impl<const LIST_SIZE: usize, T> From<CyclicList<LIST_SIZE, T, false>>
-    for CyclicList<LIST_SIZE, T, true>
+impl<const LIST_SIZE: usize, T, S> From<CyclicList<LIST_SIZE, T, false, S>>
+    for CyclicList<LIST_SIZE, T, true, S>
+where
+    S: Storage<T>,
 {
-    fn from(value: CyclicList<LIST_SIZE, T, false>) -> Self {
-        Self::new(value.list, value.start, value.end, value.empty)
+    fn from(value: CyclicList<LIST_SIZE, T, false, S>) -> Self {
+        value.into_write_over()
     }
 }
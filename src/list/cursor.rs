@@ -0,0 +1,425 @@
+use std::fmt::Debug;
+
+use crate::{error::Error, storage::Storage};
+
+use super::List;
+
+/// `Cursor` is a persistent position within a [`List`]. Which can move forward and backward through the list - wrapping around from the back of the list to the front (and vice versa).
+///
+/// ```
+/// # use cyclic_data_types::list::List;
+/// # const SIZE: usize = 5;
+/// let list: List<SIZE, i64, false> = vec![1, 2, 3].try_into().unwrap();
+///
+/// let mut cursor = list.cursor(2);
+///
+/// assert_eq!(cursor.current(), Some(&3));
+///
+/// cursor.move_next();
+///
+/// assert_eq!(cursor.current(), Some(&1));
+/// assert_eq!(cursor.peek_prev(), Some(&3));
+/// ```
+pub struct Cursor<'a, const SIZE: usize, T, const WRITE_OVER: bool, S = [Option<T>; SIZE]>
+where
+    S: Storage<T>,
+{
+    list: &'a List<SIZE, T, WRITE_OVER, S>,
+    index: usize,
+}
+
+impl<'a, const SIZE: usize, T, const WRITE_OVER: bool, S> Cursor<'a, SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    pub(crate) fn new(list: &'a List<SIZE, T, WRITE_OVER, S>, index: usize) -> Self {
+        let index = index.checked_rem(list.len()).unwrap_or(0);
+
+        Self { list, index }
+    }
+
+    /// Returns the index of the cursor - or `None` if the list is empty.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list: List<SIZE, i64, false> = vec![1, 2, 3].try_into().unwrap();
+    ///
+    /// assert_eq!(list.cursor(4).index(), Some(1));
+    /// ```
+    pub fn index(&self) -> Option<usize> {
+        (!self.list.is_empty()).then_some(self.index)
+    }
+
+    /// Returns a reference to the element under the cursor - or `None` if the list is empty.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list: List<SIZE, i64, false> = vec![1, 2, 3].try_into().unwrap();
+    ///
+    /// assert_eq!(list.cursor(0).current(), Some(&1));
+    /// ```
+    pub fn current(&self) -> Option<&'a T> {
+        self.list.get(self.index as isize)
+    }
+
+    /// Returns a reference to the element after the cursor - wrapping around to the front of the list.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list: List<SIZE, i64, false> = vec![1, 2, 3].try_into().unwrap();
+    ///
+    /// assert_eq!(list.cursor(2).peek_next(), Some(&1));
+    /// ```
+    pub fn peek_next(&self) -> Option<&'a T> {
+        self.list.get(self.index as isize + 1)
+    }
+
+    /// Returns a reference to the element before the cursor - wrapping around to the back of the list.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list: List<SIZE, i64, false> = vec![1, 2, 3].try_into().unwrap();
+    ///
+    /// assert_eq!(list.cursor(0).peek_prev(), Some(&3));
+    /// ```
+    pub fn peek_prev(&self) -> Option<&'a T> {
+        self.list.get(self.index as isize - 1)
+    }
+
+    /// Moves the cursor to the next element - wrapping around to the front of the list.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list: List<SIZE, i64, false> = vec![1, 2].try_into().unwrap();
+    ///
+    /// let mut cursor = list.cursor(0);
+    ///
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), Some(&2));
+    ///
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), Some(&1));
+    /// ```
+    pub fn move_next(&mut self) {
+        self.index = next_index(self.index, self.list.len());
+    }
+
+    /// Moves the cursor to the previous element - wrapping around to the back of the list.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list: List<SIZE, i64, false> = vec![1, 2].try_into().unwrap();
+    ///
+    /// let mut cursor = list.cursor(0);
+    ///
+    /// cursor.move_prev();
+    /// assert_eq!(cursor.current(), Some(&2));
+    /// ```
+    pub fn move_prev(&mut self) {
+        self.index = prev_index(self.index, self.list.len());
+    }
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool, S> Clone for Cursor<'_, SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list,
+            index: self.index,
+        }
+    }
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool, S> Debug for Cursor<'_, SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cursor")
+            .field("index", &self.index())
+            .field("current", &self.current())
+            .finish()
+    }
+}
+
+/// `CursorMut` is a persistent position within a [`List`] - which can also update the element under the cursor, and insert or remove elements at the cursor.
+///
+/// *NOTE:* Inserting through a cursor never writes over an element. Therefore, inserting into a full list results in an [`Error::Overflow`] - regardless of the `WRITE_OVER` flag.
+///
+/// ```
+/// # use cyclic_data_types::list::List;
+/// # const SIZE: usize = 5;
+/// let mut list: List<SIZE, i64, false> = vec![1, 2, 3].try_into().unwrap();
+///
+/// let mut cursor = list.cursor_mut(1);
+///
+/// assert_eq!(cursor.remove_current(), Some(2));
+/// assert_eq!(cursor.current(), Some(&3));
+///
+/// assert!(cursor.insert_before(4).is_ok());
+///
+/// assert_eq!(list, vec![1, 4, 3].try_into().unwrap());
+/// ```
+pub struct CursorMut<'a, const SIZE: usize, T, const WRITE_OVER: bool, S = [Option<T>; SIZE]>
+where
+    S: Storage<T>,
+{
+    list: &'a mut List<SIZE, T, WRITE_OVER, S>,
+    index: usize,
+}
+
+impl<'a, const SIZE: usize, T, const WRITE_OVER: bool, S> CursorMut<'a, SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    pub(crate) fn new(list: &'a mut List<SIZE, T, WRITE_OVER, S>, index: usize) -> Self {
+        let index = index.checked_rem(list.len()).unwrap_or(0);
+
+        Self { list, index }
+    }
+
+    /// Returns the index of the cursor - or `None` if the list is empty.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list: List<SIZE, i64, false> = vec![1, 2, 3].try_into().unwrap();
+    ///
+    /// assert_eq!(list.cursor_mut(4).index(), Some(1));
+    /// ```
+    pub fn index(&self) -> Option<usize> {
+        (!self.list.is_empty()).then_some(self.index)
+    }
+
+    /// Returns a reference to the element under the cursor - or `None` if the list is empty.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list: List<SIZE, i64, false> = vec![1, 2, 3].try_into().unwrap();
+    ///
+    /// assert_eq!(list.cursor_mut(0).current(), Some(&1));
+    /// ```
+    pub fn current(&self) -> Option<&T> {
+        self.list.get(self.index as isize)
+    }
+
+    /// Returns a mutable reference to the element under the cursor - or `None` if the list is empty.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list: List<SIZE, i64, false> = vec![1, 2, 3].try_into().unwrap();
+    ///
+    /// if let Some(elem) = list.cursor_mut(0).current_mut() {
+    ///     *elem = 10;
+    /// }
+    ///
+    /// assert_eq!(list.get(0), Some(&10));
+    /// ```
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        self.list.get_mut(self.index as isize)
+    }
+
+    /// Returns a reference to the element after the cursor - wrapping around to the front of the list.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list: List<SIZE, i64, false> = vec![1, 2, 3].try_into().unwrap();
+    ///
+    /// assert_eq!(list.cursor_mut(2).peek_next(), Some(&1));
+    /// ```
+    pub fn peek_next(&self) -> Option<&T> {
+        self.list.get(self.index as isize + 1)
+    }
+
+    /// Returns a reference to the element before the cursor - wrapping around to the back of the list.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list: List<SIZE, i64, false> = vec![1, 2, 3].try_into().unwrap();
+    ///
+    /// assert_eq!(list.cursor_mut(0).peek_prev(), Some(&3));
+    /// ```
+    pub fn peek_prev(&self) -> Option<&T> {
+        self.list.get(self.index as isize - 1)
+    }
+
+    /// Moves the cursor to the next element - wrapping around to the front of the list.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list: List<SIZE, i64, false> = vec![1, 2].try_into().unwrap();
+    ///
+    /// let mut cursor = list.cursor_mut(1);
+    ///
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), Some(&1));
+    /// ```
+    pub fn move_next(&mut self) {
+        self.index = next_index(self.index, self.list.len());
+    }
+
+    /// Moves the cursor to the previous element - wrapping around to the back of the list.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list: List<SIZE, i64, false> = vec![1, 2].try_into().unwrap();
+    ///
+    /// let mut cursor = list.cursor_mut(0);
+    ///
+    /// cursor.move_prev();
+    /// assert_eq!(cursor.current(), Some(&2));
+    /// ```
+    pub fn move_prev(&mut self) {
+        self.index = prev_index(self.index, self.list.len());
+    }
+
+    /// Removes the element under the cursor - moving the cursor onto the next element (wrapping around to the front of the list).
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list: List<SIZE, i64, false> = vec![1, 2].try_into().unwrap();
+    ///
+    /// let mut cursor = list.cursor_mut(1);
+    ///
+    /// assert_eq!(cursor.remove_current(), Some(2));
+    /// assert_eq!(cursor.current(), Some(&1));
+    ///
+    /// assert_eq!(cursor.remove_current(), Some(1));
+    /// assert_eq!(cursor.remove_current(), None);
+    /// ```
+    pub fn remove_current(&mut self) -> Option<T> {
+        let elem = self.list.remove_at(self.index).ok()?;
+
+        if self.index >= self.list.len() {
+            self.index = 0;
+        }
+
+        Some(elem)
+    }
+
+    /// Inserts an element before the cursor. The cursor stays on the same element - or moves onto the new element if the list was empty.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list: List<SIZE, i64, false> = vec![2].try_into().unwrap();
+    ///
+    /// let mut cursor = list.cursor_mut(0);
+    ///
+    /// assert!(cursor.insert_before(1).is_ok());
+    /// assert_eq!(cursor.current(), Some(&2));
+    ///
+    /// assert_eq!(list, vec![1, 2].try_into().unwrap());
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(())` if the insert was successful
+    /// * [`Error::Overflow`] if the list is full
+    pub fn insert_before(&mut self, elem: T) -> Result<(), Error> {
+        let empty = self.list.is_empty();
+
+        self.insert(elem, self.index)?;
+
+        if !empty {
+            self.index += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Inserts an element after the cursor. The cursor stays on the same element - or moves onto the new element if the list was empty.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list: List<SIZE, i64, false> = vec![1].try_into().unwrap();
+    ///
+    /// let mut cursor = list.cursor_mut(0);
+    ///
+    /// assert!(cursor.insert_after(2).is_ok());
+    /// assert_eq!(cursor.current(), Some(&1));
+    ///
+    /// assert_eq!(list, vec![1, 2].try_into().unwrap());
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(())` if the insert was successful
+    /// * [`Error::Overflow`] if the list is full
+    pub fn insert_after(&mut self, elem: T) -> Result<(), Error> {
+        let index = match self.list.len() {
+            0 => 0,
+            _ => self.index + 1,
+        };
+
+        self.insert(elem, index)
+    }
+
+    /// Returns a read only cursor at the same position.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list: List<SIZE, i64, false> = vec![1, 2].try_into().unwrap();
+    ///
+    /// let cursor = list.cursor_mut(1);
+    ///
+    /// assert_eq!(cursor.as_cursor().current(), Some(&2));
+    /// ```
+    pub fn as_cursor(&self) -> Cursor<'_, SIZE, T, WRITE_OVER, S> {
+        Cursor::new(self.list, self.index)
+    }
+
+    /// Inserts an element at `index` - without writing over an element
+    fn insert(&mut self, elem: T, index: usize) -> Result<(), Error> {
+        if self.list.len() == self.list.capacity() {
+            return Err(Error::Overflow);
+        }
+
+        self.list.insert_at(elem, index)?;
+
+        Ok(())
+    }
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool, S> Debug
+    for CursorMut<'_, SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CursorMut")
+            .field("index", &self.index())
+            .field("current", &self.current())
+            .finish()
+    }
+}
+
+/// Returns the index after `index` in a list of `len` elements
+fn next_index(index: usize, len: usize) -> usize {
+    (index + 1).checked_rem(len).unwrap_or(0)
+}
+
+/// Returns the index before `index` in a list of `len` elements
+fn prev_index(index: usize, len: usize) -> usize {
+    match index {
+        0 => len.saturating_sub(1),
+        _ => index - 1,
+    }
+}
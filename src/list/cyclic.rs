@@ -0,0 +1,328 @@
+use crate::storage::Storage;
+
+use super::List;
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool, S> List<SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    /// Returns `true` if `other` is a rotation of the list. Such that, `[1,2,3]`, `[2,3,1]` and `[3,1,2]` are all equal up to rotation. This is done in `O(len)`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list : List<SIZE, i64, false> = vec![1,2,3].try_into().unwrap();
+    ///
+    /// let rotated : List<SIZE, i64, false> = vec![2,3,1].try_into().unwrap();
+    /// let reversed : List<SIZE, i64, false> = vec![3,2,1].try_into().unwrap();
+    ///
+    /// assert!(list.eq_up_to_rotation(&rotated));
+    /// assert!(!list.eq_up_to_rotation(&reversed));
+    /// ```
+    pub fn eq_up_to_rotation<const OTHER_SIZE: usize, const OTHER_WRITE_OVER: bool, O>(
+        &self,
+        other: &List<OTHER_SIZE, T, OTHER_WRITE_OVER, O>,
+    ) -> bool
+    where
+        O: Storage<T>,
+        T: PartialEq,
+    {
+        self.find_rotation(other).is_some()
+    }
+
+    /// Returns the number of positions the list must be rotated left by to equal `other` - or `None` if `other` is not a rotation of the list. Such that, `other[i] == list[(i + rotation) % len]`. This is done in `O(len)`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list : List<SIZE, i64, false> = vec![1,2,3,4].try_into().unwrap();
+    ///
+    /// let rotated : List<SIZE, i64, false> = vec![3,4,1,2].try_into().unwrap();
+    /// let other : List<SIZE, i64, false> = vec![1,2,4,3].try_into().unwrap();
+    ///
+    /// assert_eq!(list.find_rotation(&rotated), Some(2));
+    /// assert_eq!(list.find_rotation(&other), None);
+    /// ```
+    ///
+    /// # Returns
+    /// * `Some(rotation)` of the smallest rotation if `other` is a rotation of the list
+    /// * `None` if `other` is not a rotation of the list
+    pub fn find_rotation<const OTHER_SIZE: usize, const OTHER_WRITE_OVER: bool, O>(
+        &self,
+        other: &List<OTHER_SIZE, T, OTHER_WRITE_OVER, O>,
+    ) -> Option<usize>
+    where
+        O: Storage<T>,
+        T: PartialEq,
+    {
+        if self.len() != other.len() {
+            return None;
+        }
+
+        if self.is_empty() {
+            return Some(0);
+        }
+
+        CyclicMatches::new(self.iter().collect(), other.iter().collect()).next()
+    }
+
+    /// Rotates the list to its lexicographically smallest rotation - returning the number of positions the list was rotated left by. This is done in `O(len)` using Booth's algorithm.
+    ///
+    /// As a result, two lists which are equal up to rotation are equal after being canonicalized.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list : List<SIZE, i64, false> = vec![3,1,2,1,1].try_into().unwrap();
+    ///
+    /// assert_eq!(list.canonicalize(), 3);
+    ///
+    /// assert_eq!(list, vec![1,1,3,1,2].try_into().unwrap());
+    /// ```
+    pub fn canonicalize(&mut self) -> usize
+    where
+        T: Ord,
+    {
+        let rotation = self.least_rotation();
+
+        self.rotate_left(rotation);
+
+        rotation
+    }
+
+    /// Returns the index of the first occurrence of `pattern` in the list. The list is treated as cyclic - so an occurrence may wrap around from the back of the list to the front. This is done in `O(len + pattern.len())` using the Knuth-Morris-Pratt algorithm.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list : List<SIZE, i64, false> = [1,2,3,4,5].into();
+    ///
+    /// assert_eq!(list.find_subsequence(&[3,4]), Some(2));
+    /// // spans the seam of the list
+    /// assert_eq!(list.find_subsequence(&[5,1,2]), Some(4));
+    /// assert_eq!(list.find_subsequence(&[2,1]), None);
+    /// ```
+    ///
+    /// *NOTE:* Like [`str::find`], an empty pattern matches at the start of the list - even if the list is empty. A pattern longer than the list never matches.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list : List<SIZE, i64, false> = vec![1,2,3].try_into().unwrap();
+    /// let empty : List<SIZE, i64, false> = List::default();
+    ///
+    /// assert_eq!(list.find_subsequence(&[]), Some(0));
+    /// assert_eq!(empty.find_subsequence(&[]), Some(0));
+    /// ```
+    pub fn find_subsequence(&self, pattern: &[T]) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        if pattern.is_empty() {
+            return Some(0);
+        }
+
+        self.matches(pattern).next()
+    }
+
+    /// Returns the index of every (possibly overlapping) occurrence of `pattern` in the list - in ascending order. The list is treated as cyclic - so an occurrence may wrap around from the back of the list to the front. This is done in `O(len + pattern.len())` using the Knuth-Morris-Pratt algorithm.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list : List<SIZE, i64, false> = [1,1,2,1,1].into();
+    ///
+    /// assert_eq!(list.find_all(&[1,1]), vec![0, 3, 4]);
+    /// ```
+    ///
+    /// *NOTE:* An empty pattern matches at every index of the list. A pattern longer than the list never matches.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list : List<SIZE, i64, false> = vec![1,2,3].try_into().unwrap();
+    ///
+    /// assert_eq!(list.find_all(&[]), vec![0, 1, 2]);
+    /// ```
+    pub fn find_all(&self, pattern: &[T]) -> Vec<usize>
+    where
+        T: PartialEq,
+    {
+        if pattern.is_empty() {
+            return (0..self.len()).collect();
+        }
+
+        self.matches(pattern).collect()
+    }
+
+    /// Returns an iterator over the start of every cyclic occurrence of `pattern`
+    fn matches<'a>(&'a self, pattern: &'a [T]) -> CyclicMatches<'a, T>
+    where
+        T: PartialEq,
+    {
+        let text = match pattern.len() <= self.len() {
+            true => self.iter().collect(),
+            false => Vec::new(),
+        };
+
+        CyclicMatches::new(text, pattern.iter().collect())
+    }
+
+    /// Returns the index of the lexicographically smallest rotation of the list - using Booth's algorithm
+    fn least_rotation(&self) -> usize
+    where
+        T: Ord,
+    {
+        let len = self.len();
+        if len == 0 {
+            return 0;
+        }
+
+        let at = |index: usize| &self[index % len];
+
+        let mut failure: Vec<Option<usize>> = vec![None; 2 * len];
+        let mut k = 0;
+
+        for j in 1..2 * len {
+            let elem = at(j);
+            let mut i = failure[j - k - 1];
+
+            while let Some(prefix) = i {
+                let other = at(k + prefix + 1);
+                if elem == other {
+                    break;
+                }
+
+                if elem < other {
+                    k = j - prefix - 1;
+                }
+                i = failure[prefix];
+            }
+
+            match i {
+                Some(prefix) => failure[j - k] = Some(prefix + 1),
+                None => {
+                    if elem != at(k) {
+                        if elem < at(k) {
+                            k = j;
+                        }
+                        failure[j - k] = None;
+                    } else {
+                        failure[j - k] = Some(0);
+                    }
+                }
+            }
+        }
+
+        k % len
+    }
+
+    /// Rotates the list left by `rotation` positions - moving elements from the front to the back
+    fn rotate_left(&mut self, rotation: usize) {
+        for _ in 0..rotation {
+            if let Some(elem) = self.remove_front() {
+                self.push_back(elem)
+                    .expect("an element was just removed from the list");
+            }
+        }
+    }
+}
+
+impl<const SIZE: usize, const WRITE_OVER: bool, S> List<SIZE, u8, WRITE_OVER, S>
+where
+    S: Storage<u8>,
+{
+    /// Returns the index of the first occurrence of `byte` in the list. The underlying storage is searched as two contiguous slices (before and after the seam of the list) - rather than mapping each index of the list onto the ring.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 4;
+    /// let mut list : List<SIZE, u8, true> = List::default();
+    ///
+    /// for byte in b"abcdef" {
+    ///     assert!(list.push_back(*byte).is_ok());
+    /// }
+    ///
+    /// // the list holds "cdef" - wrapped around the underlying array
+    /// assert_eq!(list.find_byte(b'c'), Some(0));
+    /// assert_eq!(list.find_byte(b'f'), Some(3));
+    /// assert_eq!(list.find_byte(b'a'), None);
+    /// ```
+    pub fn find_byte(&self, byte: u8) -> Option<usize> {
+        let (mut front, mut back) = self.as_slices();
+        let seam = front.len();
+
+        front.position(|elem| *elem == byte).or_else(|| {
+            back.position(|elem| *elem == byte)
+                .map(|index| seam + index)
+        })
+    }
+}
+
+/// `CyclicMatches` iterates over the start of every occurrence of a pattern in a text - where the text is treated as cyclic, so occurrences may wrap around from the end of the text back to the start. Matching is done using the Knuth-Morris-Pratt algorithm, in `O(text + pattern)`.
+pub(crate) struct CyclicMatches<'a, T> {
+    text: Vec<&'a T>,
+    pattern: Vec<&'a T>,
+    failure: Vec<usize>,
+    position: usize,
+    matched: usize,
+}
+
+impl<'a, T: PartialEq> CyclicMatches<'a, T> {
+    pub(crate) fn new(text: Vec<&'a T>, pattern: Vec<&'a T>) -> Self {
+        let mut failure = vec![0; pattern.len()];
+        let mut matched = 0;
+
+        for index in 1..pattern.len() {
+            while matched > 0 && pattern[index] != pattern[matched] {
+                matched = failure[matched - 1];
+            }
+            if pattern[index] == pattern[matched] {
+                matched += 1;
+            }
+            failure[index] = matched;
+        }
+
+        Self {
+            text,
+            pattern,
+            failure,
+            position: 0,
+            matched: 0,
+        }
+    }
+}
+
+impl<T: PartialEq> Iterator for CyclicMatches<'_, T> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.text.len();
+        let pattern_len = self.pattern.len();
+
+        if len == 0 || pattern_len == 0 {
+            return None;
+        }
+
+        // every match starting in the text ends within the first `len + pattern_len - 1` elements of the cycled text
+        while self.position < len + pattern_len - 1 {
+            let elem = self.text[self.position % len];
+            self.position += 1;
+
+            while self.matched > 0 && elem != self.pattern[self.matched] {
+                self.matched = self.failure[self.matched - 1];
+            }
+            if elem == self.pattern[self.matched] {
+                self.matched += 1;
+            }
+
+            if self.matched == pattern_len {
+                self.matched = self.failure[pattern_len - 1];
+
+                return Some(self.position - pattern_len);
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,196 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::Deref,
+};
+
+use crate::error::Error;
+
+use super::List;
+
+/// `EvictingList` is a [`List`] paired with an eviction handler. The handler is called with every element that is written over when a `WRITE_OVER` list overflows - allowing rolling buffers to flush or aggregate old data as it leaves the list.
+///
+/// The handler can either be a closure or a trait object (`Box<dyn FnMut(T)>`).
+///
+/// ```
+/// # use cyclic_data_types::list::{EvictingList, List};
+/// # const SIZE: usize = 3;
+/// let mut total = 0;
+///
+/// let mut list: EvictingList<SIZE, i64, true, _> = List::default().with_evict(|elem| total += elem);
+///
+/// assert!(list.push_back(1).is_ok());
+/// assert!(list.push_back(2).is_ok());
+/// assert!(list.push_back(3).is_ok());
+/// assert!(list.push_back(4).is_ok());
+/// assert!(list.push_back(5).is_ok());
+///
+/// assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![3, 4, 5]);
+///
+/// assert_eq!(total, 3);
+/// ```
+///
+/// *NOTE:* Elements removed using `remove_front` or `remove_back` are returned to the caller and are not passed to the handler. Likewise, lists with `WRITE_OVER = false` never evict - as pushing to a full list results in an [`Error::Overflow`].
+pub struct EvictingList<const SIZE: usize, T, const WRITE_OVER: bool, F>
+where
+    F: FnMut(T),
+{
+    list: List<SIZE, T, WRITE_OVER>,
+    on_evict: F,
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool, F> EvictingList<SIZE, T, WRITE_OVER, F>
+where
+    F: FnMut(T),
+{
+    /// Creates a new `EvictingList` from a list and an eviction handler.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::{EvictingList, List};
+    /// # const SIZE: usize = 5;
+    /// let list: EvictingList<SIZE, i64, true, _> = EvictingList::new(List::default(), |_elem| {});
+    ///
+    /// assert_eq!(list.len(), 0);
+    /// ```
+    pub fn new(list: List<SIZE, T, WRITE_OVER>, on_evict: F) -> Self {
+        Self { list, on_evict }
+    }
+
+    /// Pushes a new element to the back of the list. If the list is full and `WRITE_OVER = true`, the first element is written over and passed to the eviction handler.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 2;
+    /// let mut evicted = Vec::new();
+    /// let mut list = List::<SIZE, i64, true>::from([1, 2]).with_evict(|elem| evicted.push(elem));
+    ///
+    /// assert!(list.push_back(3).is_ok());
+    ///
+    /// assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![2, 3]);
+    /// assert_eq!(evicted, vec![1]);
+    /// ```
+    ///
+    /// # Returns
+    /// * Self if the push was successful
+    /// * [Error] if List is full and the `WRITE_OVER` flag is set to `false`
+    pub fn push_back(&mut self, elem: T) -> Result<&mut Self, Error> {
+        if let Some(evicted) = self.list.push_back_displacing(elem)? {
+            (self.on_evict)(evicted);
+        }
+
+        Ok(self)
+    }
+
+    /// Pushes a new element to the front of the list. If the list is full and `WRITE_OVER = true`, the last element is written over and passed to the eviction handler.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 2;
+    /// let mut evicted = Vec::new();
+    /// let mut list = List::<SIZE, i64, true>::from([1, 2]).with_evict(|elem| evicted.push(elem));
+    ///
+    /// assert!(list.push_front(0).is_ok());
+    ///
+    /// assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![0, 1]);
+    /// assert_eq!(evicted, vec![2]);
+    /// ```
+    ///
+    /// # Returns
+    /// * Self if the push was successful
+    /// * [Error] if List is full and the `WRITE_OVER` flag is set to `false`
+    pub fn push_front(&mut self, elem: T) -> Result<&mut Self, Error> {
+        if let Some(evicted) = self.list.push_front_displacing(elem)? {
+            (self.on_evict)(evicted);
+        }
+
+        Ok(self)
+    }
+
+    /// Removes the last element from the list and returns removed element. The removed element is not passed to the eviction handler.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 3;
+    /// let mut list = List::<SIZE, i64, true>::from([1, 2, 3]).with_evict(|_elem| {});
+    ///
+    /// assert_eq!(list.remove_back(), Some(3));
+    /// ```
+    pub fn remove_back(&mut self) -> Option<T> {
+        self.list.remove_back()
+    }
+
+    /// Removes the first element from the list and returns removed element. The removed element is not passed to the eviction handler.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 3;
+    /// let mut list = List::<SIZE, i64, true>::from([1, 2, 3]).with_evict(|_elem| {});
+    ///
+    /// assert_eq!(list.remove_front(), Some(1));
+    /// ```
+    pub fn remove_front(&mut self) -> Option<T> {
+        self.list.remove_front()
+    }
+
+    /// returns a mutable reference to an element in the list at provided index. Works the same as [`List::get_mut`].
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 3;
+    /// let mut list = List::<SIZE, i64, true>::from([1, 2, 3]).with_evict(|_elem| {});
+    ///
+    /// *list.get_mut(-1).unwrap() = 4;
+    ///
+    /// assert_eq!(list.get(-1), Some(&4));
+    /// ```
+    pub fn get_mut(&mut self, index: isize) -> Option<&mut T> {
+        self.list.get_mut(index)
+    }
+
+    /// Unwraps the `EvictingList` - returning the underlying list and eviction handler.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 3;
+    /// let list = List::<SIZE, i64, true>::from([1, 2, 3]).with_evict(|_elem| {});
+    ///
+    /// let (list, _on_evict) = list.into_inner();
+    ///
+    /// assert_eq!(list, [1, 2, 3].into());
+    /// ```
+    pub fn into_inner(self) -> (List<SIZE, T, WRITE_OVER>, F) {
+        (self.list, self.on_evict)
+    }
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool, F> Deref for EvictingList<SIZE, T, WRITE_OVER, F>
+where
+    F: FnMut(T),
+{
+    type Target = List<SIZE, T, WRITE_OVER>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<const S: usize, T, const W: bool, F> Display for EvictingList<S, T, W, F>
+where
+    T: Display,
+    F: FnMut(T),
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.list)
+    }
+}
+
+impl<const S: usize, T, const W: bool, F> Debug for EvictingList<S, T, W, F>
+where
+    T: Debug,
+    F: FnMut(T),
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EvictingList")
+            .field("", &self.list)
+            .finish()
+    }
+}
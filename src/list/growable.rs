@@ -0,0 +1,448 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{Index, IndexMut},
+};
+
+use super::{HeapList, List};
+
+/// `GrowableList` is a cyclic list that stays inline (on the stack) while it holds at most `SIZE` elements. When an element is pushed to a full list, the list grows into heap storage with double the capacity - instead of rejecting or writing over elements.
+///
+/// The logical order of the elements is kept whenever the list grows. [`GrowableList::shrink_to_fit`] can be used to return to the inline storage after a burst.
+///
+/// # Generics
+///
+/// 1. `const SIZE: usize`
+///
+/// SIZE is a generic constant that defines the inline capacity of the list
+///
+/// 2. `T: Sized`
+///
+/// T is the type of element stored in the list
+///
+/// ```
+/// # use cyclic_data_types::list::GrowableList;
+/// # const SIZE: usize = 2;
+/// let mut list: GrowableList<SIZE, i64> = GrowableList::new();
+///
+/// list.push_back(1).push_back(2);
+/// assert!(list.is_inline());
+///
+/// list.push_back(3);
+/// assert!(!list.is_inline());
+/// assert_eq!(list.capacity(), 4);
+///
+/// assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![1, 2, 3]);
+/// ```
+pub struct GrowableList<const SIZE: usize, T> {
+    buffer: Buffer<SIZE, T>,
+}
+
+enum Buffer<const SIZE: usize, T> {
+    Inline(List<SIZE, T, false>),
+    Heap(HeapList<T, false>),
+}
+
+impl<const SIZE: usize, T> GrowableList<SIZE, T> {
+    /// Creates an empty list that uses inline storage.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::GrowableList;
+    /// # const SIZE: usize = 5;
+    /// let list: GrowableList<SIZE, i64> = GrowableList::new();
+    ///
+    /// assert_eq!(list.len(), 0);
+    /// assert_eq!(list.capacity(), SIZE);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            buffer: Buffer::Inline(List::empty()),
+        }
+    }
+
+    /// Returns the number of elements in the list
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::GrowableList;
+    /// # const SIZE: usize = 5;
+    /// let mut list: GrowableList<SIZE, i64> = GrowableList::new();
+    ///
+    /// list.push_back(1);
+    ///
+    /// assert_eq!(list.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        match &self.buffer {
+            Buffer::Inline(list) => list.len(),
+            Buffer::Heap(list) => list.len(),
+        }
+    }
+
+    /// Returns `true` if the list contains no elements
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::GrowableList;
+    /// # const SIZE: usize = 5;
+    /// let list: GrowableList<SIZE, i64> = GrowableList::new();
+    ///
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of elements the list can hold before it has to grow.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::GrowableList;
+    /// # const SIZE: usize = 2;
+    /// let mut list: GrowableList<SIZE, i64> = GrowableList::new();
+    ///
+    /// assert_eq!(list.capacity(), 2);
+    ///
+    /// list.push_back(1).push_back(2).push_back(3);
+    ///
+    /// assert_eq!(list.capacity(), 4);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        match &self.buffer {
+            Buffer::Inline(_) => SIZE,
+            Buffer::Heap(list) => list.capacity(),
+        }
+    }
+
+    /// Returns `true` if the elements are stored inline rather than on the heap.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::GrowableList;
+    /// # const SIZE: usize = 1;
+    /// let mut list: GrowableList<SIZE, i64> = GrowableList::new();
+    ///
+    /// list.push_back(1);
+    /// assert!(list.is_inline());
+    ///
+    /// list.push_back(2);
+    /// assert!(!list.is_inline());
+    /// ```
+    pub fn is_inline(&self) -> bool {
+        matches!(self.buffer, Buffer::Inline(_))
+    }
+
+    /// Pushes a new element to the back of the list. If the list is full, the list grows to double its capacity before the element is pushed.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::GrowableList;
+    /// # const SIZE: usize = 2;
+    /// let mut list: GrowableList<SIZE, i64> = GrowableList::new();
+    ///
+    /// list.push_back(1).push_back(2).push_back(3);
+    ///
+    /// assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![1, 2, 3]);
+    /// ```
+    pub fn push_back(&mut self, elem: T) -> &mut Self {
+        if self.len() == self.capacity() {
+            self.grow();
+        }
+
+        match &mut self.buffer {
+            Buffer::Inline(list) => list.push_back(elem).map(|_| ()),
+            Buffer::Heap(list) => list.push_back(elem).map(|_| ()),
+        }
+        .expect("list has room after growing");
+
+        self
+    }
+
+    /// Pushes a new element to the front of the list. If the list is full, the list grows to double its capacity before the element is pushed.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::GrowableList;
+    /// # const SIZE: usize = 2;
+    /// let mut list: GrowableList<SIZE, i64> = GrowableList::new();
+    ///
+    /// list.push_front(1).push_front(2).push_front(3);
+    ///
+    /// assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![3, 2, 1]);
+    /// ```
+    pub fn push_front(&mut self, elem: T) -> &mut Self {
+        if self.len() == self.capacity() {
+            self.grow();
+        }
+
+        match &mut self.buffer {
+            Buffer::Inline(list) => list.push_front(elem).map(|_| ()),
+            Buffer::Heap(list) => list.push_front(elem).map(|_| ()),
+        }
+        .expect("list has room after growing");
+
+        self
+    }
+
+    /// returns a reference to an element in the list at provided index. Indexes loop back around the list in the same manner as [`List::get`].
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::GrowableList;
+    /// # const SIZE: usize = 2;
+    /// let list: GrowableList<SIZE, i64> = [1, 2, 3].into_iter().collect();
+    ///
+    /// assert_eq!(list.get(2), Some(&3));
+    /// assert_eq!(list.get(5), Some(&3));
+    /// assert_eq!(list.get(-1), Some(&3));
+    /// ```
+    pub fn get(&self, index: isize) -> Option<&T> {
+        match &self.buffer {
+            Buffer::Inline(list) => list.get(index),
+            Buffer::Heap(list) => list.get(index),
+        }
+    }
+
+    /// returns a mutable reference to an element in the list at provided index. Indexes loop back around the list in the same manner as [`List::get_mut`].
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::GrowableList;
+    /// # const SIZE: usize = 2;
+    /// let mut list: GrowableList<SIZE, i64> = [1, 2, 3].into_iter().collect();
+    ///
+    /// *list.get_mut(-1).unwrap() = 4;
+    ///
+    /// assert_eq!(list.get(2), Some(&4));
+    /// ```
+    pub fn get_mut(&mut self, index: isize) -> Option<&mut T> {
+        match &mut self.buffer {
+            Buffer::Inline(list) => list.get_mut(index),
+            Buffer::Heap(list) => list.get_mut(index),
+        }
+    }
+
+    /// Removes the last element from the list and returns removed element. The list does not shrink.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::GrowableList;
+    /// # const SIZE: usize = 2;
+    /// let mut list: GrowableList<SIZE, i64> = [1, 2, 3].into_iter().collect();
+    ///
+    /// assert_eq!(list.remove_back(), Some(3));
+    /// assert_eq!(list.len(), 2);
+    /// ```
+    pub fn remove_back(&mut self) -> Option<T> {
+        match &mut self.buffer {
+            Buffer::Inline(list) => list.remove_back(),
+            Buffer::Heap(list) => list.remove_back(),
+        }
+    }
+
+    /// Removes the first element from the list and returns removed element. The list does not shrink.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::GrowableList;
+    /// # const SIZE: usize = 2;
+    /// let mut list: GrowableList<SIZE, i64> = [1, 2, 3].into_iter().collect();
+    ///
+    /// assert_eq!(list.remove_front(), Some(1));
+    /// assert_eq!(list.len(), 2);
+    /// ```
+    pub fn remove_front(&mut self) -> Option<T> {
+        match &mut self.buffer {
+            Buffer::Inline(list) => list.remove_front(),
+            Buffer::Heap(list) => list.remove_front(),
+        }
+    }
+
+    /// Shrinks the capacity of the list as much as possible. If the elements fit within `SIZE`, the list moves back to inline storage. Otherwise, the heap storage is shrunk to the number of elements in the list.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::GrowableList;
+    /// # const SIZE: usize = 2;
+    /// let mut list: GrowableList<SIZE, i64> = [1, 2, 3, 4, 5].into_iter().collect();
+    ///
+    /// assert_eq!(list.capacity(), 8);
+    ///
+    /// list.shrink_to_fit();
+    /// assert_eq!(list.capacity(), 5);
+    ///
+    /// list.remove_front();
+    /// list.remove_front();
+    /// list.remove_front();
+    /// list.shrink_to_fit();
+    ///
+    /// assert!(list.is_inline());
+    /// assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![4, 5]);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        let len = self.len();
+
+        let heap = match &mut self.buffer {
+            Buffer::Inline(_) => return,
+            Buffer::Heap(heap) if len == heap.capacity() => return,
+            Buffer::Heap(heap) => heap,
+        };
+
+        self.buffer = if len <= SIZE {
+            let mut list = List::empty();
+            while let Some(elem) = heap.remove_front() {
+                list.push_back(elem)
+                    .expect("the heap list holds at most SIZE elements");
+            }
+            Buffer::Inline(list)
+        } else {
+            Buffer::Heap(Self::move_to_heap(heap, len))
+        };
+    }
+
+    /// Creates an iterator object that iterates over the elements in the list
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::GrowableList;
+    /// # const SIZE: usize = 2;
+    /// let list: GrowableList<SIZE, i64> = [1, 2, 3].into_iter().collect();
+    ///
+    /// assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![1, 2, 3]);
+    /// ```
+    pub fn iter(&self) -> GrowableIter<'_, SIZE, T> {
+        GrowableIter {
+            pointer: 0,
+            list: self,
+        }
+    }
+
+    fn grow(&mut self) {
+        let capacity = (self.capacity() * 2).max(1);
+
+        let heap = match &mut self.buffer {
+            Buffer::Inline(list) => {
+                let mut heap = HeapList::new(capacity).expect("capacity is never zero");
+                while let Some(elem) = list.remove_front() {
+                    heap.push_back(elem)
+                        .expect("the heap list is larger than the inline list");
+                }
+                heap
+            }
+            Buffer::Heap(heap) => Self::move_to_heap(heap, capacity),
+        };
+
+        self.buffer = Buffer::Heap(heap);
+    }
+
+    /// Moves the elements of `heap` in order into a new heap list with the given capacity
+    fn move_to_heap(heap: &mut HeapList<T, false>, capacity: usize) -> HeapList<T, false> {
+        let mut new_heap = HeapList::new(capacity.max(1)).expect("capacity is never zero");
+        while let Some(elem) = heap.remove_front() {
+            new_heap
+                .push_back(elem)
+                .expect("the new heap list is at least as large as the old one");
+        }
+        new_heap
+    }
+}
+
+/// Iterator over the elements of a [`GrowableList`]
+pub struct GrowableIter<'a, const SIZE: usize, T> {
+    pointer: usize,
+    list: &'a GrowableList<SIZE, T>,
+}
+
+impl<'a, const SIZE: usize, T> Iterator for GrowableIter<'a, SIZE, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len() == 0 {
+            return None;
+        }
+
+        let tmp = Some(&self.list[self.pointer]);
+
+        self.pointer += 1;
+
+        tmp
+    }
+}
+
+impl<'a, const SIZE: usize, T> ExactSizeIterator for GrowableIter<'a, SIZE, T> {
+    fn len(&self) -> usize {
+        self.list.len() - self.pointer
+    }
+}
+
+impl<const SIZE: usize, T> Default for GrowableList<SIZE, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const SIZE: usize, T> PartialEq for GrowableList<SIZE, T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<const SIZE: usize, T> Eq for GrowableList<SIZE, T> where T: Eq {}
+
+impl<const SIZE: usize, T> Display for GrowableList<SIZE, T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.buffer {
+            Buffer::Inline(list) => write!(f, "{}", list),
+            Buffer::Heap(list) => write!(f, "{}", list),
+        }
+    }
+}
+
+impl<const SIZE: usize, T> Debug for GrowableList<SIZE, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.buffer {
+            Buffer::Inline(list) => f.debug_struct("GrowableList").field("", list).finish(),
+            Buffer::Heap(list) => f.debug_struct("GrowableList").field("", list).finish(),
+        }
+    }
+}
+
+impl<const SIZE: usize, T> Index<usize> for GrowableList<SIZE, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match &self.buffer {
+            Buffer::Inline(list) => &list[index],
+            Buffer::Heap(list) => &list[index],
+        }
+    }
+}
+
+impl<const SIZE: usize, T> IndexMut<usize> for GrowableList<SIZE, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match &mut self.buffer {
+            Buffer::Inline(list) => &mut list[index],
+            Buffer::Heap(list) => &mut list[index],
+        }
+    }
+}
+
+impl<const SIZE: usize, T> FromIterator<T> for GrowableList<SIZE, T> {
+    fn from_iter<A: IntoIterator<Item = T>>(iter: A) -> Self {
+        let mut list = Self::new();
+
+        for elem in iter {
+            list.push_back(elem);
+        }
+
+        list
+    }
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool> From<List<SIZE, T, WRITE_OVER>>
+    for GrowableList<SIZE, T>
+{
+    fn from(value: List<SIZE, T, WRITE_OVER>) -> Self {
+        Self {
+            buffer: Buffer::Inline(List {
+                list: value.list.into_write_over(),
+            }),
+        }
+    }
+}
@@ -1,12 +1,22 @@
+use std::{array, marker::PhantomData, slice};
+
+use crate::storage::{Slot, Storage};
+
 use super::List;
 
-pub struct Iter<'a, const SIZE: usize, T, const WRITE_OVER: bool> {
+pub struct Iter<'a, const SIZE: usize, T, const WRITE_OVER: bool, S = [Option<T>; SIZE]>
+where
+    S: Storage<T>,
+{
     pointer: usize,
-    list: &'a List<SIZE, T, WRITE_OVER>,
+    list: &'a List<SIZE, T, WRITE_OVER, S>,
 }
 
-impl<'a, const SIZE: usize, T, const WRITE_OVER: bool> Iter<'a, SIZE, T, WRITE_OVER> {
-    pub fn new(list: &'a List<SIZE, T, WRITE_OVER>) -> Self {
+impl<'a, const SIZE: usize, T, const WRITE_OVER: bool, S> Iter<'a, SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    pub fn new(list: &'a List<SIZE, T, WRITE_OVER, S>) -> Self {
         Self {
             pointer: 0,
             list: &list,
@@ -14,7 +24,11 @@ impl<'a, const SIZE: usize, T, const WRITE_OVER: bool> Iter<'a, SIZE, T, WRITE_O
     }
 }
 
-impl<'a, const SIZE: usize, T, const WRITE_OVER: bool> Iterator for Iter<'a, SIZE, T, WRITE_OVER> {
+impl<'a, const SIZE: usize, T, const WRITE_OVER: bool, S> Iterator
+    for Iter<'a, SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -30,14 +44,288 @@ impl<'a, const SIZE: usize, T, const WRITE_OVER: bool> Iterator for Iter<'a, SIZ
     }
 }
 
-impl<'a, const SIZE: usize, T, const WRITE_OVER: bool> ExactSizeIterator
-    for Iter<'a, SIZE, T, WRITE_OVER>
+impl<'a, const SIZE: usize, T, const WRITE_OVER: bool, S> ExactSizeIterator
+    for Iter<'a, SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    fn len(&self) -> usize {
+        self.list.len() - self.pointer
+    }
+}
+
+/// `CycleFrom` endlessly iterates around a [`List`] - starting from a logical index. Created using [`List::cycle_from`].
+pub struct CycleFrom<'a, const SIZE: usize, T, const WRITE_OVER: bool, S = [Option<T>; SIZE]>
+where
+    S: Storage<T>,
+{
+    pointer: usize,
+    list: &'a List<SIZE, T, WRITE_OVER, S>,
+}
+
+impl<'a, const SIZE: usize, T, const WRITE_OVER: bool, S> CycleFrom<'a, SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    pub(crate) fn new(list: &'a List<SIZE, T, WRITE_OVER, S>, index: isize) -> Self {
+        Self {
+            pointer: wrap_index(index, list.len()),
+            list,
+        }
+    }
+}
+
+impl<'a, const SIZE: usize, T, const WRITE_OVER: bool, S> Iterator
+    for CycleFrom<'a, SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.list.is_empty() {
+            return None;
+        }
+
+        let tmp = Some(&self.list[self.pointer]);
+
+        self.pointer = (self.pointer + 1) % self.list.len();
+
+        tmp
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.list.len() {
+            0 => (0, Some(0)),
+            _ => (usize::MAX, None),
+        }
+    }
+}
+
+/// `IterFrom` visits every element of a [`List`] exactly once - starting from a logical index, and wrapping around past the end of the list. Created using [`List::iter_from`].
+pub struct IterFrom<'a, const SIZE: usize, T, const WRITE_OVER: bool, S = [Option<T>; SIZE]>
+where
+    S: Storage<T>,
+{
+    start: usize,
+    pointer: usize,
+    list: &'a List<SIZE, T, WRITE_OVER, S>,
+}
+
+impl<'a, const SIZE: usize, T, const WRITE_OVER: bool, S> IterFrom<'a, SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    pub(crate) fn new(list: &'a List<SIZE, T, WRITE_OVER, S>, index: isize) -> Self {
+        Self {
+            start: wrap_index(index, list.len()),
+            pointer: 0,
+            list,
+        }
+    }
+}
+
+impl<'a, const SIZE: usize, T, const WRITE_OVER: bool, S> Iterator
+    for IterFrom<'a, SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len() == 0 {
+            return None;
+        }
+
+        let tmp = Some(&self.list[(self.start + self.pointer) % self.list.len()]);
+
+        self.pointer += 1;
+
+        tmp
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+impl<'a, const SIZE: usize, T, const WRITE_OVER: bool, S> ExactSizeIterator
+    for IterFrom<'a, SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    fn len(&self) -> usize {
+        self.list.len() - self.pointer
+    }
+}
+
+/// `CircularPairs` iterates over every pair of neighbouring elements in a [`List`] - including the pair which wraps from the last element back to the first. Created using [`List::circular_pairs`].
+pub struct CircularPairs<'a, const SIZE: usize, T, const WRITE_OVER: bool, S = [Option<T>; SIZE]>
+where
+    S: Storage<T>,
+{
+    pointer: usize,
+    list: &'a List<SIZE, T, WRITE_OVER, S>,
+}
+
+impl<'a, const SIZE: usize, T, const WRITE_OVER: bool, S> CircularPairs<'a, SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    pub(crate) fn new(list: &'a List<SIZE, T, WRITE_OVER, S>) -> Self {
+        Self { pointer: 0, list }
+    }
+}
+
+impl<'a, const SIZE: usize, T, const WRITE_OVER: bool, S> Iterator
+    for CircularPairs<'a, SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    type Item = (&'a T, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len() == 0 {
+            return None;
+        }
+
+        let len = self.list.len();
+        let tmp = Some((
+            &self.list[self.pointer],
+            &self.list[(self.pointer + 1) % len],
+        ));
+
+        self.pointer += 1;
+
+        tmp
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+impl<'a, const SIZE: usize, T, const WRITE_OVER: bool, S> ExactSizeIterator
+    for CircularPairs<'a, SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
 {
     fn len(&self) -> usize {
         self.list.len() - self.pointer
     }
 }
 
+/// `CircularWindows` iterates over every window of `K` consecutive elements in a [`List`] - including the windows which wrap around from the last element back to the first. Created using [`List::circular_windows`].
+pub struct CircularWindows<
+    'a,
+    const SIZE: usize,
+    T,
+    const WRITE_OVER: bool,
+    const K: usize,
+    S = [Option<T>; SIZE],
+> where
+    S: Storage<T>,
+{
+    pointer: usize,
+    list: &'a List<SIZE, T, WRITE_OVER, S>,
+}
+
+impl<'a, const SIZE: usize, T, const WRITE_OVER: bool, const K: usize, S>
+    CircularWindows<'a, SIZE, T, WRITE_OVER, K, S>
+where
+    S: Storage<T>,
+{
+    pub(crate) fn new(list: &'a List<SIZE, T, WRITE_OVER, S>) -> Self {
+        Self { pointer: 0, list }
+    }
+}
+
+impl<'a, const SIZE: usize, T, const WRITE_OVER: bool, const K: usize, S> Iterator
+    for CircularWindows<'a, SIZE, T, WRITE_OVER, K, S>
+where
+    S: Storage<T>,
+{
+    type Item = [&'a T; K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len() == 0 {
+            return None;
+        }
+
+        let len = self.list.len();
+        let list = self.list;
+        let pointer = self.pointer;
+
+        let tmp = Some(array::from_fn(|offset| &list[(pointer + offset) % len]));
+
+        self.pointer += 1;
+
+        tmp
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+impl<'a, const SIZE: usize, T, const WRITE_OVER: bool, const K: usize, S> ExactSizeIterator
+    for CircularWindows<'a, SIZE, T, WRITE_OVER, K, S>
+where
+    S: Storage<T>,
+{
+    fn len(&self) -> usize {
+        match K {
+            0 => 0,
+            _ => self.list.len() - self.pointer,
+        }
+    }
+}
+
+/// `Elems` iterates over the elements held in a run of occupied slots
+pub(crate) struct Elems<'a, T, S> {
+    slots: slice::Iter<'a, S>,
+    elem: PhantomData<&'a T>,
+}
+
+impl<'a, T, S: Slot<T>> Elems<'a, T, S> {
+    /// # Safety
+    /// Every slot in `slots` must be occupied.
+    pub(crate) unsafe fn new(slots: &'a [S]) -> Self {
+        Self {
+            slots: slots.iter(),
+            elem: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: 'a, S: Slot<T>> Iterator for Elems<'a, T, S> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.next().map(|slot| unsafe { slot.get() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.slots.size_hint()
+    }
+}
+
+impl<'a, T: 'a, S: Slot<T>> DoubleEndedIterator for Elems<'a, T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.slots.next_back().map(|slot| unsafe { slot.get() })
+    }
+}
+
+impl<'a, T: 'a, S: Slot<T>> ExactSizeIterator for Elems<'a, T, S> {}
+
+/// Maps a logical index (which can be negative, or greater than the length) onto `[0, len)` - or 0 if the list is empty
+fn wrap_index(index: isize, len: usize) -> usize {
+    match len {
+        0 => 0,
+        _ => index.rem_euclid(len as isize) as usize,
+    }
+}
+
 // impl<'a, const SIZE: usize, T, const WRITE_OVER: bool> DoubleEndedIterator for Iter<'a, SIZE, T, WRITE_OVER> {
 //     fn next_back(&mut self) -> Option<Self::Item> {
 //         match self.pointer.checked_sub(1) {
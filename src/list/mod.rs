@@ -11,19 +11,37 @@
 //! As result, a [`List<T>`] struct can be sized (assuming that `T` is also sized). Allowing the `List` to exist on the stack.
 use std::convert::TryFrom;
 use std::{
+    array,
     collections::LinkedList,
     fmt::{Debug, Display},
-    mem,
     ops::{Index, IndexMut},
 };
 
 use std::iter::FromIterator;
 
-use crate::{error::Error, CyclicList};
+use crate::{error::Error, storage::Storage, CyclicList};
 
-use self::iterator::Iter;
+use self::iterator::Elems;
+pub(crate) use self::iterator::Iter;
+pub use self::iterator::{CircularPairs, CircularWindows, CycleFrom, IterFrom};
 
+pub use self::cursor::{Cursor, CursorMut};
+pub use self::evict::EvictingList;
+pub use self::growable::{GrowableIter, GrowableList};
+pub use self::round_robin::RoundRobin;
+pub use self::seq::{SeqIter, SeqList};
+pub use self::storage_list::{HeapIter, HeapList, StorageIter, StorageList};
+
+mod cursor;
+mod cyclic;
+mod evict;
+mod growable;
 mod iterator;
+mod round_robin;
+mod search;
+mod seq;
+mod sort;
+mod storage_list;
 
 #[cfg(test)]
 mod tests;
@@ -31,7 +49,7 @@ mod tests;
 /// `List` is the `struct` used to define the state of a cyclic List
 ///
 /// # Generics
-/// List types are derived using 3 generics.
+/// List types are derived using 4 generics.
 ///
 /// 1. `const SIZE: usize`
 ///
@@ -45,6 +63,10 @@ mod tests;
 ///
 /// WRITE_OVER is a generic constant [^note] that is used to determine if elements should be over written on overflow
 ///
+/// 4. `S: Storage<T> = [Option<T>; SIZE]`
+///
+/// S is the [`Storage`] the elements of the list are stored in - an inline array by default. The capacity of the list is the capacity of the storage, so SIZE is only meaningful for the default storage (see [`StorageList`] and [`HeapList`]).
+///
 /// # Creating Lists
 ///
 /// Lists can be created in a couple of ways.
@@ -113,12 +135,86 @@ mod tests;
 /// ```
 ///
 /// [note]: [Generic Constraints](https://rust-lang.github.io/rfcs/2000-const-generics.html)
-#[derive(Eq, PartialEq, Default)]
-pub struct List<const SIZE: usize, T: Sized, const WRITE_OVER: bool> {
-    list: CyclicList<SIZE, T, WRITE_OVER>,
+pub struct List<const SIZE: usize, T: Sized, const WRITE_OVER: bool, S = [Option<T>; SIZE]>
+where
+    S: Storage<T>,
+{
+    list: CyclicList<SIZE, T, WRITE_OVER, S>,
 }
 
 impl<const SIZE: usize, T, const WRITE_OVER: bool> List<SIZE, T, WRITE_OVER> {
+    /// Creates an empty list without requiring `T` to implement [`Default`].
+    pub(crate) fn empty() -> Self {
+        List {
+            list: CyclicList::new(array::from_fn(|_| None), 0, 0, true),
+        }
+    }
+
+    /// Wraps the list in an [`EvictingList`]. In which, `on_evict` is called with every element that is written over by `push_back` or `push_front`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 3;
+    /// let mut evicted = Vec::new();
+    ///
+    /// let mut list = List::<SIZE, i64, true>::default().with_evict(|elem| evicted.push(elem));
+    ///
+    /// for i in 1..=5 {
+    ///     assert!(list.push_back(i).is_ok());
+    /// }
+    ///
+    /// assert_eq!(list.len(), 3);
+    /// assert_eq!(evicted, vec![1, 2]);
+    /// ```
+    pub fn with_evict<F>(self, on_evict: F) -> EvictingList<SIZE, T, WRITE_OVER, F>
+    where
+        F: FnMut(T),
+    {
+        EvictingList::new(self, on_evict)
+    }
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool, S> List<SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    /// Creates an empty list over the provided storage. Any values already in the storage are treated as vacant slots.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::StorageList;
+    /// let list: StorageList<[Option<i64>; 4], i64, false> = StorageList::from_storage([None; 4]).unwrap();
+    ///
+    /// assert_eq!(list.len(), 0);
+    /// assert_eq!(list.capacity(), 4);
+    /// ```
+    ///
+    /// # Returns
+    /// * Self if the storage has at least one slot
+    /// * [`Error::InvalidSize`] if the storage has no slots
+    pub fn from_storage(storage: S) -> Result<Self, Error> {
+        if storage.capacity() == 0 {
+            return Err(Error::InvalidSize);
+        }
+
+        Ok(List {
+            list: CyclicList::new(storage, 0, 0, true),
+        })
+    }
+
+    /// Removes every element from the list - returning the underlying storage.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::StorageList;
+    /// let mut list: StorageList<[Option<i64>; 2], i64, true> = StorageList::from_storage([None; 2]).unwrap();
+    ///
+    /// assert!(list.push_back(1).is_ok());
+    ///
+    /// assert_eq!(list.into_storage(), [None, None]);
+    /// ```
+    pub fn into_storage(self) -> S {
+        self.list.into_storage()
+    }
+
     /// Returns the number of elements in the list
     ///
     /// ```
@@ -136,10 +232,114 @@ impl<const SIZE: usize, T, const WRITE_OVER: bool> List<SIZE, T, WRITE_OVER> {
         self.list.len()
     }
 
-    // pub fn insert_at(&mut self, elem: T, index: usize) -> Result<&mut Self, Error> where T: Clone {
+    /// Returns `true` if the list contains no elements
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list: List<SIZE, i64, false> = List::default();
+    ///
+    /// assert!(list.is_empty());
+    ///
+    /// assert!(list.push_back(1).is_ok());
+    ///
+    /// assert!(!list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.list.empty
+    }
 
-    //     todo!();
-    // }
+    /// Returns the maximum number of elements the list can hold. Which is `SIZE` for a list over the default inline array - or the capacity of the storage otherwise.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::{HeapList, List};
+    /// let list: List<5, i64, false> = List::default();
+    /// let heap: HeapList<i64, false> = HeapList::new(3).unwrap();
+    ///
+    /// assert_eq!(list.capacity(), 5);
+    /// assert_eq!(heap.capacity(), 3);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.list.capacity()
+    }
+
+    /// Inserts an element at `index` - shifting every element after it towards the back of the list. This operation is done in `O(min(index, len - index))`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list : List<SIZE, i64, false> = vec![1,2,4].try_into().unwrap();
+    ///
+    /// assert!(list.insert_at(3, 2).is_ok());
+    /// assert!(list.insert_at(5, 4).is_ok());
+    ///
+    /// assert_eq!(list, [1,2,3,4,5].into());
+    /// ```
+    ///
+    /// If the list is full - the list has two options based on the `WRITE_OVER` flag.
+    /// 1. `WRITE_OVER = true`
+    ///
+    /// The first element is written over and dropped - before the new element is inserted in front of the element which was at `index`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list : List<SIZE, i64, true> = [1,2,3,4,5].into();
+    ///
+    /// assert!(list.insert_at(0, 3).is_ok());
+    ///
+    /// assert_eq!(list, [2,3,0,4,5].into());
+    /// ```
+    ///
+    /// 2. `WRITE_OVER = false`
+    ///
+    /// The new element isn't added to the list. Resulting in no change to the state of the list.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # use cyclic_data_types::error::Error;
+    /// # const SIZE: usize = 5;
+    /// let mut list : List<SIZE, i64, false> = [1,2,3,4,5].into();
+    ///
+    /// assert_eq!(list.insert_at(0, 3).err(), Some(Error::Overflow));
+    /// ```
+    ///
+    /// # Returns
+    /// * Self if the insert was successful
+    /// * [`Error::IndexOutOfRange`] if `index` is greater than the length of the list
+    /// * [`Error::Overflow`] if List is full and the `WRITE_OVER` flag is set to `false`
+    pub fn insert_at(&mut self, elem: T, index: usize) -> Result<&mut Self, Error> {
+        if self.len() < index {
+            return Err(Error::IndexOutOfRange);
+        }
+
+        let mut index = index;
+        if self.len() == self.capacity() {
+            if !WRITE_OVER || self.capacity() == 0 {
+                return Err(Error::Overflow);
+            }
+
+            self.remove_front();
+            index = index.saturating_sub(1);
+        }
+
+        let len = self.len();
+        if index < len / 2 {
+            self.push_front(elem)?;
+
+            for i in 0..index {
+                self.swap(i, i + 1);
+            }
+        } else {
+            self.push_back(elem)?;
+
+            for i in (index..len).rev() {
+                self.swap(i, i + 1);
+            }
+        }
+
+        Ok(self)
+    }
 
     /// Pushes a new element to the back of the list. This operation is done in `O(1)`.
     ///
@@ -184,7 +384,16 @@ impl<const SIZE: usize, T, const WRITE_OVER: bool> List<SIZE, T, WRITE_OVER> {
     /// * Self if the push was successful
     /// * [Error] if List is full and the `WRITE_OVER` flag is set to `false`
     pub fn push_back(&mut self, elem: T) -> Result<&mut Self, Error> {
-        if self.len() + 1 > SIZE && !WRITE_OVER {
+        self.push_back_displacing(elem)?;
+
+        Ok(self)
+    }
+
+    /// Pushes a new element to the back of the list - returning the element that was written over (if any).
+    pub(crate) fn push_back_displacing(&mut self, elem: T) -> Result<Option<T>, Error> {
+        let full = self.len() == self.capacity();
+
+        if full && !WRITE_OVER {
             return Err(Error::Overflow);
         }
 
@@ -209,9 +418,12 @@ impl<const SIZE: usize, T, const WRITE_OVER: bool> List<SIZE, T, WRITE_OVER> {
         //pushing new value
         let end = self.list.end;
 
-        self.list[end] = Some(elem);
+        // the slot at end is only occupied when the first element is written over
+        let displaced = full.then(|| unsafe { self.list.take(end) });
 
-        Ok(self)
+        unsafe { self.list.write(end, elem) };
+
+        Ok(displaced)
     }
 
     /// Pushes a new element to the front of the list. This operation is done in `O(1)`.
@@ -257,7 +469,16 @@ impl<const SIZE: usize, T, const WRITE_OVER: bool> List<SIZE, T, WRITE_OVER> {
     /// * Self if the push was successful
     /// * [Error] if List is full and the `WRITE_OVER` flag is set to `false`
     pub fn push_front(&mut self, elem: T) -> Result<&mut Self, Error> {
-        if self.len() + 1 > SIZE && !WRITE_OVER {
+        self.push_front_displacing(elem)?;
+
+        Ok(self)
+    }
+
+    /// Pushes a new element to the front of the list - returning the element that was written over (if any).
+    pub(crate) fn push_front_displacing(&mut self, elem: T) -> Result<Option<T>, Error> {
+        let full = self.len() == self.capacity();
+
+        if full && !WRITE_OVER {
             return Err(Error::Overflow);
         }
 
@@ -278,9 +499,13 @@ impl<const SIZE: usize, T, const WRITE_OVER: bool> List<SIZE, T, WRITE_OVER> {
             }
         }
         let start = self.list.start;
-        self.list[start] = Some(elem);
 
-        Ok(self)
+        // the slot at start is only occupied when the last element is written over
+        let displaced = full.then(|| unsafe { self.list.take(start) });
+
+        unsafe { self.list.write(start, elem) };
+
+        Ok(displaced)
     }
 
     /// returns a reference to an element in the list at provided index.
@@ -330,7 +555,7 @@ impl<const SIZE: usize, T, const WRITE_OVER: bool> List<SIZE, T, WRITE_OVER> {
     ///
     /// Such that, im(`Self::Index<usize>`) ⊆ im(`Self::Index<isize>`) ⊆ im(`Self::get(&self, isize)`).
     pub fn get(&self, index: isize) -> Option<&T> {
-        if self.len() == 0 {
+        if self.is_empty() {
             return None;
         }
 
@@ -405,7 +630,7 @@ impl<const SIZE: usize, T, const WRITE_OVER: bool> List<SIZE, T, WRITE_OVER> {
     ///
     /// Such that, im(`Self::Index<usize>`) ⊆ im(`Self::Index<isize>`) ⊆ im(`Self::get_mut(&self, isize)`).
     pub fn get_mut(&mut self, index: isize) -> Option<&mut T> {
-        if self.len() == 0 {
+        if self.is_empty() {
             return None;
         }
 
@@ -442,7 +667,7 @@ impl<const SIZE: usize, T, const WRITE_OVER: bool> List<SIZE, T, WRITE_OVER> {
     /// * `Some(last element in list)` if `list.len()` > 0
     /// * `None` if `list.len()` = 0
     pub fn remove_back(&mut self) -> Option<T> {
-        if self.len() == 0 {
+        if self.is_empty() {
             return None;
         }
         let pop_index = self.list.end;
@@ -453,11 +678,7 @@ impl<const SIZE: usize, T, const WRITE_OVER: bool> List<SIZE, T, WRITE_OVER> {
             self.list.empty = true;
         }
 
-        let mut value = None;
-
-        mem::swap(&mut self.list[pop_index], &mut value);
-
-        Some(value.unwrap())
+        Some(unsafe { self.list.take(pop_index) })
     }
 
     /// Removes the first element from the list and returns removed element. This occurs in `O(1)`
@@ -477,7 +698,7 @@ impl<const SIZE: usize, T, const WRITE_OVER: bool> List<SIZE, T, WRITE_OVER> {
     /// * `Some(last element in list)` if `list.len()` > 0
     /// * `None` if `list.len()` = 0
     pub fn remove_front(&mut self) -> Option<T> {
-        if self.len() == 0 {
+        if self.is_empty() {
             return None;
         }
 
@@ -489,34 +710,197 @@ impl<const SIZE: usize, T, const WRITE_OVER: bool> List<SIZE, T, WRITE_OVER> {
             self.list.empty = true;
         }
 
-        let mut value = None;
+        Some(unsafe { self.list.take(pop_index) })
+    }
+
+    /// Removes the element at `index` and returns the removed element - shifting every element after it towards the front of the list. This operation is done in `O(min(index, len - index))`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # use cyclic_data_types::error::Error;
+    /// # const SIZE: usize = 5;
+    /// let mut list : List<SIZE, i64, false> = [1,2,3,4,5].into();
+    ///
+    /// assert_eq!(list.remove_at(1), Ok(2));
+    /// assert_eq!(list.remove_at(4), Err(Error::IndexOutOfRange));
+    ///
+    /// assert_eq!(list, vec![1,3,4,5].try_into().unwrap());
+    /// ```
+    ///
+    /// # Return
+    /// * `Ok(removed element)` if `index` < `list.len()`
+    /// * [`Error::IndexOutOfRange`] if `index` >= `list.len()`
+    pub fn remove_at(&mut self, index: usize) -> Result<T, Error> {
+        let len = self.len();
 
-        mem::swap(&mut self.list[pop_index], &mut value);
+        if len <= index {
+            return Err(Error::IndexOutOfRange);
+        }
 
-        Some(value.unwrap())
+        let removed = if index < len / 2 {
+            for i in (0..index).rev() {
+                self.swap(i, i + 1);
+            }
+
+            self.remove_front()
+        } else {
+            for i in index..len - 1 {
+                self.swap(i, i + 1);
+            }
+
+            self.remove_back()
+        };
+
+        removed.ok_or(Error::IndexOutOfRange)
     }
 
-    // pub fn remove_at(&mut self, index: usize) -> Result<T, Error> where T: Clone {
+    /// Swaps the elements at the (in range) indexes `a` and `b`
+    fn swap(&mut self, a: usize, b: usize) {
+        let start = self.list.start;
+        let capacity = self.capacity();
 
-    //     todo!();
-    // }
+        self.list
+            .list
+            .slots_mut()
+            .swap((start + a) % capacity, (start + b) % capacity);
+    }
+
+    /// Returns the elements of the list as two contiguous halves of the underlying storage - from the front of the list to the end of the storage, and the remainder from the start of the storage
+    fn as_slices(&self) -> (Elems<'_, T, S::Slot>, Elems<'_, T, S::Slot>) {
+        let len = self.len();
+        let start = self.list.start;
+        let capacity = self.capacity();
+        let slots = self.list.list.slots();
+
+        let (front, back) = match start + len <= capacity {
+            true => (&slots[start..start + len], &slots[..0]),
+            false => (&slots[start..], &slots[..start + len - capacity]),
+        };
+
+        // every slot in either half is occupied
+        unsafe { (Elems::new(front), Elems::new(back)) }
+    }
 
     /// Creates an iterator object that iterates over the elements in the list
-    pub fn iter(&self) -> Iter<SIZE, T, WRITE_OVER>
+    pub fn iter(&self) -> Iter<'_, SIZE, T, WRITE_OVER, S>
     where
         Self: Sized,
     {
         Iter::new(self)
     }
 
+    /// Creates an iterator which endlessly cycles around the list - starting from the logical index `index`. Like [`List::get`], the index wraps around the list and can be negative.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list : List<SIZE, i64, false> = vec![1,2,3].try_into().unwrap();
+    ///
+    /// assert_eq!(list.cycle_from(1).take(7).copied().collect::<Vec<i64>>(), vec![2,3,1,2,3,1,2]);
+    /// assert_eq!(list.cycle_from(-1).take(4).copied().collect::<Vec<i64>>(), vec![3,1,2,3]);
+    /// ```
+    ///
+    /// *NOTE:* The iterator is empty if the list is empty.
+    pub fn cycle_from(&self, index: isize) -> CycleFrom<'_, SIZE, T, WRITE_OVER, S> {
+        CycleFrom::new(self, index)
+    }
+
+    /// Creates an iterator which visits every element of the list exactly once - starting from the logical index `index`, and wrapping around past the end of the list. Like [`List::get`], the index wraps around the list and can be negative.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list : List<SIZE, i64, false> = vec![1,2,3,4].try_into().unwrap();
+    ///
+    /// assert_eq!(list.iter_from(2).copied().collect::<Vec<i64>>(), vec![3,4,1,2]);
+    /// assert_eq!(list.iter_from(-1).copied().collect::<Vec<i64>>(), vec![4,1,2,3]);
+    /// assert_eq!(list.iter_from(6).copied().collect::<Vec<i64>>(), vec![3,4,1,2]);
+    /// ```
+    pub fn iter_from(&self, index: isize) -> IterFrom<'_, SIZE, T, WRITE_OVER, S> {
+        IterFrom::new(self, index)
+    }
+
+    /// Creates an iterator over every pair of neighbouring elements - `(list[i], list[(i + 1) % len])`. Including the pair which wraps from the last element back to the first (such as the closing edge of a polygon).
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list : List<SIZE, i64, false> = vec![1,2,3].try_into().unwrap();
+    ///
+    /// assert_eq!(
+    ///     list.circular_pairs().map(|(a, b)| (*a, *b)).collect::<Vec<(i64, i64)>>(),
+    ///     vec![(1,2), (2,3), (3,1)]
+    /// );
+    /// ```
+    ///
+    /// *NOTE:* A list of a single element yields the element paired with itself.
+    pub fn circular_pairs(&self) -> CircularPairs<'_, SIZE, T, WRITE_OVER, S> {
+        CircularPairs::new(self)
+    }
+
+    /// Creates an iterator over every window of `K` consecutive elements - one window starting at each element. Including the windows which wrap around from the last element back to the first.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list : List<SIZE, i64, false> = vec![1,2,3,4].try_into().unwrap();
+    ///
+    /// assert_eq!(
+    ///     list.circular_windows::<3>().map(|window| window.map(|elem| *elem)).collect::<Vec<[i64; 3]>>(),
+    ///     vec![[1,2,3], [2,3,4], [3,4,1], [4,1,2]]
+    /// );
+    /// ```
+    ///
+    /// *NOTE:* Windows larger than the list wrap around the list more than once. The iterator is empty if `K` is 0.
+    pub fn circular_windows<const K: usize>(
+        &self,
+    ) -> CircularWindows<'_, SIZE, T, WRITE_OVER, K, S> {
+        CircularWindows::new(self)
+    }
+
+    /// Creates a [`Cursor`] at `index` - wrapping around if `index` is greater than the length of the list.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list : List<SIZE, i64, false> = [1,2,3,4,5].into();
+    ///
+    /// let mut cursor = list.cursor(4);
+    /// cursor.move_next();
+    ///
+    /// assert_eq!(cursor.current(), Some(&1));
+    /// ```
+    pub fn cursor(&self, index: usize) -> Cursor<'_, SIZE, T, WRITE_OVER, S> {
+        Cursor::new(self, index)
+    }
+
+    /// Creates a [`CursorMut`] at `index` - wrapping around if `index` is greater than the length of the list.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list : List<SIZE, i64, false> = [1,2,3,4,5].into();
+    ///
+    /// let mut cursor = list.cursor_mut(0);
+    /// cursor.move_prev();
+    ///
+    /// assert_eq!(cursor.remove_current(), Some(5));
+    ///
+    /// assert_eq!(list, vec![1,2,3,4].try_into().unwrap());
+    /// ```
+    pub fn cursor_mut(&mut self, index: usize) -> CursorMut<'_, SIZE, T, WRITE_OVER, S> {
+        CursorMut::new(self, index)
+    }
+
     // /// Creates a iterator object over the list. In which, each element in the iterator can be updated.
     // pub fn iter_mut(&mut self) -> Iter<SIZE, T, WRITE_OVER> where Self: Sized {
     //     Iter::new(self)
     // }
 }
 
-impl<const S: usize, T, const W: bool> Display for List<S, T, W>
+impl<const SIZE: usize, T, const W: bool, S> Display for List<SIZE, T, W, S>
 where
+    S: Storage<T>,
     T: Display,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -524,8 +908,9 @@ where
     }
 }
 
-impl<const S: usize, T, const W: bool> Debug for List<S, T, W>
+impl<const SIZE: usize, T, const W: bool, S> Debug for List<SIZE, T, W, S>
 where
+    S: Storage<T>,
     T: Debug,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -533,7 +918,49 @@ where
     }
 }
 
-impl<const SIZE: usize, T, const W: bool> Index<usize> for List<SIZE, T, W> {
+impl<const SIZE: usize, T, const W: bool, S> PartialEq for List<SIZE, T, W, S>
+where
+    S: Storage<T>,
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.list == other.list
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool, S> Eq for List<SIZE, T, W, S>
+where
+    S: Storage<T>,
+    T: Eq,
+{
+}
+
+impl<const SIZE: usize, T, const W: bool, S> Clone for List<SIZE, T, W, S>
+where
+    S: Storage<T> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list.clone(),
+        }
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Default for List<SIZE, T, W>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self {
+            list: CyclicList::default(),
+        }
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool, S> Index<usize> for List<SIZE, T, W, S>
+where
+    S: Storage<T>,
+{
     type Output = T;
 
     fn index(&self, index: usize) -> &Self::Output {
@@ -541,24 +968,28 @@ impl<const SIZE: usize, T, const W: bool> Index<usize> for List<SIZE, T, W> {
             panic!("{:?}", Error::IndexOutOfRange);
         }
 
-        self.list[(self.list.start + index) % SIZE]
-            .as_ref()
-            .unwrap()
+        self.list.slot((self.list.start + index) % self.capacity())
     }
 }
 
-impl<const SIZE: usize, T, const W: bool> IndexMut<usize> for List<SIZE, T, W> {
+impl<const SIZE: usize, T, const W: bool, S> IndexMut<usize> for List<SIZE, T, W, S>
+where
+    S: Storage<T>,
+{
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         if self.len() <= index {
             panic!("{:?}", Error::IndexOutOfRange);
         }
 
-        let start = self.list.start;
-        self.list[(start + index) % SIZE].as_mut().unwrap()
+        let index = (self.list.start + index) % self.capacity();
+        self.list.slot_mut(index)
     }
 }
 
-impl<const S: usize, T, const W: bool> Index<isize> for List<S, T, W> {
+impl<const SIZE: usize, T, const W: bool, S> Index<isize> for List<SIZE, T, W, S>
+where
+    S: Storage<T>,
+{
     type Output = T;
 
     fn index(&self, index: isize) -> &Self::Output {
@@ -575,7 +1006,10 @@ impl<const S: usize, T, const W: bool> Index<isize> for List<S, T, W> {
     }
 }
 
-impl<const S: usize, T, const W: bool> IndexMut<isize> for List<S, T, W> {
+impl<const SIZE: usize, T, const W: bool, S> IndexMut<isize> for List<SIZE, T, W, S>
+where
+    S: Storage<T>,
+{
     fn index_mut(&mut self, index: isize) -> &mut Self::Output {
         if 0 <= index {
             return &mut self[index as usize];
@@ -682,16 +1116,24 @@ impl<const LIST_SIZE: usize, T, const WRITE_OVER: bool> From<[T; LIST_SIZE]>
     }
 }
 
-impl<const LIST_SIZE: usize, T> From<List<LIST_SIZE, T, true>> for List<LIST_SIZE, T, false> {
-    fn from(value: List<LIST_SIZE, T, true>) -> Self {
+impl<const LIST_SIZE: usize, T, S> From<List<LIST_SIZE, T, true, S>>
+    for List<LIST_SIZE, T, false, S>
+where
+    S: Storage<T>,
+{
+    fn from(value: List<LIST_SIZE, T, true, S>) -> Self {
         Self {
             list: value.list.into(),
         }
     }
 }
 
-impl<const LIST_SIZE: usize, T> From<List<LIST_SIZE, T, false>> for List<LIST_SIZE, T, true> {
-    fn from(value: List<LIST_SIZE, T, false>) -> Self {
+impl<const LIST_SIZE: usize, T, S> From<List<LIST_SIZE, T, false, S>>
+    for List<LIST_SIZE, T, true, S>
+where
+    S: Storage<T>,
+{
+    fn from(value: List<LIST_SIZE, T, false, S>) -> Self {
         Self {
             list: value.list.into(),
         }
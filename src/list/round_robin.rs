@@ -0,0 +1,320 @@
+use std::fmt::Debug;
+
+use crate::error::Error;
+
+use super::List;
+
+struct Entry<T> {
+    item: T,
+    weight: u32,
+    current_weight: i64,
+    healthy: bool,
+}
+
+/// `RoundRobin` selects entries from a fixed set (such as backends) in turn - skipping every entry which has been marked as unhealthy.
+///
+/// Entries can be selected in two ways.
+/// 1. [`RoundRobin::next`] walks around the entries with a cursor - selecting each healthy entry once per rotation.
+/// 2. [`RoundRobin::next_weighted`] uses smooth weighted round robin - selecting each healthy entry in proportion to its weight, while interleaving the selections as evenly as possible.
+///
+/// ```
+/// # use cyclic_data_types::list::RoundRobin;
+/// let mut backends: RoundRobin<3, &str> = RoundRobin::new();
+///
+/// assert!(backends.add("a").is_ok());
+/// assert!(backends.add("b").is_ok());
+/// assert!(backends.add("c").is_ok());
+///
+/// assert!(backends.set_healthy(1, false).is_ok());
+///
+/// assert_eq!(backends.next(), Some(&"a"));
+/// assert_eq!(backends.next(), Some(&"c"));
+/// assert_eq!(backends.next(), Some(&"a"));
+/// ```
+pub struct RoundRobin<const SIZE: usize, T> {
+    entries: List<SIZE, Entry<T>, false>,
+    position: usize,
+}
+
+impl<const SIZE: usize, T> RoundRobin<SIZE, T> {
+    /// Creates an empty round robin.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::RoundRobin;
+    /// let mut backends: RoundRobin<3, &str> = RoundRobin::new();
+    ///
+    /// assert!(backends.is_empty());
+    /// assert_eq!(backends.next(), None);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            entries: List::empty(),
+            position: 0,
+        }
+    }
+
+    /// Adds a healthy entry with a weight of 1.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::RoundRobin;
+    /// # use cyclic_data_types::error::Error;
+    /// let mut backends: RoundRobin<1, &str> = RoundRobin::new();
+    ///
+    /// assert_eq!(backends.add("a"), Ok(()));
+    /// assert_eq!(backends.add("b"), Err(Error::Overflow));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(())` if the entry was added
+    /// * [`Error::Overflow`] if the round robin already holds `SIZE` entries
+    pub fn add(&mut self, item: T) -> Result<(), Error> {
+        self.add_weighted(item, 1)
+    }
+
+    /// Adds a healthy entry with the given weight. Entries with a weight of 0 are never selected by [`RoundRobin::next_weighted`].
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::RoundRobin;
+    /// let mut backends: RoundRobin<2, &str> = RoundRobin::new();
+    ///
+    /// assert!(backends.add_weighted("a", 2).is_ok());
+    /// assert!(backends.add_weighted("b", 1).is_ok());
+    ///
+    /// let picks: Vec<&str> = (0..3).filter_map(|_| backends.next_weighted().copied()).collect();
+    ///
+    /// assert_eq!(picks, vec!["a", "b", "a"]);
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(())` if the entry was added
+    /// * [`Error::Overflow`] if the round robin already holds `SIZE` entries
+    pub fn add_weighted(&mut self, item: T, weight: u32) -> Result<(), Error> {
+        self.entries.push_back(Entry {
+            item,
+            weight,
+            current_weight: 0,
+            healthy: true,
+        })?;
+
+        Ok(())
+    }
+
+    /// Removes the entry at `index` - returning the removed item.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::RoundRobin;
+    /// let mut backends: RoundRobin<3, &str> = RoundRobin::new();
+    ///
+    /// assert!(backends.add("a").is_ok());
+    /// assert!(backends.add("b").is_ok());
+    ///
+    /// assert_eq!(backends.remove(0), Ok("a"));
+    /// assert_eq!(backends.next(), Some(&"b"));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(T)` if the entry was removed
+    /// * [`Error::IndexOutOfRange`] if there is no entry at `index`
+    pub fn remove(&mut self, index: usize) -> Result<T, Error> {
+        let entry = self.entries.remove_at(index)?;
+
+        if index < self.position {
+            self.position -= 1;
+        }
+        if self.position >= self.entries.len() {
+            self.position = 0;
+        }
+
+        Ok(entry.item)
+    }
+
+    /// Returns the next healthy entry - walking around the entries in turn.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::RoundRobin;
+    /// let mut backends: RoundRobin<2, &str> = RoundRobin::new();
+    ///
+    /// assert!(backends.add("a").is_ok());
+    /// assert!(backends.add("b").is_ok());
+    ///
+    /// assert_eq!(backends.next(), Some(&"a"));
+    /// assert_eq!(backends.next(), Some(&"b"));
+    /// assert_eq!(backends.next(), Some(&"a"));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Some(&T)` of the selected entry
+    /// * `None` if there are no healthy entries
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&T> {
+        let mut cursor = self.entries.cursor(self.position);
+
+        for _ in 0..self.entries.len() {
+            let entry = cursor.current()?;
+            cursor.move_next();
+
+            if entry.healthy {
+                self.position = cursor.index()?;
+
+                return Some(&entry.item);
+            }
+        }
+
+        None
+    }
+
+    /// Returns the next healthy entry - using smooth weighted round robin. In which, each healthy entry is selected in proportion to its weight.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::RoundRobin;
+    /// let mut backends: RoundRobin<3, &str> = RoundRobin::new();
+    ///
+    /// assert!(backends.add_weighted("a", 5).is_ok());
+    /// assert!(backends.add_weighted("b", 1).is_ok());
+    /// assert!(backends.add_weighted("c", 1).is_ok());
+    ///
+    /// let picks: Vec<&str> = (0..7).filter_map(|_| backends.next_weighted().copied()).collect();
+    ///
+    /// assert_eq!(picks, vec!["a", "a", "b", "a", "c", "a", "a"]);
+    /// ```
+    ///
+    /// # Returns
+    /// * `Some(&T)` of the selected entry
+    /// * `None` if there are no healthy entries with a weight greater than 0
+    pub fn next_weighted(&mut self) -> Option<&T> {
+        let mut total = 0;
+        let mut selected: Option<usize> = None;
+
+        for index in 0..self.entries.len() {
+            let entry = &mut self.entries[index];
+
+            if !entry.healthy || entry.weight == 0 {
+                continue;
+            }
+
+            entry.current_weight += i64::from(entry.weight);
+            total += i64::from(entry.weight);
+
+            let current_weight = entry.current_weight;
+            if selected
+                .is_none_or(|selected| current_weight > self.entries[selected].current_weight)
+            {
+                selected = Some(index);
+            }
+        }
+
+        let entry = &mut self.entries[selected?];
+        entry.current_weight -= total;
+
+        Some(&entry.item)
+    }
+
+    /// Marks the entry at `index` as healthy or unhealthy. Unhealthy entries are skipped until they are marked as healthy.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::RoundRobin;
+    /// # use cyclic_data_types::error::Error;
+    /// let mut backends: RoundRobin<2, &str> = RoundRobin::new();
+    ///
+    /// assert!(backends.add("a").is_ok());
+    ///
+    /// assert_eq!(backends.set_healthy(0, false), Ok(()));
+    /// assert_eq!(backends.next(), None);
+    ///
+    /// assert_eq!(backends.set_healthy(1, false), Err(Error::IndexOutOfRange));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(())` if the entry was updated
+    /// * [`Error::IndexOutOfRange`] if there is no entry at `index`
+    pub fn set_healthy(&mut self, index: usize, healthy: bool) -> Result<(), Error> {
+        if self.entries.len() <= index {
+            return Err(Error::IndexOutOfRange);
+        }
+
+        let entry = &mut self.entries[index];
+
+        entry.healthy = healthy;
+        entry.current_weight = 0;
+
+        Ok(())
+    }
+
+    /// Returns `true` if the entry at `index` is healthy - or `None` if there is no entry at `index`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::RoundRobin;
+    /// let mut backends: RoundRobin<2, &str> = RoundRobin::new();
+    ///
+    /// assert!(backends.add("a").is_ok());
+    ///
+    /// assert_eq!(backends.is_healthy(0), Some(true));
+    /// assert_eq!(backends.is_healthy(1), None);
+    /// ```
+    pub fn is_healthy(&self, index: usize) -> Option<bool> {
+        (index < self.entries.len()).then(|| self.entries[index].healthy)
+    }
+
+    /// Returns a reference to the item at `index` - or `None` if there is no entry at `index`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::RoundRobin;
+    /// let mut backends: RoundRobin<2, &str> = RoundRobin::new();
+    ///
+    /// assert!(backends.add("a").is_ok());
+    ///
+    /// assert_eq!(backends.get(0), Some(&"a"));
+    /// assert_eq!(backends.get(1), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        (index < self.entries.len()).then(|| &self.entries[index].item)
+    }
+
+    /// Returns the number of entries - both healthy and unhealthy.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::RoundRobin;
+    /// let mut backends: RoundRobin<2, &str> = RoundRobin::new();
+    ///
+    /// assert!(backends.add("a").is_ok());
+    ///
+    /// assert_eq!(backends.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no entries.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::RoundRobin;
+    /// let backends: RoundRobin<2, &str> = RoundRobin::new();
+    ///
+    /// assert!(backends.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<const SIZE: usize, T> Default for RoundRobin<SIZE, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const SIZE: usize, T: Debug> Debug for RoundRobin<SIZE, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.entries.iter().map(|entry| {
+                (
+                    &entry.item,
+                    match entry.healthy {
+                        true => "healthy",
+                        false => "unhealthy",
+                    },
+                )
+            }))
+            .finish()
+    }
+}
@@ -0,0 +1,149 @@
+use crate::storage::Storage;
+
+use super::List;
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool, S> List<SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    /// Returns `true` if the list contains an element equal to `elem`. This is done in `O(len)`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list : List<SIZE, i64, false> = vec![1,2,3].try_into().unwrap();
+    ///
+    /// assert!(list.contains(&2));
+    /// assert!(!list.contains(&4));
+    /// ```
+    pub fn contains(&self, elem: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.position(|other| other == elem).is_some()
+    }
+
+    /// Returns the index of the first element for which `pred` returns `true`. This is done in `O(len)`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list : List<SIZE, i64, false> = [1,2,3,2,1].into();
+    ///
+    /// assert_eq!(list.position(|elem| *elem == 2), Some(1));
+    /// assert_eq!(list.position(|elem| *elem > 3), None);
+    /// ```
+    pub fn position<P>(&self, mut pred: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let (mut front, mut back) = self.as_slices();
+        let offset = front.len();
+
+        front
+            .position(&mut pred)
+            .or_else(|| back.position(&mut pred).map(|index| offset + index))
+    }
+
+    /// Returns the index of the last element for which `pred` returns `true`. This is done in `O(len)`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list : List<SIZE, i64, false> = [1,2,3,2,1].into();
+    ///
+    /// assert_eq!(list.rposition(|elem| *elem == 2), Some(3));
+    /// assert_eq!(list.rposition(|elem| *elem > 3), None);
+    /// ```
+    pub fn rposition<P>(&self, mut pred: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let (mut front, mut back) = self.as_slices();
+        let offset = front.len();
+
+        back.rposition(&mut pred)
+            .map(|index| offset + index)
+            .or_else(|| front.rposition(&mut pred))
+    }
+
+    /// Returns the number of elements for which `pred` returns `true`. This is done in `O(len)`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list : List<SIZE, i64, false> = [1,2,3,4,5].into();
+    ///
+    /// assert_eq!(list.count_matching(|elem| elem % 2 == 1), 3);
+    /// ```
+    pub fn count_matching<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let (front, back) = self.as_slices();
+
+        front.chain(back).filter(|elem| pred(elem)).count()
+    }
+
+    /// Removes consecutive repeated elements - keeping the first element of each run. The remaining elements are compacted towards the front of the list in place. This is done in `O(len)`.
+    ///
+    /// *NOTE:* The list is not treated as cyclic here - so equal elements at the back and the front of the list are both kept.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 8;
+    /// let mut list : List<SIZE, i64, false> = [1,1,2,3,3,3,2,1].into();
+    ///
+    /// list.dedup();
+    ///
+    /// assert_eq!(list, vec![1,2,3,2,1].try_into().unwrap());
+    /// ```
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes consecutive elements which resolve to the same key - keeping the first element of each run. The remaining elements are compacted towards the front of the list in place. This is done in `O(len)`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list : List<SIZE, i64, false> = [10,11,20,21,12].into();
+    ///
+    /// list.dedup_by_key(|elem| elem / 10);
+    ///
+    /// assert_eq!(list, vec![10,20,12].try_into().unwrap());
+    /// ```
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: PartialEq,
+        F: FnMut(&T) -> K,
+    {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes every element for which `same` returns `true` when compared with the last kept element
+    fn dedup_by<F>(&mut self, mut same: F)
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let len = self.len();
+        if len < 2 {
+            return;
+        }
+
+        let mut kept = 1;
+        for index in 1..len {
+            if !same(&self[index], &self[kept - 1]) {
+                self.swap(index, kept);
+                kept += 1;
+            }
+        }
+
+        while self.len() > kept {
+            self.remove_back();
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::Deref,
+};
+
+use crate::error::Error;
+
+use super::List;
+
+/// `SeqList` is a [`List`] where every pushed element is given a monotonically increasing sequence number. Unlike an index, the sequence number of an element does not change as the list wraps - allowing callers (such as log tailers) to resume reading from the last element they have seen.
+///
+/// Elements are pushed to the back and removed from the front. Therefore, the sequence numbers of the elements in the list are always contiguous.
+///
+/// ```
+/// # use cyclic_data_types::list::SeqList;
+/// # use cyclic_data_types::error::Error;
+/// # const SIZE: usize = 3;
+/// let mut list: SeqList<SIZE, &str, true> = SeqList::new();
+///
+/// for line in ["a", "b", "c", "d"] {
+///     assert!(list.push_back(line).is_ok());
+/// }
+///
+/// assert_eq!(list.first_seq(), Some(1));
+/// assert_eq!(list.last_seq(), Some(3));
+///
+/// assert_eq!(list.get_by_seq(0), Err(Error::Overwritten));
+/// assert_eq!(list.get_by_seq(3), Ok(&"d"));
+///
+/// let tail: Vec<(u64, &&str)> = list.iter_since(2).unwrap().collect();
+///
+/// assert_eq!(tail, vec![(2, &"c"), (3, &"d")]);
+/// ```
+pub struct SeqList<const SIZE: usize, T, const WRITE_OVER: bool> {
+    list: List<SIZE, T, WRITE_OVER>,
+    next_seq: u64,
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool> SeqList<SIZE, T, WRITE_OVER> {
+    /// Creates an empty `SeqList` - where the first pushed element is given the sequence number 0.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::SeqList;
+    /// # const SIZE: usize = 3;
+    /// let list: SeqList<SIZE, i64, true> = SeqList::new();
+    ///
+    /// assert_eq!(list.next_seq(), 0);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            list: List::empty(),
+            next_seq: 0,
+        }
+    }
+
+    /// Pushes a new element to the back of the list - giving the element the next sequence number. If the list is full and `WRITE_OVER = true`, the first element is written over.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::SeqList;
+    /// # use cyclic_data_types::error::Error;
+    /// # const SIZE: usize = 1;
+    /// let mut list: SeqList<SIZE, i64, false> = SeqList::new();
+    ///
+    /// assert_eq!(list.push_back(1), Ok(0));
+    /// assert_eq!(list.push_back(2), Err(Error::Overflow));
+    /// ```
+    ///
+    /// # Returns
+    /// * The sequence number of the element if the push was successful
+    /// * [`Error::Overflow`] if List is full and the `WRITE_OVER` flag is set to `false`
+    pub fn push_back(&mut self, elem: T) -> Result<u64, Error> {
+        self.list.push_back(elem)?;
+
+        self.next_seq += 1;
+
+        Ok(self.next_seq - 1)
+    }
+
+    /// Removes the first element from the list and returns the removed element alongside its sequence number.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::SeqList;
+    /// # const SIZE: usize = 2;
+    /// let mut list: SeqList<SIZE, i64, true> = SeqList::new();
+    ///
+    /// assert!(list.push_back(1).is_ok());
+    ///
+    /// assert_eq!(list.remove_front(), Some((0, 1)));
+    /// assert_eq!(list.remove_front(), None);
+    /// ```
+    pub fn remove_front(&mut self) -> Option<(u64, T)> {
+        let seq = self.first_seq()?;
+
+        self.list.remove_front().map(|elem| (seq, elem))
+    }
+
+    /// Returns the element with the sequence number `seq`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::SeqList;
+    /// # use cyclic_data_types::error::Error;
+    /// # const SIZE: usize = 2;
+    /// let mut list: SeqList<SIZE, i64, true> = SeqList::new();
+    ///
+    /// for i in 0..3 {
+    ///     assert!(list.push_back(i * 10).is_ok());
+    /// }
+    ///
+    /// assert_eq!(list.get_by_seq(0), Err(Error::Overwritten));
+    /// assert_eq!(list.get_by_seq(1), Ok(&10));
+    /// assert_eq!(list.get_by_seq(2), Ok(&20));
+    /// assert_eq!(list.get_by_seq(3), Err(Error::IndexOutOfRange));
+    /// ```
+    ///
+    /// # Returns
+    /// * A reference to the element with the sequence number `seq`
+    /// * [`Error::Overwritten`] if the element has been written over or removed from the list
+    /// * [`Error::IndexOutOfRange`] if no element has been given the sequence number `seq` yet
+    pub fn get_by_seq(&self, seq: u64) -> Result<&T, Error> {
+        Ok(&self.list[self.index_of(seq)?])
+    }
+
+    /// Returns the sequence number of the first element in the list - or `None` if the list is empty.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::SeqList;
+    /// # const SIZE: usize = 2;
+    /// let mut list: SeqList<SIZE, i64, true> = SeqList::new();
+    ///
+    /// assert_eq!(list.first_seq(), None);
+    ///
+    /// for i in 0..3 {
+    ///     assert!(list.push_back(i).is_ok());
+    /// }
+    ///
+    /// assert_eq!(list.first_seq(), Some(1));
+    /// ```
+    pub fn first_seq(&self) -> Option<u64> {
+        match self.list.len() {
+            0 => None,
+            len => Some(self.next_seq - len as u64),
+        }
+    }
+
+    /// Returns the sequence number of the last element in the list - or `None` if the list is empty.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::SeqList;
+    /// # const SIZE: usize = 2;
+    /// let mut list: SeqList<SIZE, i64, true> = SeqList::new();
+    ///
+    /// assert_eq!(list.last_seq(), None);
+    ///
+    /// for i in 0..3 {
+    ///     assert!(list.push_back(i).is_ok());
+    /// }
+    ///
+    /// assert_eq!(list.last_seq(), Some(2));
+    /// ```
+    pub fn last_seq(&self) -> Option<u64> {
+        match self.list.len() {
+            0 => None,
+            _ => Some(self.next_seq - 1),
+        }
+    }
+
+    /// Returns the sequence number the next pushed element will be given.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::SeqList;
+    /// # const SIZE: usize = 2;
+    /// let mut list: SeqList<SIZE, i64, true> = SeqList::new();
+    ///
+    /// assert!(list.push_back(1).is_ok());
+    ///
+    /// assert_eq!(list.next_seq(), 1);
+    /// ```
+    pub fn next_seq(&self) -> u64 {
+        self.next_seq
+    }
+
+    /// Returns an iterator over the elements with a sequence number of at least `seq` - alongside their sequence numbers. Iterating from [`SeqList::next_seq`] results in an empty iterator.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::SeqList;
+    /// # use cyclic_data_types::error::Error;
+    /// # const SIZE: usize = 3;
+    /// let mut list: SeqList<SIZE, i64, true> = SeqList::new();
+    ///
+    /// for i in 0..5 {
+    ///     assert!(list.push_back(i * 10).is_ok());
+    /// }
+    ///
+    /// assert_eq!(list.iter_since(3).unwrap().collect::<Vec<(u64, &i64)>>(), vec![(3, &30), (4, &40)]);
+    /// assert_eq!(list.iter_since(5).unwrap().count(), 0);
+    ///
+    /// assert!(matches!(list.iter_since(1), Err(Error::Overwritten)));
+    /// assert!(matches!(list.iter_since(6), Err(Error::IndexOutOfRange)));
+    /// ```
+    ///
+    /// # Returns
+    /// * An iterator starting at the element with the sequence number `seq`
+    /// * [`Error::Overwritten`] if the element has been written over or removed from the list
+    /// * [`Error::IndexOutOfRange`] if `seq` is greater than the next sequence number
+    pub fn iter_since(&self, seq: u64) -> Result<SeqIter<'_, SIZE, T, WRITE_OVER>, Error> {
+        let index = match self.index_of(seq) {
+            Err(Error::IndexOutOfRange) if seq == self.next_seq => self.list.len(),
+            index => index?,
+        };
+
+        Ok(SeqIter {
+            list: &self.list,
+            index,
+            seq,
+        })
+    }
+
+    /// Returns the index of the element with the sequence number `seq`
+    fn index_of(&self, seq: u64) -> Result<usize, Error> {
+        if seq >= self.next_seq {
+            return Err(Error::IndexOutOfRange);
+        }
+
+        let first_seq = self.next_seq - self.list.len() as u64;
+
+        match seq.checked_sub(first_seq) {
+            Some(index) => Ok(index as usize),
+            None => Err(Error::Overwritten),
+        }
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Default for SeqList<SIZE, T, W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Deref for SeqList<SIZE, T, W> {
+    type Target = List<SIZE, T, W>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<const S: usize, T, const W: bool> Display for SeqList<S, T, W>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.list)
+    }
+}
+
+impl<const S: usize, T, const W: bool> Debug for SeqList<S, T, W>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SeqList")
+            .field("", &self.list)
+            .field("next_seq", &self.next_seq)
+            .finish()
+    }
+}
+
+/// `SeqIter` is the iterator returned by [`SeqList::iter_since`]. Which yields every element alongside its sequence number.
+pub struct SeqIter<'a, const SIZE: usize, T, const WRITE_OVER: bool> {
+    list: &'a List<SIZE, T, WRITE_OVER>,
+    index: usize,
+    seq: u64,
+}
+
+impl<'a, const SIZE: usize, T, const WRITE_OVER: bool> Iterator
+    for SeqIter<'a, SIZE, T, WRITE_OVER>
+{
+    type Item = (u64, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.list.len() {
+            return None;
+        }
+
+        let item = (self.seq, &self.list[self.index]);
+
+        self.index += 1;
+        self.seq += 1;
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.len() - self.index;
+
+        (len, Some(len))
+    }
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool> ExactSizeIterator
+    for SeqIter<'_, SIZE, T, WRITE_OVER>
+{
+}
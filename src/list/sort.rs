@@ -0,0 +1,223 @@
+use std::cmp::Ordering;
+
+use crate::{
+    error::Error,
+    storage::{Slot, Storage},
+};
+
+use super::List;
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool, S> List<SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
+    /// Sorts the list in ascending order - preserving the order of equal elements. This is done in `O(len * log(len))`.
+    ///
+    /// The list does not need to be contiguous (i.e. it may wrap around the underlying array) before being sorted.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list : List<SIZE, i64, true> = [5,4,3,2,1].into();
+    ///
+    /// // wraps the list around the underlying array
+    /// assert!(list.push_back(0).is_ok());
+    ///
+    /// list.sort();
+    ///
+    /// assert_eq!(list, [0,1,2,3,4].into());
+    /// ```
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sorts the list using the `compare` function - preserving the order of equal elements. This is done in `O(len * log(len))`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list : List<SIZE, i64, false> = [1,4,2,5,3].into();
+    ///
+    /// list.sort_by(|a, b| b.cmp(a));
+    ///
+    /// assert_eq!(list, [5,4,3,2,1].into());
+    /// ```
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        // every slot of a contiguous list holds an element
+        self.make_contiguous()
+            .sort_by(|a, b| unsafe { compare(a.get(), b.get()) });
+    }
+
+    /// Sorts the list by the key extracted using the `key` function - without preserving the order of equal elements. This is done in `O(len * log(len))`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list : List<SIZE, i64, false> = [-3,1,-5,4,2].into();
+    ///
+    /// list.sort_unstable_by_key(|elem| elem.abs());
+    ///
+    /// assert_eq!(list, [1,2,-3,4,-5].into());
+    /// ```
+    pub fn sort_unstable_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        // every slot of a contiguous list holds an element
+        self.make_contiguous()
+            .sort_unstable_by(|a, b| unsafe { key(a.get()).cmp(&key(b.get())) });
+    }
+
+    /// Binary searches a sorted list using the `compare` function - which returns the ordering of an element relative to the target. This is done in `O(log(len))`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list : List<SIZE, i64, false> = [1,3,5,7,9].into();
+    ///
+    /// assert_eq!(list.binary_search_by(|elem| elem.cmp(&7)), Ok(3));
+    /// assert_eq!(list.binary_search_by(|elem| elem.cmp(&4)), Err(2));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(index)` of a matching element - if there are several matches, any one of them may be returned
+    /// * `Err(index)` where a matching element could be inserted while keeping the list sorted
+    pub fn binary_search_by<F>(&self, mut compare: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut low = 0;
+        let mut high = self.len();
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+
+            match compare(&self[mid]) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+
+        Err(low)
+    }
+
+    /// Returns the index of the first element for which `pred` returns `false` - assuming the list is partitioned, such that `pred` returns `true` for every element before that index and `false` for every element after it. This is done in `O(log(len))`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let list : List<SIZE, i64, false> = [1,2,2,3,4].into();
+    ///
+    /// assert_eq!(list.partition_point(|elem| *elem < 2), 1);
+    /// assert_eq!(list.partition_point(|elem| *elem <= 2), 3);
+    /// assert_eq!(list.partition_point(|_| true), 5);
+    /// ```
+    pub fn partition_point<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut low = 0;
+        let mut high = self.len();
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+
+            match pred(&self[mid]) {
+                true => low = mid + 1,
+                false => high = mid,
+            }
+        }
+
+        low
+    }
+
+    /// Inserts an element into a sorted list - keeping the list sorted. The element is inserted after every element equal to it. This is done in `O(len)`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # const SIZE: usize = 5;
+    /// let mut list : List<SIZE, i64, false> = vec![1,3,5].try_into().unwrap();
+    ///
+    /// assert_eq!(list.insert_sorted(4), Ok(2));
+    ///
+    /// assert_eq!(list, vec![1,3,4,5].try_into().unwrap());
+    /// ```
+    ///
+    /// If the list is full - the list has two options based on the `WRITE_OVER` flag.
+    /// 1. `WRITE_OVER = true`
+    ///
+    /// The smallest element (the front of the list) is written over and dropped - before the new element is inserted. Unless the new element is smaller than every element in the list, in which case the new element is the one dropped - resulting in no change to the state of the list.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # use cyclic_data_types::error::Error;
+    /// # const SIZE: usize = 5;
+    /// let mut list : List<SIZE, i64, true> = [1,2,3,4,5].into();
+    ///
+    /// assert_eq!(list.insert_sorted(4), Ok(3));
+    /// assert_eq!(list, [2,3,4,4,5].into());
+    ///
+    /// assert_eq!(list.insert_sorted(0), Err(Error::Overflow));
+    /// assert_eq!(list, [2,3,4,4,5].into());
+    /// ```
+    ///
+    /// 2. `WRITE_OVER = false`
+    ///
+    /// The new element isn't added to the list. Resulting in no change to the state of the list.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::List;
+    /// # use cyclic_data_types::error::Error;
+    /// # const SIZE: usize = 5;
+    /// let mut list : List<SIZE, i64, false> = [1,2,3,4,5].into();
+    ///
+    /// assert_eq!(list.insert_sorted(4), Err(Error::Overflow));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(index)` of the inserted element
+    /// * [`Error::Overflow`] if List is full and either the `WRITE_OVER` flag is set to `false`, or the element is smaller than every element in the list
+    pub fn insert_sorted(&mut self, elem: T) -> Result<usize, Error>
+    where
+        T: Ord,
+    {
+        let index = self.partition_point(|other| *other <= elem);
+        let full = self.len() == self.capacity();
+
+        if full && index == 0 {
+            return Err(Error::Overflow);
+        }
+
+        self.insert_at(elem, index)?;
+
+        // the front of a full list was dropped before the element was inserted
+        match full {
+            true => Ok(index.saturating_sub(1)),
+            false => Ok(index),
+        }
+    }
+
+    /// Rotates the underlying storage so that the list starts at the beginning of the storage - returning the occupied slots
+    fn make_contiguous(&mut self) -> &mut [S::Slot] {
+        let len = self.len();
+
+        if len == 0 {
+            return &mut [];
+        }
+
+        let start = self.list.start;
+        self.list.list.slots_mut().rotate_left(start);
+        self.list.start = 0;
+        self.list.end = len - 1;
+
+        &mut self.list.list.slots_mut()[..len]
+    }
+}
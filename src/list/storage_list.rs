@@ -0,0 +1,125 @@
+use std::convert::TryFrom;
+
+use crate::error::Error;
+
+use super::{Iter, List};
+
+/// `StorageList` is a [`List`] that runs over any [`Storage`](crate::storage::Storage) - rather than the default inline array. Such as an owned array, a boxed slice or a borrowed slice of [`MaybeUninit<T>`](std::mem::MaybeUninit) (from a DMA region or a `static mut` buffer).
+///
+/// Apart from where the elements are stored, `StorageList` is the same type as [`List`] - so has the same API and semantics. Such as, wrap-around retrieval using `get`, sorting, searching and the `WRITE_OVER` overflow policy.
+///
+/// *NOTE:* The `SIZE` of a `StorageList` is 0, as its capacity is the capacity of the storage.
+///
+/// # Generics
+///
+/// 1. `S: Storage<T>`
+///
+/// S is the storage the elements of the list are stored in. The capacity of the list is the capacity of the storage.
+///
+/// 2. `T: Sized`
+///
+/// T is the type of element stored in the list
+///
+/// 3. `const WRITE_OVER: bool`
+///
+/// WRITE_OVER is a generic constant that is used to determine if elements should be over written on overflow
+///
+/// # Creating Storage Lists
+///
+/// ```
+/// # use cyclic_data_types::list::StorageList;
+/// use std::mem::MaybeUninit;
+///
+/// let storage: Box<[MaybeUninit<String>]> = Box::new_uninit_slice(3);
+/// let mut list: StorageList<_, String, false> = StorageList::from_storage(storage).unwrap();
+///
+/// assert!(list.push_back(String::from("a")).is_ok());
+///
+/// assert_eq!(list.len(), 1);
+/// assert_eq!(list.capacity(), 3);
+/// ```
+///
+/// A list cannot be created from a storage without any capacity.
+/// ```
+/// # use cyclic_data_types::list::StorageList;
+/// # use cyclic_data_types::error::Error;
+/// let mut storage: [Option<i64>; 0] = [];
+/// let list: Result<StorageList<&mut [Option<i64>], i64, false>, Error> = StorageList::from_storage(&mut storage[..]);
+///
+/// assert!(list.is_err());
+/// ```
+pub type StorageList<S, T, const WRITE_OVER: bool> = List<0, T, WRITE_OVER, S>;
+
+/// `HeapList` is a [`StorageList`] whose capacity is chosen at construction time rather than at compile time. Elements are stored in a single boxed slice - which is allocated once and never reallocated.
+///
+/// # Creating Heap Lists
+///
+/// 1. Empty list
+///
+/// ```
+/// # use cyclic_data_types::list::HeapList;
+/// let capacity = 5;
+/// let list: HeapList<i64, false> = HeapList::new(capacity).unwrap();
+///
+/// assert_eq!(list.len(), 0);
+/// assert_eq!(list.capacity(), 5);
+/// ```
+///
+/// A list cannot be created without any capacity.
+/// ```
+/// # use cyclic_data_types::list::HeapList;
+/// # use cyclic_data_types::error::Error;
+/// let list: Result<HeapList<i64, false>, Error> = HeapList::new(0);
+///
+/// assert_eq!(list, Err(Error::InvalidSize));
+/// ```
+///
+/// 2. From Vectors
+///
+/// The capacity of the list is the same as the length of the vector.
+/// ```
+/// # use cyclic_data_types::list::HeapList;
+/// let list: HeapList<i64, false> = vec![1, 2, 3].try_into().unwrap();
+///
+/// assert_eq!(list.len(), 3);
+/// assert_eq!(list.capacity(), 3);
+/// ```
+pub type HeapList<T, const WRITE_OVER: bool> = StorageList<Box<[Option<T>]>, T, WRITE_OVER>;
+
+/// Iterator over the elements of a [`HeapList`]
+pub type HeapIter<'a, T, const WRITE_OVER: bool> = StorageIter<'a, Box<[Option<T>]>, T, WRITE_OVER>;
+
+/// Iterator over the elements of a [`StorageList`]
+pub type StorageIter<'a, S, T, const WRITE_OVER: bool> = Iter<'a, 0, T, WRITE_OVER, S>;
+
+impl<T, const WRITE_OVER: bool> HeapList<T, WRITE_OVER> {
+    /// Creates an empty list that can hold at most `capacity` elements.
+    ///
+    /// ```
+    /// # use cyclic_data_types::list::HeapList;
+    /// let list: HeapList<i64, true> = HeapList::new(10).unwrap();
+    ///
+    /// assert_eq!(list.capacity(), 10);
+    /// ```
+    ///
+    /// # Returns
+    /// * Self if `capacity` > 0
+    /// * [`Error::InvalidSize`] if `capacity` = 0
+    pub fn new(capacity: usize) -> Result<Self, Error> {
+        Self::from_storage((0..capacity).map(|_| None).collect())
+    }
+}
+
+impl<T, const WRITE_OVER: bool> TryFrom<Vec<T>> for HeapList<T, WRITE_OVER> {
+    type Error = Error;
+
+    fn try_from(value: Vec<T>) -> Result<Self, Self::Error> {
+        let mut list = Self::from_storage(value.iter().map(|_| None).collect())?;
+
+        for elem in value {
+            list.push_back(elem)?;
+        }
+
+        Ok(list)
+    }
+}
@@ -1,3 +1,25 @@
+use crate::list::List;
+
+/// Returns a write over list of `values` - which wraps around the underlying array after `offset` elements
+fn rotated<const SIZE: usize, T: Clone + Default>(
+    values: &[T],
+    offset: usize,
+) -> List<SIZE, T, true> {
+    let mut list: List<SIZE, T, true> = List::default();
+
+    for _ in 0..offset {
+        assert!(list.push_back(T::default()).is_ok());
+    }
+    for value in values {
+        assert!(list.push_back(value.clone()).is_ok());
+    }
+    while list.len() > values.len() {
+        list.remove_front();
+    }
+
+    list
+}
+
 mod len {
     use std::cmp::min;
 
@@ -85,6 +107,8 @@ mod len {
 // }
 
 mod push_back {
+    use std::marker::PhantomData;
+
     use crate::{error::Error, list::List, CyclicList};
 
     const SIZE: usize = 5;
@@ -98,6 +122,7 @@ mod push_back {
                 start: 0,
                 end: 0,
                 empty: true,
+                elem: PhantomData,
             },
         };
         assert_eq!(list, expect);
@@ -109,6 +134,7 @@ mod push_back {
                 start: 0,
                 end: 0,
                 empty: false,
+                elem: PhantomData,
             },
         };
         assert_eq!(list, expect);
@@ -124,6 +150,7 @@ mod push_back {
                 start: 0,
                 end: 0,
                 empty: true,
+                elem: PhantomData,
             },
         };
         assert_eq!(list, expect);
@@ -135,6 +162,7 @@ mod push_back {
                 start: 0,
                 end: 0,
                 empty: false,
+                elem: PhantomData,
             },
         };
         assert_eq!(list, expect);
@@ -146,6 +174,7 @@ mod push_back {
                 start: 0,
                 end: 1,
                 empty: false,
+                elem: PhantomData,
             },
         };
         assert_eq!(list, expect);
@@ -157,6 +186,7 @@ mod push_back {
                 start: 0,
                 end: 2,
                 empty: false,
+                elem: PhantomData,
             },
         };
         assert_eq!(list, expect);
@@ -168,6 +198,7 @@ mod push_back {
                 start: 0,
                 end: 3,
                 empty: false,
+                elem: PhantomData,
             },
         };
         assert_eq!(list, expect);
@@ -179,6 +210,7 @@ mod push_back {
                 start: 0,
                 end: 4,
                 empty: false,
+                elem: PhantomData,
             },
         };
         assert_eq!(list, expect);
@@ -206,6 +238,7 @@ mod push_back {
                 start: 1,
                 end: 0,
                 empty: false,
+                elem: PhantomData,
             },
         };
         assert_eq!(list, expect);
@@ -220,6 +253,8 @@ mod push_back {
 }
 
 mod push_front {
+    use std::marker::PhantomData;
+
     use crate::{error::Error, list::List, CyclicList};
 
     const SIZE: usize = 5;
@@ -233,6 +268,7 @@ mod push_front {
                 start: 0,
                 end: 0,
                 empty: true,
+                elem: PhantomData,
             },
         };
         assert_eq!(list, expect);
@@ -244,6 +280,7 @@ mod push_front {
                 start: 0,
                 end: 0,
                 empty: false,
+                elem: PhantomData,
             },
         };
         assert_eq!(list, expect);
@@ -259,6 +296,7 @@ mod push_front {
                 start: 0,
                 end: 0,
                 empty: true,
+                elem: PhantomData,
             },
         };
         assert_eq!(list, expect);
@@ -270,6 +308,7 @@ mod push_front {
                 start: 0,
                 end: 0,
                 empty: false,
+                elem: PhantomData,
             },
         };
         assert_eq!(list, expect);
@@ -281,6 +320,7 @@ mod push_front {
                 start: 4,
                 end: 0,
                 empty: false,
+                elem: PhantomData,
             },
         };
         assert_eq!(list, expect);
@@ -292,6 +332,7 @@ mod push_front {
                 start: 3,
                 end: 0,
                 empty: false,
+                elem: PhantomData,
             },
         };
         assert_eq!(list, expect);
@@ -303,6 +344,7 @@ mod push_front {
                 start: 2,
                 end: 0,
                 empty: false,
+                elem: PhantomData,
             },
         };
         assert_eq!(list, expect);
@@ -314,6 +356,7 @@ mod push_front {
                 start: 1,
                 end: 0,
                 empty: false,
+                elem: PhantomData,
             },
         };
         assert_eq!(list, expect);
@@ -336,6 +379,7 @@ mod push_front {
                 start: 0,
                 end: 4,
                 empty: false,
+                elem: PhantomData,
             },
         };
         assert_eq!(list, expect);
@@ -1283,6 +1327,8 @@ mod index_mut {
 }
 
 mod try_from_vec {
+    use std::marker::PhantomData;
+
     use crate::{error::Error, list::List, CyclicList};
 
     const SIZE: usize = 5;
@@ -1304,6 +1350,7 @@ mod try_from_vec {
                 start: 0,
                 end: 2,
                 empty: false,
+                elem: PhantomData,
             },
         };
 
@@ -1320,6 +1367,7 @@ mod try_from_vec {
                 start: 0,
                 end: 4,
                 empty: false,
+                elem: PhantomData,
             },
         };
 
@@ -1343,6 +1391,7 @@ mod try_from_vec {
                 start: 1,
                 end: 0,
                 empty: false,
+                elem: PhantomData,
             },
         };
 
@@ -1353,6 +1402,8 @@ mod try_from_vec {
 mod try_from_linked_list {
     use std::collections::LinkedList;
 
+    use std::marker::PhantomData;
+
     use crate::{error::Error, list::List, CyclicList};
 
     const SIZE: usize = 5;
@@ -1374,6 +1425,7 @@ mod try_from_linked_list {
                 start: 0,
                 end: 2,
                 empty: false,
+                elem: PhantomData,
             },
         };
 
@@ -1391,6 +1443,7 @@ mod try_from_linked_list {
                 start: 0,
                 end: 4,
                 empty: false,
+                elem: PhantomData,
             },
         };
 
@@ -1416,6 +1469,7 @@ mod try_from_linked_list {
                 start: 1,
                 end: 0,
                 empty: false,
+                elem: PhantomData,
             },
         };
 
@@ -1424,6 +1478,8 @@ mod try_from_linked_list {
 }
 
 mod try_from_iter {
+    use std::marker::PhantomData;
+
     use crate::{error::Error, list::List, CyclicList};
 
     const SIZE: usize = 5;
@@ -1450,6 +1506,7 @@ mod try_from_iter {
                 start: 0,
                 end: 2,
                 empty: false,
+                elem: PhantomData,
             },
         };
 
@@ -1469,6 +1526,7 @@ mod try_from_iter {
                 start: 0,
                 end: 4,
                 empty: false,
+                elem: PhantomData,
             },
         };
 
@@ -1499,6 +1557,7 @@ mod try_from_iter {
                 start: 1,
                 end: 0,
                 empty: false,
+                elem: PhantomData,
             },
         };
 
@@ -1582,3 +1641,1596 @@ mod swap_write_over {
         assert!(list.push_back(6).is_ok())
     }
 }
+
+mod with_evict {
+    use crate::{error::Error, list::List};
+
+    const SIZE: usize = 3;
+
+    #[test]
+    fn no_eviction() {
+        let mut evicted: Vec<i64> = Vec::new();
+        let mut list = List::<SIZE, i64, true>::default().with_evict(|elem| evicted.push(elem));
+
+        for i in 1..=3 {
+            assert!(list.push_back(i).is_ok());
+        }
+
+        assert_eq!(list.len(), 3);
+        assert!(evicted.is_empty());
+    }
+
+    #[test]
+    fn push_back_overflow() {
+        let mut evicted: Vec<i64> = Vec::new();
+        let mut list = List::<SIZE, i64, true>::default().with_evict(|elem| evicted.push(elem));
+
+        for i in 1..=7 {
+            assert!(list.push_back(i).is_ok());
+        }
+
+        assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![5, 6, 7]);
+        assert_eq!(evicted, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn push_front_overflow() {
+        let mut evicted: Vec<i64> = Vec::new();
+        let mut list = List::<SIZE, i64, true>::default().with_evict(|elem| evicted.push(elem));
+
+        for i in 1..=5 {
+            assert!(list.push_front(i).is_ok());
+        }
+
+        assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![5, 4, 3]);
+        assert_eq!(evicted, vec![1, 2]);
+    }
+
+    #[test]
+    fn mixed_overflow() {
+        let mut evicted: Vec<i64> = Vec::new();
+        let mut list =
+            List::<SIZE, i64, true>::from([1, 2, 3]).with_evict(|elem| evicted.push(elem));
+
+        assert!(list.push_back(4).is_ok());
+        assert!(list.push_front(0).is_ok());
+
+        assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![0, 2, 3]);
+        assert_eq!(evicted, vec![1, 4]);
+    }
+
+    #[test]
+    fn removal_is_not_eviction() {
+        let mut evicted: Vec<i64> = Vec::new();
+        let mut list =
+            List::<SIZE, i64, true>::from([1, 2, 3]).with_evict(|elem| evicted.push(elem));
+
+        assert_eq!(list.remove_front(), Some(1));
+        assert_eq!(list.remove_back(), Some(3));
+        assert!(list.push_back(4).is_ok());
+
+        assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![2, 4]);
+        assert!(evicted.is_empty());
+    }
+
+    #[test]
+    fn no_write_over() {
+        let mut evicted: Vec<i64> = Vec::new();
+        let mut list =
+            List::<SIZE, i64, false>::from([1, 2, 3]).with_evict(|elem| evicted.push(elem));
+
+        assert_eq!(list.push_back(4).err(), Some(Error::Overflow));
+        assert_eq!(list.push_front(0).err(), Some(Error::Overflow));
+
+        assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![1, 2, 3]);
+        assert!(evicted.is_empty());
+    }
+
+    #[test]
+    fn boxed_handler() {
+        let mut evicted: Vec<i64> = Vec::new();
+        {
+            let handler: Box<dyn FnMut(i64) + '_> = Box::new(|elem| evicted.push(elem));
+            let mut list = List::<SIZE, i64, true>::from([1, 2, 3]).with_evict(handler);
+
+            assert!(list.push_back(4).is_ok());
+        }
+        assert_eq!(evicted, vec![1]);
+    }
+}
+
+mod heap_list {
+    use crate::{error::Error, list::HeapList};
+
+    const CAPACITY: usize = 5;
+
+    #[test]
+    fn new() {
+        let list: HeapList<i64, false> = HeapList::new(CAPACITY).unwrap();
+
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.capacity(), CAPACITY);
+        assert_eq!(list.get(0), None);
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let list: Result<HeapList<i64, false>, Error> = HeapList::new(0);
+        assert_eq!(list, Err(Error::InvalidSize));
+
+        let list: Result<HeapList<i64, false>, Error> = Vec::new().try_into();
+        assert_eq!(list, Err(Error::InvalidSize));
+    }
+
+    #[test]
+    fn eq_ignores_capacity() {
+        let mut list: HeapList<i64, false> = HeapList::new(CAPACITY).unwrap();
+        let other: HeapList<i64, false> = vec![1, 2].try_into().unwrap();
+
+        assert!(list.push_back(1).is_ok());
+        assert_ne!(list, other);
+
+        assert!(list.push_back(2).is_ok());
+        assert_eq!(list, other);
+    }
+
+    #[test]
+    fn push_back_no_overflow() {
+        let mut list: HeapList<i64, false> = HeapList::new(CAPACITY).unwrap();
+
+        for i in 0..5 {
+            assert!(list.push_back(i).is_ok());
+            assert_eq!(list.len(), (i + 1) as usize);
+        }
+
+        assert_eq!(list.push_back(5).err(), Some(Error::Overflow));
+        assert_eq!(
+            list.iter().copied().collect::<Vec<i64>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn push_back_overflow() {
+        let mut list: HeapList<i64, true> = HeapList::new(CAPACITY).unwrap();
+
+        for i in 0..8 {
+            assert!(list.push_back(i).is_ok());
+        }
+
+        assert_eq!(list.len(), CAPACITY);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<i64>>(),
+            vec![3, 4, 5, 6, 7]
+        );
+    }
+
+    #[test]
+    fn push_front_overflow() {
+        let mut list: HeapList<i64, true> = HeapList::new(CAPACITY).unwrap();
+
+        for i in 0..8 {
+            assert!(list.push_front(i).is_ok());
+        }
+
+        assert_eq!(list.len(), CAPACITY);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<i64>>(),
+            vec![7, 6, 5, 4, 3]
+        );
+    }
+
+    #[test]
+    fn get() {
+        let mut list: HeapList<i64, true> = vec![1, 2, 3, 4, 5].try_into().unwrap();
+
+        assert!(list.push_back(6).is_ok());
+
+        for i in 0..5isize {
+            assert_eq!(list.get(i), Some(&(i as i64 + 2)));
+            assert_eq!(list.get(i + 5), Some(&(i as i64 + 2)));
+            assert_eq!(list.get(i - 5), Some(&(i as i64 + 2)));
+        }
+    }
+
+    #[test]
+    fn remove() {
+        let mut list: HeapList<i64, true> = vec![1, 2, 3].try_into().unwrap();
+
+        assert!(list.push_back(4).is_ok());
+
+        assert_eq!(list.remove_front(), Some(2));
+        assert_eq!(list.remove_back(), Some(4));
+        assert_eq!(list.remove_back(), Some(3));
+        assert_eq!(list.remove_back(), None);
+        assert_eq!(list.remove_front(), None);
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn display() {
+        let mut list: HeapList<i64, true> = HeapList::new(3).unwrap();
+        assert_eq!(list.to_string(), "[]");
+
+        for i in 1..=4 {
+            assert!(list.push_back(i).is_ok());
+        }
+        assert_eq!(list.to_string(), "[2, 3, 4]");
+    }
+
+    #[test]
+    fn debug() {
+        let mut list: HeapList<i64, true> = vec![1, 2, 3].try_into().unwrap();
+
+        assert!(list.push_back(4).is_ok());
+
+        assert_eq!(
+            "List { : CyclicList { list: [Some(4), Some(2), Some(3)], start: 1, end: 0, size: 3 } }",
+            format!("{list:?}")
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_range() {
+        let list: HeapList<i64, true> = vec![1, 2, 3].try_into().unwrap();
+
+        let _ = list[3usize];
+    }
+}
+
+mod growable {
+    use crate::list::{GrowableList, List};
+
+    const SIZE: usize = 4;
+
+    #[test]
+    fn stays_inline() {
+        let mut list: GrowableList<SIZE, i64> = GrowableList::new();
+
+        for i in 0..4 {
+            list.push_back(i);
+        }
+
+        assert!(list.is_inline());
+        assert_eq!(list.capacity(), SIZE);
+        assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn doubles_on_overflow() {
+        let mut list: GrowableList<SIZE, i64> = GrowableList::new();
+
+        for i in 0..5 {
+            list.push_back(i);
+        }
+        assert!(!list.is_inline());
+        assert_eq!(list.capacity(), 8);
+
+        for i in 5..9 {
+            list.push_back(i);
+        }
+        assert_eq!(list.capacity(), 16);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<i64>>(),
+            (0..9).collect::<Vec<i64>>()
+        );
+    }
+
+    #[test]
+    fn keeps_order_when_wrapped() {
+        let mut list: GrowableList<SIZE, i64> = GrowableList::new();
+
+        list.push_back(2).push_back(3);
+        list.push_front(1).push_front(0);
+        assert!(list.is_inline());
+
+        list.push_front(-1);
+        list.push_back(4);
+
+        assert_eq!(
+            list.iter().copied().collect::<Vec<i64>>(),
+            vec![-1, 0, 1, 2, 3, 4]
+        );
+        assert_eq!(list.get(-1), Some(&4));
+        assert_eq!(list.get(6), Some(&-1));
+    }
+
+    #[test]
+    fn remove_after_grow() {
+        let mut list: GrowableList<SIZE, i64> = (0..6).collect();
+
+        assert_eq!(list.remove_front(), Some(0));
+        assert_eq!(list.remove_back(), Some(5));
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.capacity(), 8);
+    }
+
+    #[test]
+    fn shrink_to_fit() {
+        let mut list: GrowableList<SIZE, i64> = (0..10).collect();
+        assert_eq!(list.capacity(), 16);
+
+        list.shrink_to_fit();
+        assert_eq!(list.capacity(), 10);
+        assert!(!list.is_inline());
+
+        for _ in 0..7 {
+            list.remove_front();
+        }
+        list.shrink_to_fit();
+
+        assert!(list.is_inline());
+        assert_eq!(list.capacity(), SIZE);
+        assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![7, 8, 9]);
+    }
+
+    #[test]
+    fn shrink_inline_is_noop() {
+        let mut list: GrowableList<SIZE, i64> = (0..3).collect();
+
+        list.shrink_to_fit();
+
+        assert!(list.is_inline());
+        assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn from_list() {
+        let list: List<SIZE, i64, true> = [1, 2, 3, 4].into();
+        let mut list: GrowableList<SIZE, i64> = list.into();
+
+        list.push_back(5);
+
+        assert_eq!(list.to_string(), "[1, 2, 3, 4, 5]");
+    }
+}
+
+mod storage_list {
+    use std::{mem::MaybeUninit, rc::Rc};
+
+    use crate::{error::Error, list::StorageList};
+
+    #[test]
+    fn array_storage() {
+        let mut list: StorageList<[Option<i64>; 3], i64, true> =
+            StorageList::from_storage([None; 3]).unwrap();
+
+        for i in 0..5 {
+            assert!(list.push_back(i).is_ok());
+        }
+
+        assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![2, 3, 4]);
+        assert_eq!(list.get(-1), Some(&4));
+    }
+
+    #[test]
+    fn borrowed_option_storage() {
+        let mut buffer: [Option<i64>; 3] = [None; 3];
+
+        {
+            let mut list: StorageList<&mut [Option<i64>], i64, false> =
+                StorageList::from_storage(&mut buffer[..]).unwrap();
+
+            assert!(list.push_back(1).is_ok());
+            assert!(list.push_front(0).is_ok());
+            assert!(list.push_back(2).is_ok());
+            assert_eq!(list.push_back(3).err(), Some(Error::Overflow));
+
+            assert_eq!(list.to_string(), "[0, 1, 2]");
+        }
+
+        assert_eq!(buffer, [Some(1), Some(2), Some(0)]);
+    }
+
+    #[test]
+    fn borrowed_uninit_storage() {
+        let mut buffer: [MaybeUninit<i64>; 4] = [MaybeUninit::uninit(); 4];
+
+        let mut list: StorageList<&mut [MaybeUninit<i64>], i64, true> =
+            StorageList::from_storage(&mut buffer[..]).unwrap();
+
+        for i in 0..6 {
+            assert!(list.push_front(i).is_ok());
+        }
+
+        assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![5, 4, 3, 2]);
+        assert_eq!(list.remove_back(), Some(2));
+        assert_eq!(list.remove_front(), Some(5));
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn boxed_uninit_storage() {
+        let storage: Box<[MaybeUninit<String>]> = Box::new_uninit_slice(2);
+        let mut list: StorageList<_, String, true> = StorageList::from_storage(storage).unwrap();
+
+        assert!(list.push_back(String::from("a")).is_ok());
+        assert!(list.push_back(String::from("b")).is_ok());
+        assert!(list.push_back(String::from("c")).is_ok());
+
+        assert_eq!(list.to_string(), "[b, c]");
+    }
+
+    #[test]
+    fn empty_storage() {
+        let mut buffer: [MaybeUninit<i64>; 0] = [];
+
+        let list: Result<StorageList<&mut [MaybeUninit<i64>], i64, true>, Error> =
+            StorageList::from_storage(&mut buffer[..]);
+
+        assert!(list.is_err());
+    }
+
+    #[test]
+    fn drops_elements() {
+        let counter = Rc::new(());
+
+        {
+            let storage: Box<[MaybeUninit<Rc<()>>]> = Box::new_uninit_slice(3);
+            let mut list: StorageList<_, Rc<()>, true> =
+                StorageList::from_storage(storage).unwrap();
+
+            for _ in 0..5 {
+                assert!(list.push_back(counter.clone()).is_ok());
+            }
+
+            // two elements have been written over
+            assert_eq!(Rc::strong_count(&counter), 4);
+
+            drop(list.remove_front());
+            assert_eq!(Rc::strong_count(&counter), 3);
+        }
+
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn debug() {
+        let mut buffer: [MaybeUninit<i64>; 3] = [MaybeUninit::uninit(); 3];
+        let mut list: StorageList<&mut [MaybeUninit<i64>], i64, true> =
+            StorageList::from_storage(&mut buffer[..]).unwrap();
+
+        assert!(list.push_back(1).is_ok());
+        assert!(list.push_front(0).is_ok());
+
+        assert_eq!(
+            "List { : CyclicList { list: [Some(1), None, Some(0)], start: 2, end: 0, size: 2 } }",
+            format!("{list:?}")
+        );
+    }
+
+    #[test]
+    fn list_api() {
+        let storage: Box<[MaybeUninit<String>]> = Box::new_uninit_slice(5);
+        let mut list: StorageList<_, String, true> = StorageList::from_storage(storage).unwrap();
+
+        for elem in ["d", "b", "b", "e", "c", "a"] {
+            assert!(list.push_back(String::from(elem)).is_ok());
+        }
+        assert_eq!(list.to_string(), "[b, b, e, c, a]");
+
+        list.dedup();
+        assert_eq!(list.to_string(), "[b, e, c, a]");
+
+        assert!(list.insert_at(String::from("f"), 1).is_ok());
+        assert_eq!(list.to_string(), "[b, f, e, c, a]");
+
+        // the list wraps around the underlying storage
+        list.sort();
+        assert_eq!(list.to_string(), "[a, b, c, e, f]");
+
+        assert_eq!(list.binary_search_by(|elem| elem.as_str().cmp("e")), Ok(3));
+        assert_eq!(list.binary_search_by(|elem| elem.as_str().cmp("d")), Err(3));
+        assert!(list.contains(&String::from("c")));
+        assert_eq!(list.position(|elem| elem == "f"), Some(4));
+
+        assert_eq!(
+            list.cycle_from(-1)
+                .take(3)
+                .cloned()
+                .collect::<Vec<String>>(),
+            vec!["f", "a", "b"]
+        );
+    }
+
+    #[test]
+    fn into_storage() {
+        let mut list: StorageList<[Option<i64>; 2], i64, false> =
+            StorageList::from_storage([None; 2]).unwrap();
+
+        assert!(list.push_back(1).is_ok());
+        let list: StorageList<[Option<i64>; 2], i64, true> = list.into();
+
+        assert_eq!(list.into_storage(), [None, None]);
+    }
+}
+
+mod seq_list {
+    use crate::{error::Error, list::SeqList};
+
+    const SIZE: usize = 3;
+
+    #[test]
+    fn sequence_numbers() {
+        let mut list: SeqList<SIZE, i64, true> = SeqList::new();
+
+        assert_eq!(list.first_seq(), None);
+        assert_eq!(list.last_seq(), None);
+
+        for i in 0..10 {
+            assert_eq!(list.push_back(i * 10), Ok(i as u64));
+            assert_eq!(list.last_seq(), Some(i as u64));
+        }
+
+        assert_eq!(list.first_seq(), Some(7));
+        assert_eq!(list.next_seq(), 10);
+        assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![70, 80, 90]);
+    }
+
+    #[test]
+    fn get_by_seq() {
+        let mut list: SeqList<SIZE, i64, true> = SeqList::new();
+
+        for i in 0..5 {
+            assert!(list.push_back(i * 10).is_ok());
+        }
+
+        assert_eq!(list.get_by_seq(1), Err(Error::Overwritten));
+        assert_eq!(list.get_by_seq(2), Ok(&20));
+        assert_eq!(list.get_by_seq(4), Ok(&40));
+        assert_eq!(list.get_by_seq(5), Err(Error::IndexOutOfRange));
+
+        assert_eq!(list.remove_front(), Some((2, 20)));
+        assert_eq!(list.get_by_seq(2), Err(Error::Overwritten));
+        assert_eq!(list.first_seq(), Some(3));
+    }
+
+    #[test]
+    fn overflow() {
+        let mut list: SeqList<SIZE, i64, false> = SeqList::new();
+
+        for i in 0..3 {
+            assert_eq!(list.push_back(i), Ok(i as u64));
+        }
+
+        assert_eq!(list.push_back(3), Err(Error::Overflow));
+        assert_eq!(list.next_seq(), 3);
+    }
+
+    #[test]
+    fn iter_since() {
+        let mut list: SeqList<SIZE, i64, true> = SeqList::new();
+
+        assert_eq!(list.iter_since(0).unwrap().count(), 0);
+
+        for i in 0..5 {
+            assert!(list.push_back(i * 10).is_ok());
+        }
+
+        let mut tail = list.iter_since(3).unwrap();
+        assert_eq!(tail.len(), 2);
+        assert_eq!(tail.next(), Some((3, &30)));
+        assert_eq!(tail.next(), Some((4, &40)));
+        assert_eq!(tail.next(), None);
+
+        assert_eq!(list.iter_since(2).unwrap().count(), 3);
+        assert_eq!(list.iter_since(5).unwrap().count(), 0);
+        assert!(matches!(list.iter_since(1), Err(Error::Overwritten)));
+        assert!(matches!(list.iter_since(6), Err(Error::IndexOutOfRange)));
+    }
+
+    #[test]
+    fn resume() {
+        let mut list: SeqList<SIZE, i64, true> = SeqList::new();
+        let mut tailed = Vec::new();
+        let mut next = 0;
+
+        for i in 0..6 {
+            assert!(list.push_back(i).is_ok());
+
+            for (seq, elem) in list.iter_since(next).unwrap() {
+                tailed.push(*elem);
+                next = seq + 1;
+            }
+        }
+
+        assert_eq!(tailed, (0..6).collect::<Vec<i64>>());
+    }
+
+    #[test]
+    fn debug() {
+        let mut list: SeqList<SIZE, i64, true> = SeqList::new();
+
+        assert!(list.push_back(1).is_ok());
+
+        assert_eq!(
+            format!("SeqList {{ : {:?}, next_seq: 1 }}", *list),
+            format!("{list:?}")
+        );
+    }
+}
+
+mod insert_at {
+    use crate::{error::Error, list::List};
+
+    const SIZE: usize = 5;
+
+    #[test]
+    fn every_index() {
+        for index in 0..=4 {
+            let mut list: List<SIZE, i64, false> = List::default();
+
+            // wrap the list around the end of the underlying array
+            for i in 0..3 {
+                assert!(list.push_back(i).is_ok());
+            }
+            list.remove_front();
+            list.remove_front();
+            for i in 3..6 {
+                assert!(list.push_back(i).is_ok());
+            }
+
+            assert!(list.insert_at(-1, index).is_ok());
+
+            let mut expected = vec![2, 3, 4, 5];
+            expected.insert(index, -1);
+
+            assert_eq!(list.iter().copied().collect::<Vec<i64>>(), expected);
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let mut list: List<SIZE, i64, false> = List::default();
+
+        assert!(list.insert_at(1, 0).is_ok());
+        assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![1]);
+    }
+
+    #[test]
+    fn out_of_range() {
+        let mut list: List<SIZE, i64, false> = vec![1, 2].try_into().unwrap();
+
+        assert_eq!(list.insert_at(3, 3).err(), Some(Error::IndexOutOfRange));
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn overflow() {
+        let mut list: List<SIZE, i64, false> = [1, 2, 3, 4, 5].into();
+
+        assert_eq!(list.insert_at(0, 2).err(), Some(Error::Overflow));
+        assert_eq!(list, [1, 2, 3, 4, 5].into());
+    }
+
+    #[test]
+    fn write_over() {
+        let mut list: List<SIZE, i64, true> = [1, 2, 3, 4, 5].into();
+
+        assert!(list.insert_at(0, 0).is_ok());
+        assert_eq!(list, [0, 2, 3, 4, 5].into());
+
+        assert!(list.insert_at(6, 5).is_ok());
+        assert_eq!(list, [2, 3, 4, 5, 6].into());
+    }
+}
+
+mod remove_at {
+    use crate::{error::Error, list::List};
+
+    const SIZE: usize = 5;
+
+    #[test]
+    fn every_index() {
+        for index in 0..5 {
+            let mut list: List<SIZE, i64, false> = List::default();
+
+            for i in 0..3 {
+                assert!(list.push_back(i).is_ok());
+            }
+            list.remove_front();
+            list.remove_front();
+            for i in 3..7 {
+                assert!(list.push_back(i).is_ok());
+            }
+
+            let mut expected = vec![2, 3, 4, 5, 6];
+            let removed = expected.remove(index);
+
+            assert_eq!(list.remove_at(index), Ok(removed));
+            assert_eq!(list.iter().copied().collect::<Vec<i64>>(), expected);
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        let mut list: List<SIZE, i64, false> = List::default();
+
+        assert_eq!(list.remove_at(0), Err(Error::IndexOutOfRange));
+
+        assert!(list.push_back(1).is_ok());
+
+        assert_eq!(list.remove_at(1), Err(Error::IndexOutOfRange));
+        assert_eq!(list.remove_at(0), Ok(1));
+        assert_eq!(list.len(), 0);
+    }
+}
+
+mod cursor {
+    use crate::{error::Error, list::List};
+
+    const SIZE: usize = 5;
+
+    #[test]
+    fn wrap_around() {
+        let list: List<SIZE, i64, false> = vec![1, 2, 3].try_into().unwrap();
+
+        let mut cursor = list.cursor(0);
+        let mut forward = Vec::new();
+        for _ in 0..7 {
+            forward.push(*cursor.current().unwrap());
+            cursor.move_next();
+        }
+        assert_eq!(forward, vec![1, 2, 3, 1, 2, 3, 1]);
+
+        let mut cursor = list.cursor(0);
+        let mut backward = Vec::new();
+        for _ in 0..7 {
+            backward.push(*cursor.current().unwrap());
+            cursor.move_prev();
+        }
+        assert_eq!(backward, vec![1, 3, 2, 1, 3, 2, 1]);
+    }
+
+    #[test]
+    fn empty() {
+        let mut list: List<SIZE, i64, false> = List::default();
+
+        let mut cursor = list.cursor(3);
+        cursor.move_next();
+        cursor.move_prev();
+
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), None);
+
+        let mut cursor = list.cursor_mut(0);
+
+        assert_eq!(cursor.remove_current(), None);
+        assert!(cursor.insert_after(1).is_ok());
+        assert_eq!(cursor.current(), Some(&1));
+    }
+
+    #[test]
+    fn remove_while_walking() {
+        let mut list: List<SIZE, i64, false> = vec![1, 2, 3, 4, 5].try_into().unwrap();
+
+        let mut cursor = list.cursor_mut(0);
+        while let Some(elem) = cursor.current() {
+            if elem % 2 == 0 {
+                cursor.remove_current();
+            } else {
+                cursor.move_next();
+            }
+
+            if cursor.index() == Some(0) {
+                break;
+            }
+        }
+
+        assert_eq!(list, vec![1, 3, 5].try_into().unwrap());
+    }
+
+    #[test]
+    fn insert() {
+        let mut list: List<SIZE, i64, false> = vec![2, 4].try_into().unwrap();
+
+        let mut cursor = list.cursor_mut(1);
+
+        assert!(cursor.insert_before(3).is_ok());
+        assert!(cursor.insert_after(5).is_ok());
+        assert_eq!(cursor.current(), Some(&4));
+
+        cursor.move_next();
+        cursor.move_next();
+        assert!(cursor.insert_before(1).is_ok());
+        assert_eq!(cursor.current(), Some(&2));
+
+        assert_eq!(cursor.insert_after(6), Err(Error::Overflow));
+        assert_eq!(list, vec![1, 2, 3, 4, 5].try_into().unwrap());
+    }
+
+    #[test]
+    fn write_over_insert() {
+        let mut list: List<SIZE, i64, true> = [1, 2, 3, 4, 5].into();
+
+        assert_eq!(list.cursor_mut(0).insert_before(0), Err(Error::Overflow));
+        assert_eq!(list, [1, 2, 3, 4, 5].into());
+    }
+}
+
+mod round_robin {
+    use crate::{error::Error, list::RoundRobin};
+
+    const SIZE: usize = 4;
+
+    fn backends() -> RoundRobin<SIZE, char> {
+        let mut backends = RoundRobin::new();
+
+        for backend in ['a', 'b', 'c', 'd'] {
+            assert!(backends.add(backend).is_ok());
+        }
+
+        backends
+    }
+
+    #[test]
+    fn rotation() {
+        let mut backends = backends();
+
+        let picks: String = (0..8).filter_map(|_| backends.next().copied()).collect();
+
+        assert_eq!(picks, "abcdabcd");
+        assert_eq!(backends.add('e'), Err(Error::Overflow));
+    }
+
+    #[test]
+    fn skips_unhealthy() {
+        let mut backends = backends();
+
+        assert_eq!(backends.next(), Some(&'a'));
+
+        assert!(backends.set_healthy(1, false).is_ok());
+        assert!(backends.set_healthy(3, false).is_ok());
+
+        let picks: String = (0..4).filter_map(|_| backends.next().copied()).collect();
+        assert_eq!(picks, "caca");
+
+        for index in 0..SIZE {
+            assert!(backends.set_healthy(index, false).is_ok());
+        }
+        assert_eq!(backends.next(), None);
+
+        assert!(backends.set_healthy(3, true).is_ok());
+        assert_eq!(backends.next(), Some(&'d'));
+        assert_eq!(backends.next(), Some(&'d'));
+    }
+
+    #[test]
+    fn remove() {
+        let mut backends = backends();
+
+        assert_eq!(backends.next(), Some(&'a'));
+        assert_eq!(backends.next(), Some(&'b'));
+
+        // removing an entry before the position keeps the rotation
+        assert_eq!(backends.remove(0), Ok('a'));
+        assert_eq!(backends.next(), Some(&'c'));
+
+        // removing the last entry wraps the position
+        assert_eq!(backends.remove(2), Ok('d'));
+        assert_eq!(backends.next(), Some(&'b'));
+
+        assert_eq!(backends.remove(5), Err(Error::IndexOutOfRange));
+        assert_eq!(backends.len(), 2);
+    }
+
+    #[test]
+    fn weighted() {
+        let mut backends: RoundRobin<SIZE, char> = RoundRobin::new();
+
+        assert!(backends.add_weighted('a', 3).is_ok());
+        assert!(backends.add_weighted('b', 2).is_ok());
+        assert!(backends.add_weighted('c', 1).is_ok());
+        assert!(backends.add_weighted('d', 0).is_ok());
+
+        let picks: String = (0..60)
+            .filter_map(|_| backends.next_weighted().copied())
+            .collect();
+
+        assert_eq!(picks.matches('a').count(), 30);
+        assert_eq!(picks.matches('b').count(), 20);
+        assert_eq!(picks.matches('c').count(), 10);
+        assert_eq!(picks.matches('d').count(), 0);
+        // smooth selection never picks the heaviest entry three times in a row
+        assert!(!picks.contains("aaa"));
+    }
+
+    #[test]
+    fn weighted_skips_unhealthy() {
+        let mut backends: RoundRobin<SIZE, char> = RoundRobin::new();
+
+        assert!(backends.add_weighted('a', 5).is_ok());
+        assert!(backends.add_weighted('b', 1).is_ok());
+
+        assert!(backends.set_healthy(0, false).is_ok());
+
+        let picks: String = (0..3)
+            .filter_map(|_| backends.next_weighted().copied())
+            .collect();
+        assert_eq!(picks, "bbb");
+
+        assert!(backends.set_healthy(1, false).is_ok());
+        assert_eq!(backends.next_weighted(), None);
+    }
+}
+
+mod cycle_from {
+    use crate::list::List;
+
+    const SIZE: usize = 5;
+
+    #[test]
+    fn empty() {
+        let list: List<SIZE, i64, false> = List::default();
+
+        assert_eq!(list.cycle_from(0).next(), None);
+        assert_eq!(list.cycle_from(-3).size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn wrapped_list() {
+        let mut list: List<SIZE, i64, true> = List::default();
+
+        for i in 0..8 {
+            assert!(list.push_back(i).is_ok());
+        }
+
+        assert_eq!(
+            list.cycle_from(0).take(12).copied().collect::<Vec<i64>>(),
+            vec![3, 4, 5, 6, 7, 3, 4, 5, 6, 7, 3, 4]
+        );
+    }
+
+    #[test]
+    fn any_index() {
+        let list: List<SIZE, i64, false> = [1, 2, 3, 4, 5].into();
+
+        for index in -12..12isize {
+            let expected: Vec<i64> = (0..7).map(|i| *list.get(index + i).unwrap()).collect();
+
+            assert_eq!(
+                list.cycle_from(index)
+                    .take(7)
+                    .copied()
+                    .collect::<Vec<i64>>(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn non_clone() {
+        struct Token(i64);
+
+        let list: List<SIZE, Token, false> =
+            List::from([Token(1), Token(2), Token(3), Token(4), Token(5)]);
+
+        assert_eq!(list.cycle_from(4).nth(3).map(|token| token.0), Some(3));
+    }
+}
+
+mod iter_from {
+    use crate::list::List;
+
+    const SIZE: usize = 5;
+
+    #[test]
+    fn empty() {
+        let list: List<SIZE, i64, false> = List::default();
+
+        assert_eq!(list.iter_from(2).len(), 0);
+        assert_eq!(list.iter_from(2).next(), None);
+    }
+
+    #[test]
+    fn any_index() {
+        let list: List<SIZE, i64, false> = vec![1, 2, 3, 4].try_into().unwrap();
+
+        for index in -9..9isize {
+            let expected: Vec<i64> = (0..4).map(|i| *list.get(index + i).unwrap()).collect();
+
+            let iter = list.iter_from(index);
+            assert_eq!(iter.len(), 4);
+            assert_eq!(iter.copied().collect::<Vec<i64>>(), expected);
+        }
+    }
+
+    #[test]
+    fn wrapped_list() {
+        let mut list: List<SIZE, i64, true> = List::default();
+
+        for i in 0..7 {
+            assert!(list.push_back(i).is_ok());
+        }
+
+        assert_eq!(
+            list.iter_from(3).copied().collect::<Vec<i64>>(),
+            vec![5, 6, 2, 3, 4]
+        );
+    }
+}
+
+mod circular_pairs {
+    use crate::list::List;
+
+    const SIZE: usize = 5;
+
+    #[test]
+    fn empty() {
+        let list: List<SIZE, i64, false> = List::default();
+
+        assert_eq!(list.circular_pairs().len(), 0);
+        assert_eq!(list.circular_pairs().next(), None);
+    }
+
+    #[test]
+    fn single() {
+        let list: List<SIZE, i64, false> = vec![1].try_into().unwrap();
+
+        assert_eq!(
+            list.circular_pairs().collect::<Vec<(&i64, &i64)>>(),
+            vec![(&1, &1)]
+        );
+    }
+
+    #[test]
+    fn wrapped_list() {
+        let mut list: List<SIZE, i64, true> = List::default();
+
+        for i in 0..7 {
+            assert!(list.push_back(i).is_ok());
+        }
+
+        let pairs = list.circular_pairs();
+        assert_eq!(pairs.len(), SIZE);
+        assert_eq!(
+            pairs.map(|(a, b)| (*a, *b)).collect::<Vec<(i64, i64)>>(),
+            vec![(2, 3), (3, 4), (4, 5), (5, 6), (6, 2)]
+        );
+    }
+
+    #[test]
+    fn perimeter() {
+        let square: List<4, (f64, f64), false> =
+            [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].into();
+
+        let perimeter: f64 = square
+            .circular_pairs()
+            .map(|(a, b)| ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt())
+            .sum();
+
+        assert_eq!(perimeter, 4.0);
+    }
+}
+
+mod circular_windows {
+    use crate::list::List;
+
+    const SIZE: usize = 5;
+
+    fn windows<const K: usize>(list: &List<SIZE, i64, true>) -> Vec<[i64; K]> {
+        list.circular_windows::<K>()
+            .map(|window| window.map(|elem| *elem))
+            .collect()
+    }
+
+    #[test]
+    fn empty() {
+        let list: List<SIZE, i64, true> = List::default();
+
+        assert_eq!(windows::<2>(&list), Vec::<[i64; 2]>::new());
+    }
+
+    #[test]
+    fn zero_sized() {
+        let list: List<SIZE, i64, true> = [1, 2, 3, 4, 5].into();
+
+        assert_eq!(list.circular_windows::<0>().len(), 0);
+        assert_eq!(windows::<0>(&list), Vec::<[i64; 0]>::new());
+    }
+
+    #[test]
+    fn wrapped_list() {
+        let mut list: List<SIZE, i64, true> = List::default();
+
+        for i in 0..7 {
+            assert!(list.push_back(i).is_ok());
+        }
+
+        assert_eq!(windows::<1>(&list), vec![[2], [3], [4], [5], [6]]);
+        assert_eq!(
+            windows::<3>(&list),
+            vec![[2, 3, 4], [3, 4, 5], [4, 5, 6], [5, 6, 2], [6, 2, 3]]
+        );
+        assert_eq!(list.circular_windows::<3>().len(), SIZE);
+    }
+
+    #[test]
+    fn larger_than_list() {
+        let list: List<SIZE, i64, true> = vec![1, 2].try_into().unwrap();
+
+        assert_eq!(windows::<3>(&list), vec![[1, 2, 1], [2, 1, 2]]);
+    }
+
+    #[test]
+    fn matches_pairs() {
+        let list: List<SIZE, i64, true> = [1, 2, 3, 4, 5].into();
+
+        assert!(list
+            .circular_windows::<2>()
+            .zip(list.circular_pairs())
+            .all(|([a, b], (c, d))| std::ptr::eq(a, c) && std::ptr::eq(b, d)));
+    }
+}
+
+mod rotation {
+    use crate::list::List;
+
+    const SIZE: usize = 8;
+
+    fn rotations(values: &[i64]) -> Vec<Vec<i64>> {
+        (0..values.len())
+            .map(|k| values[k..].iter().chain(&values[..k]).copied().collect())
+            .collect()
+    }
+
+    /// Returns a deterministic series of short lists - with many repeated values
+    fn samples() -> Vec<Vec<i64>> {
+        let mut seed: u64 = 0x2545F4914F6CDD1D;
+        let mut next = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+
+        (0..300)
+            .map(|_| {
+                let len = (next() % (SIZE as u64 + 1)) as usize;
+                (0..len).map(|_| (next() % 3) as i64).collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn find_rotation() {
+        for values in samples() {
+            let list: List<SIZE, i64, false> = values.clone().try_into().unwrap();
+
+            for (k, rotated) in rotations(&values).into_iter().enumerate() {
+                let rotated: List<SIZE, i64, false> = rotated.try_into().unwrap();
+
+                let rotation = list.find_rotation(&rotated).unwrap();
+                assert!(rotation <= k);
+                for i in 0..values.len() {
+                    assert_eq!(rotated[i], list[(i + rotation) % values.len()]);
+                }
+
+                assert!(list.eq_up_to_rotation(&rotated));
+            }
+        }
+    }
+
+    #[test]
+    fn not_a_rotation() {
+        let list: List<SIZE, i64, false> = vec![1, 1, 2, 2].try_into().unwrap();
+
+        for other in [vec![1, 2, 1, 2], vec![1, 1, 2], vec![1, 1, 2, 2, 1], vec![]] {
+            let other: List<SIZE, i64, false> = other.try_into().unwrap();
+
+            assert_eq!(list.find_rotation(&other), None);
+            assert!(!list.eq_up_to_rotation(&other));
+        }
+    }
+
+    #[test]
+    fn different_list_types() {
+        let list: List<3, i64, true> = [1, 2, 3].into();
+        let other: List<SIZE, i64, false> = vec![3, 1, 2].try_into().unwrap();
+
+        assert_eq!(list.find_rotation(&other), Some(2));
+    }
+
+    #[test]
+    fn empty() {
+        let list: List<SIZE, i64, false> = List::default();
+        let other: List<SIZE, i64, false> = List::default();
+
+        assert_eq!(list.find_rotation(&other), Some(0));
+    }
+
+    #[test]
+    fn canonicalize() {
+        for values in samples() {
+            let expected = rotations(&values).into_iter().min().unwrap_or_default();
+
+            for rotated in rotations(&values) {
+                let mut list: List<SIZE, i64, false> = rotated.clone().try_into().unwrap();
+
+                let rotation = list.canonicalize();
+
+                assert_eq!(list.iter().copied().collect::<Vec<i64>>(), expected);
+                assert_eq!(rotations(&rotated)[rotation], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn canonicalize_wrapped_list() {
+        let mut list: List<4, i64, true> = List::default();
+
+        for i in [9, 9, 4, 2, 7, 1] {
+            assert!(list.push_back(i).is_ok());
+        }
+
+        assert_eq!(list.canonicalize(), 3);
+        assert_eq!(list, [1, 4, 2, 7].into());
+    }
+}
+
+mod find_subsequence {
+    use super::rotated;
+    use crate::list::List;
+
+    const SIZE: usize = 6;
+
+    /// Returns every cyclic occurrence of `pattern` in `values` - by brute force
+    fn brute_force(values: &[i64], pattern: &[i64]) -> Vec<usize> {
+        if values.len() < pattern.len() {
+            return Vec::new();
+        }
+
+        (0..values.len())
+            .filter(|start| {
+                pattern
+                    .iter()
+                    .enumerate()
+                    .all(|(i, elem)| values[(start + i) % values.len()] == *elem)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut seed: u64 = 0x9E3779B97F4A7C15;
+        let mut next = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+
+        for _ in 0..500 {
+            let len = (next() % (SIZE as u64 + 1)) as usize;
+            let values: Vec<i64> = (0..len).map(|_| (next() % 2) as i64).collect();
+            let pattern_len = (next() % (SIZE as u64 + 2)) as usize;
+            let pattern: Vec<i64> = (0..pattern_len).map(|_| (next() % 2) as i64).collect();
+
+            let list = rotated::<SIZE, _>(&values, (next() % SIZE as u64) as usize);
+
+            let expected = brute_force(&values, &pattern);
+
+            assert_eq!(list.find_all(&pattern), expected);
+            // like str::find, an empty pattern matches even an empty list
+            let first = match pattern.is_empty() {
+                true => Some(0),
+                false => expected.first().copied(),
+            };
+            assert_eq!(list.find_subsequence(&pattern), first);
+        }
+    }
+
+    #[test]
+    fn across_seam() {
+        let list: List<SIZE, char, false> = vec!['l', 'o', 'h', 'e', 'l'].try_into().unwrap();
+
+        assert_eq!(list.find_subsequence(&['h', 'e', 'l', 'l', 'o']), Some(2));
+        assert_eq!(list.find_all(&['l']), vec![0, 4]);
+        assert_eq!(list.find_all(&['l', 'l', 'o']), vec![4]);
+    }
+
+    #[test]
+    fn whole_list() {
+        let list: List<SIZE, i64, false> = vec![1, 2, 1].try_into().unwrap();
+
+        assert_eq!(list.find_all(&[1, 2, 1]), vec![0]);
+        assert_eq!(list.find_all(&[1, 1, 2]), vec![2]);
+        assert_eq!(list.find_all(&[1, 2, 1, 1]), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn empty() {
+        let list: List<SIZE, i64, false> = List::default();
+
+        assert_eq!(list.find_subsequence(&[1]), None);
+        assert_eq!(list.find_subsequence(&[]), Some(0));
+        assert_eq!(list.find_all(&[]), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn empty_pattern() {
+        let list: List<SIZE, i64, false> = vec![1, 2, 3].try_into().unwrap();
+
+        assert_eq!(list.find_subsequence(&[]), Some(0));
+        assert_eq!(list.find_all(&[]), vec![0, 1, 2]);
+    }
+}
+
+mod find_byte {
+    use super::rotated;
+    use crate::list::List;
+
+    const SIZE: usize = 5;
+
+    #[test]
+    fn every_rotation() {
+        for offset in 0..SIZE {
+            let list = rotated::<SIZE, _>(b"hello", offset);
+
+            assert_eq!(list.find_byte(b'h'), Some(0));
+            assert_eq!(list.find_byte(b'l'), Some(2));
+            assert_eq!(list.find_byte(b'o'), Some(4));
+            assert_eq!(list.find_byte(0), None);
+        }
+    }
+
+    #[test]
+    fn partial() {
+        let mut list: List<SIZE, u8, false> = List::default();
+
+        assert_eq!(list.find_byte(0), None);
+
+        for byte in b"abc" {
+            assert!(list.push_back(*byte).is_ok());
+        }
+        list.remove_front();
+
+        assert_eq!(list.find_byte(b'a'), None);
+        assert_eq!(list.find_byte(b'c'), Some(1));
+    }
+}
+
+mod sort {
+    use super::rotated;
+    use crate::error::Error;
+    use crate::list::List;
+
+    const SIZE: usize = 6;
+
+    #[test]
+    fn sort_every_rotation() {
+        let values = [4, 1, 5, 1, 3, 2];
+
+        for offset in 0..SIZE {
+            let mut list = rotated::<SIZE, _>(&values, offset);
+            let mut expected = values.to_vec();
+            expected.sort();
+
+            list.sort();
+
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+
+            // the list is still usable after being made contiguous
+            assert_eq!(list.remove_front(), Some(1));
+            assert!(list.push_back(9).is_ok());
+            assert_eq!(
+                list.iter().copied().collect::<Vec<_>>(),
+                vec![1, 2, 3, 4, 5, 9]
+            );
+        }
+    }
+
+    #[test]
+    fn sort_by_is_stable() {
+        let mut list: List<SIZE, (i64, char), false> = vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')]
+            .try_into()
+            .unwrap();
+
+        list.sort_by(|a, b| a.0.cmp(&b.0));
+
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]
+        );
+    }
+
+    #[test]
+    fn sort_unstable_by_key() {
+        let mut list = rotated::<SIZE, i64>(&[-3, 6, -1, 2], 4);
+
+        list.sort_unstable_by_key(|elem| elem.abs());
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![-1, 2, -3, 6]);
+    }
+
+    #[test]
+    fn sort_empty() {
+        let mut list: List<SIZE, i64, false> = List::default();
+
+        list.sort();
+
+        assert_eq!(list.len(), 0);
+        assert!(list.push_back(1).is_ok());
+    }
+
+    #[test]
+    fn binary_search_by() {
+        for offset in 0..SIZE {
+            let list = rotated::<SIZE, _>(&[1, 3, 5, 7, 9], offset);
+
+            for (index, value) in [1, 3, 5, 7, 9].iter().enumerate() {
+                assert_eq!(list.binary_search_by(|elem| elem.cmp(value)), Ok(index));
+                assert_eq!(
+                    list.binary_search_by(|elem| elem.cmp(&(value - 1))),
+                    Err(index)
+                );
+            }
+            assert_eq!(list.binary_search_by(|elem| elem.cmp(&10)), Err(5));
+        }
+
+        let empty: List<SIZE, i64, false> = List::default();
+        assert_eq!(empty.binary_search_by(|elem| elem.cmp(&1)), Err(0));
+    }
+
+    #[test]
+    fn partition_point() {
+        for offset in 0..SIZE {
+            let list = rotated::<SIZE, _>(&[1, 2, 2, 2, 3, 4], offset);
+
+            assert_eq!(list.partition_point(|elem| *elem < 1), 0);
+            assert_eq!(list.partition_point(|elem| *elem < 2), 1);
+            assert_eq!(list.partition_point(|elem| *elem <= 2), 4);
+            assert_eq!(list.partition_point(|elem| *elem < 5), 6);
+        }
+    }
+
+    #[test]
+    fn insert_sorted() {
+        let mut list: List<SIZE, i64, false> = List::default();
+
+        for (value, index) in [(5, 0), (1, 0), (3, 1), (3, 2), (9, 4), (0, 0)] {
+            assert_eq!(list.insert_sorted(value), Ok(index));
+        }
+
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![0, 1, 3, 3, 5, 9]
+        );
+        assert_eq!(list.insert_sorted(4), Err(Error::Overflow));
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![0, 1, 3, 3, 5, 9]
+        );
+    }
+
+    #[test]
+    fn insert_sorted_write_over() {
+        let mut list = rotated::<SIZE, _>(&[1, 3, 5, 7, 9, 11], 3);
+
+        assert_eq!(list.insert_sorted(6), Ok(2));
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![3, 5, 6, 7, 9, 11]
+        );
+
+        assert_eq!(list.insert_sorted(12), Ok(5));
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![5, 6, 7, 9, 11, 12]
+        );
+
+        // an element equal to the smallest element is kept - in place of the smallest element
+        assert_eq!(list.insert_sorted(5), Ok(0));
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![5, 6, 7, 9, 11, 12]
+        );
+    }
+
+    #[test]
+    fn insert_sorted_write_over_smallest() {
+        for offset in 0..SIZE {
+            let mut list = rotated::<SIZE, _>(&[1, 2, 3, 4, 5, 6], offset);
+
+            // the new element would be the first to be dropped - so the list is left unchanged
+            assert_eq!(list.insert_sorted(0), Err(Error::Overflow));
+            assert_eq!(
+                list.iter().copied().collect::<Vec<_>>(),
+                vec![1, 2, 3, 4, 5, 6]
+            );
+
+            assert_eq!(list.insert_sorted(1), Ok(0));
+            assert_eq!(
+                list.iter().copied().collect::<Vec<_>>(),
+                vec![1, 2, 3, 4, 5, 6]
+            );
+        }
+
+        let mut empty: List<0, i64, true> = List::default();
+        assert_eq!(empty.insert_sorted(0), Err(Error::Overflow));
+    }
+}
+
+mod search {
+    use super::rotated;
+    use crate::list::List;
+
+    const SIZE: usize = 6;
+
+    #[test]
+    fn position_every_rotation() {
+        let values = [3, 1, 4, 1, 5, 9];
+
+        for offset in 0..SIZE {
+            let list = rotated::<SIZE, _>(&values, offset);
+
+            for value in 0..10 {
+                assert_eq!(
+                    list.position(|elem| *elem == value),
+                    values.iter().position(|elem| *elem == value)
+                );
+                assert_eq!(
+                    list.rposition(|elem| *elem == value),
+                    values.iter().rposition(|elem| *elem == value)
+                );
+                assert_eq!(list.contains(&value), values.contains(&value));
+            }
+
+            assert_eq!(list.count_matching(|elem| *elem == 1), 2);
+            assert_eq!(list.count_matching(|elem| *elem > 3), 3);
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let list: List<SIZE, i64, false> = List::default();
+
+        assert!(!list.contains(&0));
+        assert_eq!(list.position(|_| true), None);
+        assert_eq!(list.rposition(|_| true), None);
+        assert_eq!(list.count_matching(|_| true), 0);
+    }
+
+    #[test]
+    fn dedup_every_rotation() {
+        for offset in 0..SIZE {
+            let mut list = rotated::<SIZE, _>(&[1, 1, 2, 2, 2, 1], offset);
+
+            list.dedup();
+
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 1]);
+
+            // the freed slots can be reused
+            for value in [3, 4, 5] {
+                assert!(list.push_back(value).is_ok());
+            }
+            assert_eq!(
+                list.iter().copied().collect::<Vec<_>>(),
+                vec![1, 2, 1, 3, 4, 5]
+            );
+        }
+    }
+
+    #[test]
+    fn dedup_all_equal() {
+        let mut list = rotated::<SIZE, _>(&[7, 7, 7, 7], 4);
+
+        list.dedup();
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![7]);
+    }
+
+    #[test]
+    fn dedup_by_key() {
+        let mut list = rotated::<SIZE, _>(&[10, 15, 21, 32, 38, 11], 3);
+
+        list.dedup_by_key(|elem| elem / 10);
+
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![10, 21, 32, 11]
+        );
+    }
+}
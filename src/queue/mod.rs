@@ -8,7 +8,14 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use crate::{error::Error, list::List};
+use crate::{
+    error::Error,
+    list::{HeapList, List},
+    storage::Storage,
+};
+
+pub mod mpmc;
+pub mod spsc;
 
 #[cfg(test)]
 mod tests;
@@ -19,7 +26,7 @@ mod tests;
 /// `Queue` is the `struct` used to define the state of a queue using cyclic [`List`]. As a result, the queue inherits the O(1) insertion and deletion for enqueuing & dequeuing.
 ///
 /// # Generics
-/// List types are derived using 3 generics.
+/// Queue types are derived using 4 generics.
 ///
 /// 1. `const SIZE: usize`
 ///
@@ -31,6 +38,10 @@ mod tests;
 ///
 /// 3. `const WRITE_OVER: bool>`
 ///
+/// 4. `S: Storage<T> = [Option<T>; SIZE]`
+///
+/// S is the [`Storage`] the elements of the queue are stored in - an inline array by default (see [`HeapQueue`]).
+///
 /// # Creating Queue
 ///
 /// Queue can be created in a couple of ways.
@@ -102,10 +113,16 @@ mod tests;
 ///
 /// WRITE_OVER is a generic constant [^note] that is used to determine if elements should be over written on overflow
 /// [note]: [Generic Constraints](https://rust-lang.github.io/rfcs/2000-const-generics.html)
-#[derive(Default, PartialEq)]
-pub struct Queue<const SIZE: usize, T, const WRITE_OVER: bool>(List<SIZE, T, WRITE_OVER>);
+pub struct Queue<const SIZE: usize, T, const WRITE_OVER: bool, S = [Option<T>; SIZE]>(
+    List<SIZE, T, WRITE_OVER, S>,
+)
+where
+    S: Storage<T>;
 
-impl<const SIZE: usize, T, const WRITE_OVER: bool> Queue<SIZE, T, WRITE_OVER> {
+impl<const SIZE: usize, T, const WRITE_OVER: bool, S> Queue<SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
     /// Returns the number of elements in the queue.
     ///
     /// ```
@@ -154,11 +171,8 @@ impl<const SIZE: usize, T, const WRITE_OVER: bool> Queue<SIZE, T, WRITE_OVER> {
     /// # assert_eq!(queue.len(), 3);
     /// assert_eq!(queue.peek(), Some(&1));
     /// ```
-    pub fn peek(&mut self) -> Option<&T> {
-        if self.0.len() == 0 {
-            return None;
-        }
-        Some(&self.0[0usize])
+    pub fn peek(&self) -> Option<&T> {
+        self.0.get(0)
     }
 
     /// Returns the first element of the queue - after removing said element from the queue.
@@ -183,8 +197,9 @@ impl<const SIZE: usize, T, const WRITE_OVER: bool> Queue<SIZE, T, WRITE_OVER> {
     }
 }
 
-impl<const S: usize, T, const W: bool> Display for Queue<S, T, W>
+impl<const SIZE: usize, T, const W: bool, S> Display for Queue<SIZE, T, W, S>
 where
+    S: Storage<T>,
     T: Display,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -192,8 +207,9 @@ where
     }
 }
 
-impl<const S: usize, T, const W: bool> Debug for Queue<S, T, W>
+impl<const SIZE: usize, T, const W: bool, S> Debug for Queue<SIZE, T, W, S>
 where
+    S: Storage<T>,
     T: Debug,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -201,6 +217,25 @@ where
     }
 }
 
+impl<const SIZE: usize, T, const W: bool, S> PartialEq for Queue<SIZE, T, W, S>
+where
+    S: Storage<T>,
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Default for Queue<SIZE, T, W>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self(List::default())
+    }
+}
+
 impl<const QUEUE_SIZE: usize, T, const WRITE_OVER: bool> TryFrom<Vec<T>>
     for Queue<QUEUE_SIZE, T, WRITE_OVER>
 where
@@ -271,40 +306,97 @@ impl<const QUEUE_SIZE: usize, T, const WRITE_OVER: bool> From<[T; QUEUE_SIZE]>
     }
 }
 
-impl<const QUEUE_SIZE: usize, T, const WRITE_OVER: bool> From<List<QUEUE_SIZE, T, WRITE_OVER>>
-    for Queue<QUEUE_SIZE, T, WRITE_OVER>
+impl<const QUEUE_SIZE: usize, T, const WRITE_OVER: bool, S> From<List<QUEUE_SIZE, T, WRITE_OVER, S>>
+    for Queue<QUEUE_SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
 {
-    fn from(value: List<QUEUE_SIZE, T, WRITE_OVER>) -> Self {
+    fn from(value: List<QUEUE_SIZE, T, WRITE_OVER, S>) -> Self {
         Self(value)
     }
 }
 
-impl<const QUEUE_SIZE: usize, T> From<Queue<QUEUE_SIZE, T, true>> for Queue<QUEUE_SIZE, T, false> {
-    fn from(value: Queue<QUEUE_SIZE, T, true>) -> Self {
+impl<const QUEUE_SIZE: usize, T, S> From<Queue<QUEUE_SIZE, T, true, S>>
+    for Queue<QUEUE_SIZE, T, false, S>
+where
+    S: Storage<T>,
+{
+    fn from(value: Queue<QUEUE_SIZE, T, true, S>) -> Self {
         Self(value.0.into())
     }
 }
 
-impl<const QUEUE_SIZE: usize, T> From<Queue<QUEUE_SIZE, T, false>> for Queue<QUEUE_SIZE, T, true> {
-    fn from(value: Queue<QUEUE_SIZE, T, false>) -> Self {
+impl<const QUEUE_SIZE: usize, T, S> From<Queue<QUEUE_SIZE, T, false, S>>
+    for Queue<QUEUE_SIZE, T, true, S>
+where
+    S: Storage<T>,
+{
+    fn from(value: Queue<QUEUE_SIZE, T, false, S>) -> Self {
         Self(value.0.into())
     }
 }
 
-impl<const QUEUE_SIZE: usize, T, const WRITE_OVER: bool> Deref
-    for Queue<QUEUE_SIZE, T, WRITE_OVER>
+impl<const QUEUE_SIZE: usize, T, const WRITE_OVER: bool, S> Deref
+    for Queue<QUEUE_SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
 {
-    type Target = List<QUEUE_SIZE, T, WRITE_OVER>;
+    type Target = List<QUEUE_SIZE, T, WRITE_OVER, S>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
 
-impl<const QUEUE_SIZE: usize, T, const WRITE_OVER: bool> DerefMut
-    for Queue<QUEUE_SIZE, T, WRITE_OVER>
+impl<const QUEUE_SIZE: usize, T, const WRITE_OVER: bool, S> DerefMut
+    for Queue<QUEUE_SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
 {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
 }
+
+/// `HeapQueue` is a [`Queue`] built on a [`HeapList`]. As a result, the capacity of the queue is chosen at construction time rather than at compile time.
+///
+/// Apart from where the elements are stored, `HeapQueue` is the same type as [`Queue`] - so has the same API and semantics.
+///
+/// ```
+/// # use cyclic_data_types::queue::HeapQueue;
+/// let mut queue: HeapQueue<i64, false> = HeapQueue::new(3).unwrap();
+///
+/// assert!(queue.enqueue(1).is_ok());
+/// assert!(queue.enqueue(2).is_ok());
+///
+/// assert_eq!(queue.dequeue(), Some(1));
+/// assert_eq!(queue.dequeue(), Some(2));
+/// assert_eq!(queue.dequeue(), None);
+/// ```
+pub type HeapQueue<T, const WRITE_OVER: bool> = Queue<0, T, WRITE_OVER, Box<[Option<T>]>>;
+
+impl<T, const WRITE_OVER: bool> HeapQueue<T, WRITE_OVER> {
+    /// Creates an empty queue that can hold at most `capacity` elements.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::HeapQueue;
+    /// let queue: HeapQueue<i64, false> = HeapQueue::new(3).unwrap();
+    ///
+    /// assert_eq!(queue.capacity(), 3);
+    /// ```
+    ///
+    /// # Returns
+    /// * Self if `capacity` > 0
+    /// * [`Error::InvalidSize`] if `capacity` = 0
+    pub fn new(capacity: usize) -> Result<Self, Error> {
+        Ok(Self(HeapList::new(capacity)?))
+    }
+}
+
+impl<T, const WRITE_OVER: bool> TryFrom<Vec<T>> for HeapQueue<T, WRITE_OVER> {
+    type Error = Error;
+
+    fn try_from(value: Vec<T>) -> Result<Self, Self::Error> {
+        Ok(Queue(value.try_into()?))
+    }
+}
@@ -0,0 +1,463 @@
+//! The mpmc module contains a lock-free multi-producer multi-consumer bounded queue built on the same fixed-size cyclic layout as [`Queue`](super::Queue).
+//!
+//! Each slot of the queue holds a sequence number alongside its element (Dmitry Vyukov's bounded MPMC queue). The sequence number tells producers if a slot is free to be written, and consumers if a slot is ready to be read - so enqueuing and dequeuing only need a single compare-and-swap.
+//!
+//! ```
+//! # use cyclic_data_types::queue::mpmc;
+//! # use std::thread;
+//! # const SIZE: usize = 8;
+//! let (sender, receiver) = mpmc::bounded::<SIZE, u64, false>();
+//!
+//! thread::scope(|scope| {
+//!     for producer in 0..2 {
+//!         let sender = sender.clone();
+//!         scope.spawn(move || {
+//!             for i in 0..10 {
+//!                 while sender.try_send(producer * 10 + i).is_err() {
+//!                     thread::yield_now();
+//!                 }
+//!             }
+//!         });
+//!     }
+//!
+//!     let mut sum = 0;
+//!     let mut received = 0;
+//!     while received < 20 {
+//!         match receiver.try_recv() {
+//!             Some(elem) => {
+//!                 sum += elem;
+//!                 received += 1;
+//!             }
+//!             None => thread::yield_now(),
+//!         }
+//!     }
+//!
+//!     assert_eq!(sum, (0..20).sum());
+//! });
+//! ```
+
+use std::{
+    array,
+    cell::UnsafeCell,
+    fmt::Debug,
+    mem::MaybeUninit,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use crate::error::Error;
+
+/// Creates a new [`MpmcQueue`] - returning a cloneable sender and receiver to the queue.
+///
+/// ```
+/// # use cyclic_data_types::queue::mpmc;
+/// # use cyclic_data_types::error::Error;
+/// let (sender, receiver) = mpmc::bounded::<2, i64, false>();
+///
+/// assert_eq!(sender.try_send(1), Ok(()));
+/// assert_eq!(sender.clone().try_send(2), Ok(()));
+/// assert_eq!(sender.try_send(3), Err(Error::Overflow));
+///
+/// assert_eq!(receiver.try_recv(), Some(1));
+/// assert_eq!(receiver.clone().try_recv(), Some(2));
+/// assert_eq!(receiver.try_recv(), None);
+/// ```
+pub fn bounded<const SIZE: usize, T, const WRITE_OVER: bool>(
+) -> (Sender<SIZE, T, WRITE_OVER>, Receiver<SIZE, T, WRITE_OVER>) {
+    let queue = Arc::new(MpmcQueue::new());
+
+    (
+        Sender {
+            queue: queue.clone(),
+        },
+        Receiver { queue },
+    )
+}
+
+/// Returns the sequence number of a slot that can be written to at position `pos`
+fn vacant(pos: usize) -> usize {
+    pos.wrapping_mul(2)
+}
+
+/// Returns the sequence number of a slot that has been written to at position `pos`
+fn written(pos: usize) -> usize {
+    pos.wrapping_mul(2).wrapping_add(1)
+}
+
+struct Slot<T> {
+    sequence: AtomicUsize,
+    elem: UnsafeCell<MaybeUninit<T>>,
+}
+
+/// `MpmcQueue` is a fixed-size lock-free queue for any number of producers and consumers.
+///
+/// # Generics
+///
+/// 1. `const SIZE: usize`
+///
+/// SIZE is a generic constant that defines the maximum size of the queue. SIZE must be a power of two - as the positions of the queue wrap around `usize`, and only a power of two divides the range of `usize` evenly (so a wrapped position still maps onto the next slot).
+///
+/// 2. `T: Sized`
+///
+/// T is the type of element stored in the queue
+///
+/// 3. `const WRITE_OVER: bool`
+///
+/// WRITE_OVER is a generic constant that is used to determine if the oldest element should be dropped when enqueuing to a full queue. As other consumers may dequeue at the same time, the dropped element is the oldest element at the moment the queue was found to be full.
+///
+/// ```
+/// # use cyclic_data_types::queue::mpmc::MpmcQueue;
+/// let queue: MpmcQueue<2, i64, true> = MpmcQueue::new();
+///
+/// assert!(queue.try_enqueue(1).is_ok());
+/// assert!(queue.try_enqueue(2).is_ok());
+/// assert!(queue.try_enqueue(3).is_ok());
+///
+/// assert_eq!(queue.try_dequeue(), Some(2));
+/// assert_eq!(queue.try_dequeue(), Some(3));
+/// ```
+pub struct MpmcQueue<const SIZE: usize, T, const WRITE_OVER: bool> {
+    buffer: [Slot<T>; SIZE],
+    enqueue_pos: AtomicUsize,
+    dequeue_pos: AtomicUsize,
+}
+
+unsafe impl<const SIZE: usize, T: Send, const W: bool> Send for MpmcQueue<SIZE, T, W> {}
+unsafe impl<const SIZE: usize, T: Send, const W: bool> Sync for MpmcQueue<SIZE, T, W> {}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool> MpmcQueue<SIZE, T, WRITE_OVER> {
+    /// Creates an empty queue.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::mpmc::MpmcQueue;
+    /// let queue: MpmcQueue<4, i64, false> = MpmcQueue::new();
+    ///
+    /// assert!(queue.is_empty());
+    /// ```
+    ///
+    /// A queue must be able to hold at least one element, and its SIZE must be a power of two - both of which are checked at compile time.
+    ///
+    /// ```compile_fail
+    /// # use cyclic_data_types::queue::mpmc::MpmcQueue;
+    /// let queue: MpmcQueue<0, i64, false> = MpmcQueue::new();
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use cyclic_data_types::queue::mpmc::MpmcQueue;
+    /// let queue: MpmcQueue<3, i64, false> = MpmcQueue::new();
+    /// ```
+    pub fn new() -> Self {
+        const { assert!(SIZE > 0, "an MpmcQueue requires a SIZE of at least 1") };
+        const {
+            assert!(
+                SIZE.is_power_of_two(),
+                "an MpmcQueue requires a SIZE which is a power of two"
+            )
+        };
+
+        Self {
+            buffer: array::from_fn(|index| Slot {
+                sequence: AtomicUsize::new(index * 2),
+                elem: UnsafeCell::new(MaybeUninit::uninit()),
+            }),
+            enqueue_pos: AtomicUsize::new(0),
+            dequeue_pos: AtomicUsize::new(0),
+        }
+    }
+
+    /// Pushes an element to the end of the queue - without blocking.
+    ///
+    /// If the queue is full - the queue has two options based on the `WRITE_OVER` flag.
+    /// 1. `WRITE_OVER = true`: the oldest element is dropped to make room for the new element.
+    /// 2. `WRITE_OVER = false`: the new element isn't added to the queue.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::mpmc::MpmcQueue;
+    /// # use cyclic_data_types::error::Error;
+    /// let queue: MpmcQueue<1, i64, false> = MpmcQueue::new();
+    ///
+    /// assert_eq!(queue.try_enqueue(1), Ok(()));
+    /// assert_eq!(queue.try_enqueue(2), Err(Error::Overflow));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(())` if the element was enqueued
+    /// * [`Error::Overflow`] if the queue is full and the `WRITE_OVER` flag is set to `false`
+    pub fn try_enqueue(&self, elem: T) -> Result<(), Error> {
+        let mut elem = elem;
+
+        loop {
+            match self.enqueue(elem) {
+                Ok(()) => return Ok(()),
+                Err(rejected) if WRITE_OVER => {
+                    elem = rejected;
+                    drop(self.try_dequeue());
+                }
+                Err(_) => return Err(Error::Overflow),
+            }
+        }
+    }
+
+    /// Returns the first element of the queue - after removing said element from the queue. This does not block.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::mpmc::MpmcQueue;
+    /// let queue: MpmcQueue<4, i64, false> = MpmcQueue::new();
+    ///
+    /// assert!(queue.try_enqueue(1).is_ok());
+    ///
+    /// assert_eq!(queue.try_dequeue(), Some(1));
+    /// assert_eq!(queue.try_dequeue(), None);
+    /// ```
+    pub fn try_dequeue(&self) -> Option<T> {
+        let mut pos = self.dequeue_pos.load(Ordering::Relaxed);
+
+        loop {
+            let slot = &self.buffer[pos % SIZE];
+            let sequence = slot.sequence.load(Ordering::Acquire);
+
+            match (sequence as isize).wrapping_sub(written(pos) as isize) {
+                // the slot has been written to and is ready to be read
+                0 => match self.dequeue_pos.compare_exchange_weak(
+                    pos,
+                    pos.wrapping_add(1),
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        let elem = unsafe { (*slot.elem.get()).assume_init_read() };
+                        slot.sequence
+                            .store(vacant(pos.wrapping_add(SIZE)), Ordering::Release);
+
+                        return Some(elem);
+                    }
+                    Err(current) => pos = current,
+                },
+                // the slot has not been written to since the last lap - so the queue is empty
+                diff if diff < 0 => return None,
+                // another consumer has taken the slot
+                _ => pos = self.dequeue_pos.load(Ordering::Relaxed),
+            }
+        }
+    }
+
+    /// Returns the number of elements in the queue. The value may be out of date as soon as it is returned, if the queue is being used by other threads.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::mpmc::MpmcQueue;
+    /// let queue: MpmcQueue<4, i64, false> = MpmcQueue::new();
+    ///
+    /// assert!(queue.try_enqueue(1).is_ok());
+    ///
+    /// assert_eq!(queue.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        let dequeue_pos = self.dequeue_pos.load(Ordering::Acquire);
+        let enqueue_pos = self.enqueue_pos.load(Ordering::Acquire);
+
+        enqueue_pos.wrapping_sub(dequeue_pos).min(SIZE)
+    }
+
+    /// Returns `true` if the queue contains no elements.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::mpmc::MpmcQueue;
+    /// let queue: MpmcQueue<4, i64, false> = MpmcQueue::new();
+    ///
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum number of elements the queue can hold.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::mpmc::MpmcQueue;
+    /// let queue: MpmcQueue<4, i64, false> = MpmcQueue::new();
+    ///
+    /// assert_eq!(queue.capacity(), 4);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        SIZE
+    }
+
+    /// Attempts to enqueue an element once - returning the element if the queue is full
+    fn enqueue(&self, elem: T) -> Result<(), T> {
+        let mut pos = self.enqueue_pos.load(Ordering::Relaxed);
+
+        loop {
+            let slot = &self.buffer[pos % SIZE];
+            let sequence = slot.sequence.load(Ordering::Acquire);
+
+            match (sequence as isize).wrapping_sub(vacant(pos) as isize) {
+                // the slot is free to be written to
+                0 => match self.enqueue_pos.compare_exchange_weak(
+                    pos,
+                    pos.wrapping_add(1),
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        unsafe { (*slot.elem.get()).write(elem) };
+                        slot.sequence.store(written(pos), Ordering::Release);
+
+                        return Ok(());
+                    }
+                    Err(current) => pos = current,
+                },
+                // the slot has not been read since the last lap - so the queue is full
+                diff if diff < 0 => return Err(elem),
+                // another producer has taken the slot
+                _ => pos = self.enqueue_pos.load(Ordering::Relaxed),
+            }
+        }
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Default for MpmcQueue<SIZE, T, W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Debug for MpmcQueue<SIZE, T, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MpmcQueue")
+            .field("enqueue_pos", &self.enqueue_pos)
+            .field("dequeue_pos", &self.dequeue_pos)
+            .field("size", &self.len())
+            .finish()
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Drop for MpmcQueue<SIZE, T, W> {
+    fn drop(&mut self) {
+        while self.try_dequeue().is_some() {}
+    }
+}
+
+/// `Sender` is a cloneable handle used to enqueue elements into a shared [`MpmcQueue`].
+pub struct Sender<const SIZE: usize, T, const WRITE_OVER: bool> {
+    queue: Arc<MpmcQueue<SIZE, T, WRITE_OVER>>,
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool> Sender<SIZE, T, WRITE_OVER> {
+    /// Pushes an element to the end of the queue - without blocking. Works the same as [`MpmcQueue::try_enqueue`].
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::mpmc;
+    /// # use cyclic_data_types::error::Error;
+    /// let (sender, _receiver) = mpmc::bounded::<1, i64, false>();
+    ///
+    /// assert_eq!(sender.try_send(1), Ok(()));
+    /// assert_eq!(sender.try_send(2), Err(Error::Overflow));
+    /// ```
+    pub fn try_send(&self, elem: T) -> Result<(), Error> {
+        self.queue.try_enqueue(elem)
+    }
+
+    /// Returns the number of elements in the queue.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::mpmc;
+    /// let (sender, _receiver) = mpmc::bounded::<4, i64, false>();
+    ///
+    /// assert!(sender.try_send(1).is_ok());
+    ///
+    /// assert_eq!(sender.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns `true` if the queue contains no elements.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::mpmc;
+    /// let (sender, _receiver) = mpmc::bounded::<4, i64, false>();
+    ///
+    /// assert!(sender.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Clone for Sender<SIZE, T, W> {
+    fn clone(&self) -> Self {
+        Self {
+            queue: self.queue.clone(),
+        }
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Debug for Sender<SIZE, T, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Sender").field("", &self.queue).finish()
+    }
+}
+
+/// `Receiver` is a cloneable handle used to dequeue elements from a shared [`MpmcQueue`].
+pub struct Receiver<const SIZE: usize, T, const WRITE_OVER: bool> {
+    queue: Arc<MpmcQueue<SIZE, T, WRITE_OVER>>,
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool> Receiver<SIZE, T, WRITE_OVER> {
+    /// Returns the first element of the queue - after removing said element from the queue. Works the same as [`MpmcQueue::try_dequeue`].
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::mpmc;
+    /// let (sender, receiver) = mpmc::bounded::<4, i64, false>();
+    ///
+    /// assert!(sender.try_send(1).is_ok());
+    ///
+    /// assert_eq!(receiver.try_recv(), Some(1));
+    /// assert_eq!(receiver.try_recv(), None);
+    /// ```
+    pub fn try_recv(&self) -> Option<T> {
+        self.queue.try_dequeue()
+    }
+
+    /// Returns the number of elements in the queue.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::mpmc;
+    /// let (sender, receiver) = mpmc::bounded::<4, i64, false>();
+    ///
+    /// assert!(sender.try_send(1).is_ok());
+    ///
+    /// assert_eq!(receiver.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns `true` if the queue contains no elements.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::mpmc;
+    /// let (_sender, receiver) = mpmc::bounded::<4, i64, false>();
+    ///
+    /// assert!(receiver.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Clone for Receiver<SIZE, T, W> {
+    fn clone(&self) -> Self {
+        Self {
+            queue: self.queue.clone(),
+        }
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Debug for Receiver<SIZE, T, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Receiver").field("", &self.queue).finish()
+    }
+}
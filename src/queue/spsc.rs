@@ -0,0 +1,356 @@
+//! The spsc module contains a lock-free single-producer single-consumer queue built on the same fixed-size cyclic layout as [`Queue`](super::Queue).
+//!
+//! Unlike [`Queue`](super::Queue), enqueuing and dequeuing do not require `&mut` access to the whole queue. Instead, the queue is split into a [`Producer`] and a [`Consumer`] - which can be moved to different threads (or to an interrupt handler and a main loop) without a mutex.
+//!
+//! ```
+//! # use cyclic_data_types::queue::spsc::SpscQueue;
+//! # use std::thread;
+//! # const SIZE: usize = 4;
+//! let mut queue: SpscQueue<SIZE, i64> = SpscQueue::new();
+//! let (mut producer, mut consumer) = queue.split();
+//!
+//! thread::scope(|scope| {
+//!     scope.spawn(move || {
+//!         for i in 0..100 {
+//!             while producer.try_enqueue(i).is_err() {
+//!                 thread::yield_now();
+//!             }
+//!         }
+//!     });
+//!
+//!     let mut expected = 0;
+//!     while expected < 100 {
+//!         match consumer.try_dequeue() {
+//!             Some(elem) => {
+//!                 assert_eq!(elem, expected);
+//!                 expected += 1;
+//!             }
+//!             None => thread::yield_now(),
+//!         }
+//!     }
+//! });
+//! ```
+
+use std::{
+    array,
+    cell::UnsafeCell,
+    fmt::Debug,
+    mem::MaybeUninit,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::error::Error;
+
+/// `SpscQueue` is a fixed-size lock-free queue for a single producer and a single consumer.
+///
+/// The head and tail of the queue are atomic indices in the range `[0, 2 * SIZE)`. Which allows a full queue to be told apart from an empty queue without wasting a slot.
+///
+/// *NOTE:* The queue does not support `WRITE_OVER`. As only the consumer may move the head of the queue, the producer cannot drop the oldest element - so enqueuing to a full queue results in an [`Error::Overflow`].
+pub struct SpscQueue<const SIZE: usize, T> {
+    buffer: [UnsafeCell<MaybeUninit<T>>; SIZE],
+    /// Index of the next element to dequeue. Only written by the consumer
+    head: AtomicUsize,
+    /// Index of the next slot to enqueue into. Only written by the producer
+    tail: AtomicUsize,
+}
+
+unsafe impl<const SIZE: usize, T: Send> Send for SpscQueue<SIZE, T> {}
+unsafe impl<const SIZE: usize, T: Send> Sync for SpscQueue<SIZE, T> {}
+
+impl<const SIZE: usize, T> SpscQueue<SIZE, T> {
+    /// Creates an empty queue.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::spsc::SpscQueue;
+    /// # const SIZE: usize = 4;
+    /// let queue: SpscQueue<SIZE, i64> = SpscQueue::new();
+    ///
+    /// assert_eq!(queue.len(), 0);
+    /// ```
+    ///
+    /// A queue must be able to hold at least one element - which is checked at compile time.
+    ///
+    /// ```compile_fail
+    /// # use cyclic_data_types::queue::spsc::SpscQueue;
+    /// let queue: SpscQueue<0, i64> = SpscQueue::new();
+    /// ```
+    pub fn new() -> Self {
+        const { assert!(SIZE > 0, "an SpscQueue requires a SIZE of at least 1") };
+
+        Self {
+            buffer: array::from_fn(|_| UnsafeCell::new(MaybeUninit::uninit())),
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+        }
+    }
+
+    /// Splits the queue into its producer and consumer handles. The handles borrow the queue - so only one producer and one consumer can exist at a time.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::spsc::SpscQueue;
+    /// # const SIZE: usize = 4;
+    /// let mut queue: SpscQueue<SIZE, i64> = SpscQueue::new();
+    /// let (mut producer, mut consumer) = queue.split();
+    ///
+    /// assert!(producer.try_enqueue(1).is_ok());
+    /// assert_eq!(consumer.try_dequeue(), Some(1));
+    /// ```
+    pub fn split(&mut self) -> (Producer<'_, SIZE, T>, Consumer<'_, SIZE, T>) {
+        (Producer { queue: self }, Consumer { queue: self })
+    }
+
+    /// Returns the number of elements in the queue. The value may be out of date as soon as it is returned, if the queue is being used by other threads.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::spsc::SpscQueue;
+    /// # const SIZE: usize = 4;
+    /// let mut queue: SpscQueue<SIZE, i64> = SpscQueue::new();
+    /// let (mut producer, _consumer) = queue.split();
+    ///
+    /// assert!(producer.try_enqueue(1).is_ok());
+    ///
+    /// assert_eq!(producer.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        let head = self.head.load(Ordering::Acquire);
+        let tail = self.tail.load(Ordering::Acquire);
+
+        Self::distance(head, tail)
+    }
+
+    /// Returns `true` if the queue contains no elements.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::spsc::SpscQueue;
+    /// # const SIZE: usize = 4;
+    /// let queue: SpscQueue<SIZE, i64> = SpscQueue::new();
+    ///
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum number of elements the queue can hold.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::spsc::SpscQueue;
+    /// # const SIZE: usize = 4;
+    /// let queue: SpscQueue<SIZE, i64> = SpscQueue::new();
+    ///
+    /// assert_eq!(queue.capacity(), SIZE);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        SIZE
+    }
+
+    /// Number of elements between the head and tail indices
+    fn distance(head: usize, tail: usize) -> usize {
+        (tail + 2 * SIZE - head) % (2 * SIZE)
+    }
+
+    fn increment(index: usize) -> usize {
+        (index + 1) % (2 * SIZE)
+    }
+}
+
+impl<const SIZE: usize, T> Default for SpscQueue<SIZE, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const SIZE: usize, T> Debug for SpscQueue<SIZE, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SpscQueue")
+            .field("head", &self.head)
+            .field("tail", &self.tail)
+            .field("size", &self.len())
+            .finish()
+    }
+}
+
+impl<const SIZE: usize, T> Drop for SpscQueue<SIZE, T> {
+    fn drop(&mut self) {
+        let mut head = *self.head.get_mut();
+        let tail = *self.tail.get_mut();
+
+        while head != tail {
+            unsafe { self.buffer[head % SIZE].get_mut().assume_init_drop() };
+            head = Self::increment(head);
+        }
+    }
+}
+
+/// `Producer` is the handle used to enqueue elements into a [`SpscQueue`].
+pub struct Producer<'a, const SIZE: usize, T> {
+    queue: &'a SpscQueue<SIZE, T>,
+}
+
+impl<'a, const SIZE: usize, T> Producer<'a, SIZE, T> {
+    /// Pushes an element to the end of the queue - without blocking.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::spsc::SpscQueue;
+    /// # use cyclic_data_types::error::Error;
+    /// let mut queue: SpscQueue<1, i64> = SpscQueue::new();
+    /// let (mut producer, _consumer) = queue.split();
+    ///
+    /// assert_eq!(producer.try_enqueue(1), Ok(()));
+    /// assert_eq!(producer.try_enqueue(2), Err(Error::Overflow));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(())` if the element was enqueued
+    /// * [`Error::Overflow`] if the queue is full
+    pub fn try_enqueue(&mut self, elem: T) -> Result<(), Error> {
+        let tail = self.queue.tail.load(Ordering::Relaxed);
+        let head = self.queue.head.load(Ordering::Acquire);
+
+        if SpscQueue::<SIZE, T>::distance(head, tail) == SIZE {
+            return Err(Error::Overflow);
+        }
+
+        // the slot at tail is vacant and only the producer writes to vacant slots
+        unsafe { (*self.queue.buffer[tail % SIZE].get()).write(elem) };
+
+        self.queue
+            .tail
+            .store(SpscQueue::<SIZE, T>::increment(tail), Ordering::Release);
+
+        Ok(())
+    }
+
+    /// Returns the number of elements in the queue.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::spsc::SpscQueue;
+    /// # const SIZE: usize = 4;
+    /// let mut queue: SpscQueue<SIZE, i64> = SpscQueue::new();
+    /// let (producer, _consumer) = queue.split();
+    ///
+    /// assert_eq!(producer.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns `true` if the queue contains no elements.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::spsc::SpscQueue;
+    /// # const SIZE: usize = 4;
+    /// let mut queue: SpscQueue<SIZE, i64> = SpscQueue::new();
+    /// let (producer, _consumer) = queue.split();
+    ///
+    /// assert!(producer.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Returns `true` if enqueuing another element would overflow the queue.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::spsc::SpscQueue;
+    /// let mut queue: SpscQueue<1, i64> = SpscQueue::new();
+    /// let (mut producer, _consumer) = queue.split();
+    ///
+    /// assert!(producer.try_enqueue(1).is_ok());
+    ///
+    /// assert!(producer.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.queue.len() == SIZE
+    }
+}
+
+/// `Consumer` is the handle used to dequeue elements from a [`SpscQueue`].
+pub struct Consumer<'a, const SIZE: usize, T> {
+    queue: &'a SpscQueue<SIZE, T>,
+}
+
+impl<'a, const SIZE: usize, T> Consumer<'a, SIZE, T> {
+    /// Returns the first element of the queue - after removing said element from the queue. This does not block.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::spsc::SpscQueue;
+    /// # const SIZE: usize = 4;
+    /// let mut queue: SpscQueue<SIZE, i64> = SpscQueue::new();
+    /// let (mut producer, mut consumer) = queue.split();
+    ///
+    /// assert!(producer.try_enqueue(1).is_ok());
+    ///
+    /// assert_eq!(consumer.try_dequeue(), Some(1));
+    /// assert_eq!(consumer.try_dequeue(), None);
+    /// ```
+    pub fn try_dequeue(&mut self) -> Option<T> {
+        let head = self.queue.head.load(Ordering::Relaxed);
+        let tail = self.queue.tail.load(Ordering::Acquire);
+
+        if head == tail {
+            return None;
+        }
+
+        // the slot at head is occupied and only the consumer reads from occupied slots
+        let elem = unsafe { (*self.queue.buffer[head % SIZE].get()).assume_init_read() };
+
+        self.queue
+            .head
+            .store(SpscQueue::<SIZE, T>::increment(head), Ordering::Release);
+
+        Some(elem)
+    }
+
+    /// Returns a reference to the first element in the queue.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::spsc::SpscQueue;
+    /// # const SIZE: usize = 4;
+    /// let mut queue: SpscQueue<SIZE, i64> = SpscQueue::new();
+    /// let (mut producer, mut consumer) = queue.split();
+    ///
+    /// assert!(producer.try_enqueue(1).is_ok());
+    ///
+    /// assert_eq!(consumer.peek(), Some(&1));
+    /// ```
+    pub fn peek(&mut self) -> Option<&T> {
+        let head = self.queue.head.load(Ordering::Relaxed);
+        let tail = self.queue.tail.load(Ordering::Acquire);
+
+        if head == tail {
+            return None;
+        }
+
+        // the producer never writes to an occupied slot - and the element can only be removed by the consumer
+        Some(unsafe { (*self.queue.buffer[head % SIZE].get()).assume_init_ref() })
+    }
+
+    /// Returns the number of elements in the queue.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::spsc::SpscQueue;
+    /// # const SIZE: usize = 4;
+    /// let mut queue: SpscQueue<SIZE, i64> = SpscQueue::new();
+    /// let (_producer, consumer) = queue.split();
+    ///
+    /// assert_eq!(consumer.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns `true` if the queue contains no elements.
+    ///
+    /// ```
+    /// # use cyclic_data_types::queue::spsc::SpscQueue;
+    /// # const SIZE: usize = 4;
+    /// let mut queue: SpscQueue<SIZE, i64> = SpscQueue::new();
+    /// let (_producer, consumer) = queue.split();
+    ///
+    /// assert!(consumer.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
@@ -89,3 +89,285 @@ mod debug {
         assert_eq!("Queue { : List { : CyclicList { list: [Some(6), Some(2), Some(3), Some(4), Some(5)], start: 1, end: 0, size: 5 } } }", actual)
     }
 }
+
+mod heap_queue {
+    use crate::{error::Error, queue::HeapQueue};
+
+    #[test]
+    fn enqueue_dequeue() {
+        let mut queue: HeapQueue<i64, false> = HeapQueue::new(3).unwrap();
+
+        for i in 0..3 {
+            assert!(queue.enqueue(i).is_ok());
+        }
+        assert_eq!(queue.enqueue(3).err(), Some(Error::Overflow));
+
+        assert_eq!(queue.peek(), Some(&0));
+        for i in 0..3 {
+            assert_eq!(queue.dequeue(), Some(i));
+        }
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn overflow() {
+        let mut queue: HeapQueue<i64, true> = HeapQueue::new(3).unwrap();
+
+        for i in 0..5 {
+            assert!(queue.enqueue(i).is_ok());
+        }
+
+        assert_eq!(queue.to_string(), "[2, 3, 4]");
+        assert_eq!(queue.dequeue(), Some(2));
+    }
+}
+
+mod spsc {
+    use std::{rc::Rc, sync::Arc, thread};
+
+    use crate::{error::Error, queue::spsc::SpscQueue};
+
+    const SIZE: usize = 4;
+
+    #[test]
+    fn enqueue_dequeue() {
+        let mut queue: SpscQueue<SIZE, i64> = SpscQueue::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        for round in 0..3 {
+            for i in 0..4 {
+                assert_eq!(producer.try_enqueue(round * 4 + i), Ok(()));
+            }
+            assert!(producer.is_full());
+            assert_eq!(producer.try_enqueue(-1), Err(Error::Overflow));
+
+            assert_eq!(consumer.peek(), Some(&(round * 4)));
+            for i in 0..4 {
+                assert_eq!(consumer.try_dequeue(), Some(round * 4 + i));
+            }
+            assert_eq!(consumer.try_dequeue(), None);
+            assert!(consumer.is_empty());
+        }
+    }
+
+    #[test]
+    fn interleaved() {
+        let mut queue: SpscQueue<SIZE, i64> = SpscQueue::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        let mut expected = 0;
+        for i in 0..20 {
+            assert_eq!(producer.try_enqueue(i), Ok(()));
+            if i % 3 == 0 {
+                assert_eq!(consumer.try_dequeue(), Some(expected));
+                expected += 1;
+            }
+            assert_eq!(consumer.len() as i64, i + 1 - expected);
+
+            while producer.is_full() {
+                assert_eq!(consumer.try_dequeue(), Some(expected));
+                expected += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn drops_remaining() {
+        let counter = Rc::new(());
+
+        {
+            let mut queue: SpscQueue<SIZE, Rc<()>> = SpscQueue::new();
+            let (mut producer, mut consumer) = queue.split();
+
+            for _ in 0..3 {
+                assert!(producer.try_enqueue(counter.clone()).is_ok());
+            }
+            drop(consumer.try_dequeue());
+
+            assert_eq!(Rc::strong_count(&counter), 3);
+        }
+
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn threads() {
+        const COUNT: u64 = 10_000;
+
+        let mut queue: SpscQueue<SIZE, u64> = SpscQueue::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        thread::scope(|scope| {
+            scope.spawn(move || {
+                for i in 0..COUNT {
+                    while producer.try_enqueue(i).is_err() {
+                        thread::yield_now();
+                    }
+                }
+            });
+
+            let handle = scope.spawn(move || {
+                let mut expected = 0;
+                while expected < COUNT {
+                    match consumer.try_dequeue() {
+                        Some(elem) => {
+                            assert_eq!(elem, expected);
+                            expected += 1;
+                        }
+                        None => thread::yield_now(),
+                    }
+                }
+                consumer.try_dequeue()
+            });
+
+            assert_eq!(handle.join().unwrap(), None);
+        });
+    }
+
+    #[test]
+    fn threads_heap_elements() {
+        const COUNT: usize = 10_000;
+
+        let counter = Arc::new(());
+        let mut queue: SpscQueue<SIZE, Arc<()>> = SpscQueue::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        thread::scope(|scope| {
+            let counter = &counter;
+            scope.spawn(move || {
+                for _ in 0..COUNT {
+                    while producer.try_enqueue(counter.clone()).is_err() {
+                        thread::yield_now();
+                    }
+                }
+            });
+
+            let mut received = 0;
+            while received < COUNT {
+                match consumer.try_dequeue() {
+                    Some(_) => received += 1,
+                    None => thread::yield_now(),
+                }
+            }
+        });
+
+        drop(queue);
+        assert_eq!(Arc::strong_count(&counter), 1);
+    }
+}
+
+mod mpmc {
+    use std::{sync::Arc, thread};
+
+    use crate::{
+        error::Error,
+        queue::mpmc::{self, MpmcQueue},
+    };
+
+    const SIZE: usize = 4;
+
+    #[test]
+    fn overflow() {
+        let (sender, receiver) = mpmc::bounded::<SIZE, i64, false>();
+
+        for round in 0..3 {
+            for i in 0..4 {
+                assert_eq!(sender.try_send(round * 4 + i), Ok(()));
+            }
+            assert_eq!(sender.len(), SIZE);
+            assert_eq!(sender.try_send(-1), Err(Error::Overflow));
+
+            for i in 0..4 {
+                assert_eq!(receiver.try_recv(), Some(round * 4 + i));
+            }
+            assert_eq!(receiver.try_recv(), None);
+            assert!(receiver.is_empty());
+        }
+    }
+
+    #[test]
+    fn single_slot() {
+        let queue: MpmcQueue<1, i64, false> = MpmcQueue::new();
+
+        for i in 0..3 {
+            assert_eq!(queue.try_enqueue(i), Ok(()));
+            assert_eq!(queue.try_enqueue(-1), Err(Error::Overflow));
+            assert_eq!(queue.try_dequeue(), Some(i));
+            assert_eq!(queue.try_dequeue(), None);
+        }
+    }
+
+    #[test]
+    fn write_over() {
+        let queue: MpmcQueue<SIZE, i64, true> = MpmcQueue::new();
+
+        for i in 0..10 {
+            assert_eq!(queue.try_enqueue(i), Ok(()));
+        }
+        assert_eq!(queue.len(), SIZE);
+
+        for i in 6..10 {
+            assert_eq!(queue.try_dequeue(), Some(i));
+        }
+        assert_eq!(queue.try_dequeue(), None);
+    }
+
+    #[test]
+    fn drops_remaining() {
+        let counter = Arc::new(());
+        let queue: MpmcQueue<SIZE, Arc<()>, true> = MpmcQueue::new();
+
+        for _ in 0..6 {
+            assert!(queue.try_enqueue(counter.clone()).is_ok());
+        }
+        assert_eq!(Arc::strong_count(&counter), 1 + SIZE);
+
+        drop(queue);
+        assert_eq!(Arc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn threads() {
+        const PRODUCERS: u64 = 3;
+        const CONSUMERS: usize = 3;
+        const COUNT: u64 = 2_000;
+
+        let (sender, receiver) = mpmc::bounded::<SIZE, u64, false>();
+
+        let received: Vec<Vec<u64>> = thread::scope(|scope| {
+            for producer in 0..PRODUCERS {
+                let sender = sender.clone();
+                scope.spawn(move || {
+                    for i in 0..COUNT {
+                        while sender.try_send(producer * COUNT + i).is_err() {
+                            thread::yield_now();
+                        }
+                    }
+                });
+            }
+
+            let consumers: Vec<_> = (0..CONSUMERS)
+                .map(|_| {
+                    let receiver = receiver.clone();
+                    scope.spawn(move || {
+                        let mut received = Vec::new();
+                        while received.len() < (PRODUCERS * COUNT) as usize / CONSUMERS {
+                            match receiver.try_recv() {
+                                Some(elem) => received.push(elem),
+                                None => thread::yield_now(),
+                            }
+                        }
+                        received
+                    })
+                })
+                .collect();
+
+            consumers.into_iter().map(|c| c.join().unwrap()).collect()
+        });
+
+        let mut all: Vec<u64> = received.into_iter().flatten().collect();
+        all.sort_unstable();
+        assert_eq!(all, (0..PRODUCERS * COUNT).collect::<Vec<u64>>());
+        assert!(receiver.is_empty());
+    }
+}
@@ -0,0 +1,229 @@
+use std::fmt::Debug;
+
+use crate::list::List;
+
+use super::Stack;
+
+/// `History` is a bounded undo/redo history - built on a pair of [`Stack`]s.
+///
+/// Recorded actions are pushed onto a `WRITE_OVER` undo stack. As a result, once `SIZE` actions have been recorded - the oldest action is written over by each new action. Undoing an action moves it onto the redo stack, and redoing an action moves it back. Recording a new action clears the redo stack - as the undone actions belong to a branch of the history which has been abandoned.
+///
+/// ```
+/// # use cyclic_data_types::stack::History;
+/// let mut history: History<3, &str> = History::new();
+///
+/// history.record("type a");
+/// history.record("type b");
+///
+/// assert_eq!(history.undo(), Some(&"type b"));
+/// assert_eq!(history.redo(), Some(&"type b"));
+///
+/// assert_eq!(history.undo(), Some(&"type b"));
+/// history.record("type c");
+///
+/// // "type b" was on the abandoned branch
+/// assert!(!history.can_redo());
+/// assert_eq!(history.undo(), Some(&"type c"));
+/// assert_eq!(history.undo(), Some(&"type a"));
+/// assert_eq!(history.undo(), None);
+/// ```
+pub struct History<const SIZE: usize, T> {
+    undo: Stack<SIZE, T, true>,
+    redo: Stack<SIZE, T, false>,
+}
+
+impl<const SIZE: usize, T> History<SIZE, T> {
+    /// Creates an empty history.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stack::History;
+    /// let history: History<3, &str> = History::new();
+    ///
+    /// assert!(!history.can_undo());
+    /// assert!(!history.can_redo());
+    /// ```
+    ///
+    /// A history must be able to hold at least one action - which is checked at compile time.
+    ///
+    /// ```compile_fail
+    /// # use cyclic_data_types::stack::History;
+    /// let history: History<0, &str> = History::new();
+    /// ```
+    pub fn new() -> Self {
+        const { assert!(SIZE > 0, "a History requires a SIZE of at least 1") };
+
+        Self {
+            undo: List::empty().into(),
+            redo: List::empty().into(),
+        }
+    }
+
+    /// Records a new action - clearing every action which could be redone. If the history is full, the oldest action is dropped.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stack::History;
+    /// let mut history: History<2, i64> = History::new();
+    ///
+    /// history.record(1);
+    /// history.record(2);
+    /// history.record(3);
+    ///
+    /// assert_eq!(history.undo(), Some(&3));
+    /// assert_eq!(history.undo(), Some(&2));
+    /// assert_eq!(history.undo(), None);
+    /// ```
+    pub fn record(&mut self, action: T) {
+        while self.redo.pop().is_some() {}
+
+        self.undo
+            .push(action)
+            .expect("write-over Stack cannot overflow");
+    }
+
+    /// Undoes the most recent action - returning a reference to the undone action.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stack::History;
+    /// let mut history: History<3, i64> = History::new();
+    ///
+    /// history.record(1);
+    ///
+    /// assert_eq!(history.undo(), Some(&1));
+    /// assert_eq!(history.undo(), None);
+    /// ```
+    pub fn undo(&mut self) -> Option<&T> {
+        let action = self.undo.pop()?;
+
+        self.redo
+            .push(action)
+            .expect("the undo and redo stacks hold at most SIZE actions between them");
+
+        self.redo.peek()
+    }
+
+    /// Redoes the most recently undone action - returning a reference to the redone action.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stack::History;
+    /// let mut history: History<3, i64> = History::new();
+    ///
+    /// history.record(1);
+    /// history.record(2);
+    ///
+    /// assert_eq!(history.undo(), Some(&2));
+    /// assert_eq!(history.undo(), Some(&1));
+    ///
+    /// assert_eq!(history.redo(), Some(&1));
+    /// assert_eq!(history.redo(), Some(&2));
+    /// assert_eq!(history.redo(), None);
+    /// ```
+    pub fn redo(&mut self) -> Option<&T> {
+        let action = self.redo.pop()?;
+
+        self.undo
+            .push(action)
+            .expect("write-over Stack cannot overflow");
+
+        self.undo.peek()
+    }
+
+    /// Returns `true` if there is an action to undo.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stack::History;
+    /// let mut history: History<3, i64> = History::new();
+    ///
+    /// assert!(!history.can_undo());
+    ///
+    /// history.record(1);
+    ///
+    /// assert!(history.can_undo());
+    /// ```
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns `true` if there is an undone action to redo.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stack::History;
+    /// let mut history: History<3, i64> = History::new();
+    ///
+    /// history.record(1);
+    ///
+    /// assert!(!history.can_redo());
+    ///
+    /// history.undo();
+    ///
+    /// assert!(history.can_redo());
+    /// ```
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Returns the number of actions which can be undone.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stack::History;
+    /// let mut history: History<2, i64> = History::new();
+    ///
+    /// history.record(1);
+    /// history.record(2);
+    /// history.record(3);
+    ///
+    /// assert_eq!(history.undo_len(), 2);
+    /// ```
+    pub fn undo_len(&self) -> usize {
+        self.undo.len()
+    }
+
+    /// Returns the number of actions which can be redone.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stack::History;
+    /// let mut history: History<2, i64> = History::new();
+    ///
+    /// history.record(1);
+    /// history.undo();
+    ///
+    /// assert_eq!(history.redo_len(), 1);
+    /// ```
+    pub fn redo_len(&self) -> usize {
+        self.redo.len()
+    }
+
+    /// Removes every action from the history.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stack::History;
+    /// let mut history: History<2, i64> = History::new();
+    ///
+    /// history.record(1);
+    /// history.record(2);
+    /// history.undo();
+    ///
+    /// history.clear();
+    ///
+    /// assert!(!history.can_undo());
+    /// assert!(!history.can_redo());
+    /// ```
+    pub fn clear(&mut self) {
+        while self.undo.pop().is_some() {}
+        while self.redo.pop().is_some() {}
+    }
+}
+
+impl<const SIZE: usize, T> Default for History<SIZE, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const SIZE: usize, T: Debug> Debug for History<SIZE, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("History")
+            .field("undo", &self.undo)
+            .field("redo", &self.redo)
+            .finish()
+    }
+}
@@ -8,7 +8,15 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use crate::{error::Error, list::List};
+use crate::{
+    error::Error,
+    list::{HeapList, List},
+    storage::Storage,
+};
+
+pub use self::history::History;
+
+mod history;
 
 #[cfg(test)]
 mod tests;
@@ -18,7 +26,7 @@ mod tests;
 /// *NOTE:* It is recommended to use [`Vec`] over [`Stack`] for most applications. As [`Vec`] has better - if not similar performance to the [`Stack`]. It is therefore, [`Stack`] should only be used when the stack should strictly be limited to a given size and or convince of life features provided by the [`Stack`].
 ///
 /// # Generics
-/// List types are derived using 3 generics.
+/// Stack types are derived using 4 generics.
 ///
 /// 1. `const SIZE: usize`
 ///
@@ -30,6 +38,10 @@ mod tests;
 ///
 /// 3. `const WRITE_OVER: bool>`
 ///
+/// 4. `S: Storage<T> = [Option<T>; SIZE]`
+///
+/// S is the [`Storage`] the elements of the stack are stored in - an inline array by default (see [`HeapStack`]).
+///
 /// # Creating Stacks
 ///
 /// Stacks can be created in a couple of ways.
@@ -100,10 +112,16 @@ mod tests;
 ///
 /// WRITE_OVER is a generic constant [^note] that is used to determine if elements should be over written on overflow
 /// [note]: [Generic Constraints](https://rust-lang.github.io/rfcs/2000-const-generics.html)
-#[derive(Default, PartialEq)]
-pub struct Stack<const SIZE: usize, T, const WRITE_OVER: bool>(List<SIZE, T, WRITE_OVER>);
+pub struct Stack<const SIZE: usize, T, const WRITE_OVER: bool, S = [Option<T>; SIZE]>(
+    List<SIZE, T, WRITE_OVER, S>,
+)
+where
+    S: Storage<T>;
 
-impl<const SIZE: usize, T, const WRITE_OVER: bool> Stack<SIZE, T, WRITE_OVER> {
+impl<const SIZE: usize, T, const WRITE_OVER: bool, S> Stack<SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
+{
     /// Returns the number of elements in the stack.
     ///
     /// ```
@@ -200,8 +218,9 @@ impl<const SIZE: usize, T, const WRITE_OVER: bool> Stack<SIZE, T, WRITE_OVER> {
     }
 }
 
-impl<const S: usize, T, const W: bool> Display for Stack<S, T, W>
+impl<const SIZE: usize, T, const W: bool, S> Display for Stack<SIZE, T, W, S>
 where
+    S: Storage<T>,
     T: Display,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -209,8 +228,9 @@ where
     }
 }
 
-impl<const S: usize, T, const W: bool> Debug for Stack<S, T, W>
+impl<const SIZE: usize, T, const W: bool, S> Debug for Stack<SIZE, T, W, S>
 where
+    S: Storage<T>,
     T: Debug,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -218,6 +238,25 @@ where
     }
 }
 
+impl<const SIZE: usize, T, const W: bool, S> PartialEq for Stack<SIZE, T, W, S>
+where
+    S: Storage<T>,
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Default for Stack<SIZE, T, W>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self(List::default())
+    }
+}
+
 impl<const STACK_SIZE: usize, T, const WRITE_OVER: bool> TryFrom<Vec<T>>
     for Stack<STACK_SIZE, T, WRITE_OVER>
 where
@@ -288,40 +327,97 @@ impl<const STACK_SIZE: usize, T, const WRITE_OVER: bool> From<[T; STACK_SIZE]>
     }
 }
 
-impl<const STACK_SIZE: usize, T, const WRITE_OVER: bool> From<List<STACK_SIZE, T, WRITE_OVER>>
-    for Stack<STACK_SIZE, T, WRITE_OVER>
+impl<const STACK_SIZE: usize, T, const WRITE_OVER: bool, S> From<List<STACK_SIZE, T, WRITE_OVER, S>>
+    for Stack<STACK_SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
 {
-    fn from(value: List<STACK_SIZE, T, WRITE_OVER>) -> Self {
+    fn from(value: List<STACK_SIZE, T, WRITE_OVER, S>) -> Self {
         Self(value)
     }
 }
 
-impl<const STACK_SIZE: usize, T> From<Stack<STACK_SIZE, T, true>> for Stack<STACK_SIZE, T, false> {
-    fn from(value: Stack<STACK_SIZE, T, true>) -> Self {
+impl<const STACK_SIZE: usize, T, S> From<Stack<STACK_SIZE, T, true, S>>
+    for Stack<STACK_SIZE, T, false, S>
+where
+    S: Storage<T>,
+{
+    fn from(value: Stack<STACK_SIZE, T, true, S>) -> Self {
         Self(value.0.into())
     }
 }
 
-impl<const STACK_SIZE: usize, T> From<Stack<STACK_SIZE, T, false>> for Stack<STACK_SIZE, T, true> {
-    fn from(value: Stack<STACK_SIZE, T, false>) -> Self {
+impl<const STACK_SIZE: usize, T, S> From<Stack<STACK_SIZE, T, false, S>>
+    for Stack<STACK_SIZE, T, true, S>
+where
+    S: Storage<T>,
+{
+    fn from(value: Stack<STACK_SIZE, T, false, S>) -> Self {
         Self(value.0.into())
     }
 }
 
-impl<const STACK_SIZE: usize, T, const WRITE_OVER: bool> Deref
-    for Stack<STACK_SIZE, T, WRITE_OVER>
+impl<const STACK_SIZE: usize, T, const WRITE_OVER: bool, S> Deref
+    for Stack<STACK_SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
 {
-    type Target = List<STACK_SIZE, T, WRITE_OVER>;
+    type Target = List<STACK_SIZE, T, WRITE_OVER, S>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
 
-impl<const STACK_SIZE: usize, T, const WRITE_OVER: bool> DerefMut
-    for Stack<STACK_SIZE, T, WRITE_OVER>
+impl<const STACK_SIZE: usize, T, const WRITE_OVER: bool, S> DerefMut
+    for Stack<STACK_SIZE, T, WRITE_OVER, S>
+where
+    S: Storage<T>,
 {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
 }
+
+/// `HeapStack` is a [`Stack`] built on a [`HeapList`]. As a result, the capacity of the stack is chosen at construction time rather than at compile time.
+///
+/// Apart from where the elements are stored, `HeapStack` is the same type as [`Stack`] - so has the same API and semantics.
+///
+/// ```
+/// # use cyclic_data_types::stack::HeapStack;
+/// let mut stack: HeapStack<i64, false> = HeapStack::new(3).unwrap();
+///
+/// assert!(stack.push(1).is_ok());
+/// assert!(stack.push(2).is_ok());
+///
+/// assert_eq!(stack.pop(), Some(2));
+/// assert_eq!(stack.pop(), Some(1));
+/// assert_eq!(stack.pop(), None);
+/// ```
+pub type HeapStack<T, const WRITE_OVER: bool> = Stack<0, T, WRITE_OVER, Box<[Option<T>]>>;
+
+impl<T, const WRITE_OVER: bool> HeapStack<T, WRITE_OVER> {
+    /// Creates an empty stack that can hold at most `capacity` elements.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stack::HeapStack;
+    /// let stack: HeapStack<i64, false> = HeapStack::new(3).unwrap();
+    ///
+    /// assert_eq!(stack.capacity(), 3);
+    /// ```
+    ///
+    /// # Returns
+    /// * Self if `capacity` > 0
+    /// * [`Error::InvalidSize`] if `capacity` = 0
+    pub fn new(capacity: usize) -> Result<Self, Error> {
+        Ok(Self(HeapList::new(capacity)?))
+    }
+}
+
+impl<T, const WRITE_OVER: bool> TryFrom<Vec<T>> for HeapStack<T, WRITE_OVER> {
+    type Error = Error;
+
+    fn try_from(value: Vec<T>) -> Result<Self, Self::Error> {
+        Ok(Stack(value.try_into()?))
+    }
+}
@@ -321,3 +321,116 @@ mod debug {
         assert_eq!("Stack { : List { : CyclicList { list: [Some(6), Some(2), Some(3), Some(4), Some(5)], start: 1, end: 0, size: 5 } } }", actual)
     }
 }
+
+mod heap_stack {
+    use crate::{error::Error, stack::HeapStack};
+
+    #[test]
+    fn push_pop() {
+        let mut stack: HeapStack<i64, false> = HeapStack::new(3).unwrap();
+
+        for i in 0..3 {
+            assert!(stack.push(i).is_ok());
+        }
+        assert_eq!(stack.push(3).err(), Some(Error::Overflow));
+
+        assert_eq!(stack.peek(), Some(&2));
+        for i in (0..3).rev() {
+            assert_eq!(stack.pop(), Some(i));
+        }
+        assert_eq!(stack.pop(), None);
+    }
+
+    #[test]
+    fn overflow() {
+        let mut stack: HeapStack<i64, true> = HeapStack::new(3).unwrap();
+
+        for i in 0..5 {
+            assert!(stack.push(i).is_ok());
+        }
+
+        assert_eq!(stack.read(0), Ok(&4));
+        assert_eq!(stack.read(2), Ok(&2));
+        assert_eq!(stack.read(3), Err(Error::IndexOutOfRange));
+    }
+}
+
+mod history {
+    use crate::stack::History;
+
+    const SIZE: usize = 3;
+
+    #[test]
+    fn undo_redo() {
+        let mut history: History<SIZE, i64> = History::new();
+
+        for i in 0..3 {
+            history.record(i);
+        }
+
+        for i in (0..3).rev() {
+            assert!(history.can_undo());
+            assert_eq!(history.undo(), Some(&i));
+        }
+        assert!(!history.can_undo());
+        assert_eq!(history.undo(), None);
+
+        for i in 0..3 {
+            assert!(history.can_redo());
+            assert_eq!(history.redo(), Some(&i));
+        }
+        assert!(!history.can_redo());
+        assert_eq!(history.redo(), None);
+    }
+
+    #[test]
+    fn record_truncates_redo() {
+        let mut history: History<SIZE, i64> = History::new();
+
+        for i in 0..3 {
+            history.record(i);
+        }
+
+        history.undo();
+        history.undo();
+        assert_eq!(history.redo_len(), 2);
+
+        history.record(10);
+
+        assert!(!history.can_redo());
+        assert_eq!(history.undo_len(), 2);
+        assert_eq!(history.undo(), Some(&10));
+        assert_eq!(history.undo(), Some(&0));
+    }
+
+    #[test]
+    fn drops_oldest() {
+        let mut history: History<SIZE, i64> = History::new();
+
+        for i in 0..10 {
+            history.record(i);
+            assert_eq!(history.undo_len(), usize::min(i as usize + 1, SIZE));
+        }
+
+        for i in (7..10).rev() {
+            assert_eq!(history.undo(), Some(&i));
+        }
+        assert_eq!(history.undo(), None);
+
+        // every undone action can be redone
+        for i in 7..10 {
+            assert_eq!(history.redo(), Some(&i));
+        }
+    }
+
+    #[test]
+    fn non_default_actions() {
+        let mut history: History<SIZE, Box<dyn Fn() -> i64>> = History::new();
+
+        history.record(Box::new(|| 1));
+        history.record(Box::new(|| 2));
+
+        assert_eq!(history.undo().map(|action| action()), Some(2));
+        assert_eq!(history.redo().map(|action| action()), Some(2));
+    }
+}
@@ -0,0 +1,267 @@
+use std::fmt::Debug;
+
+use crate::{error::Error, list::List};
+
+use super::monotonic::{element_at, MonotonicDeque};
+
+/// `MinMaxWindow` keeps the minimum and maximum of a sliding window of [`Ord`] values - using a pair of monotonic deques over fixed-size cyclic lists. Every push is O(1) amortised, and reading the minimum or maximum is O(1).
+///
+/// Each value is stored once, in a cyclic list of the window - the deques only hold the sample numbers of the values. Therefore, values do not need to be [`Clone`].
+///
+/// Every value in the window has a timestamp. Values pushed with [`MinMaxWindow::push`] are timestamped with their sample number (starting at 0), while [`MinMaxWindow::push_at`] takes the timestamp from the caller. Timestamps are expected to never decrease.
+///
+/// *NOTE:* Mixing [`MinMaxWindow::push`] and [`MinMaxWindow::push_at`] on the same window is unsupported. Sample numbers and the caller's timestamps are unrelated - so [`MinMaxWindow::expire_before`] would remove the wrong values.
+///
+/// Values leave the window in two ways.
+/// 1. Explicitly - using [`MinMaxWindow::expire_before`] to remove every value older than a timestamp.
+/// 2. On overflow - based on the `WRITE_OVER` flag.
+///     * `WRITE_OVER = true`: the window holds the last `SIZE` values, and the oldest value leaves the window on each push to a full window.
+///     * `WRITE_OVER = false`: pushing to a full window results in an [`Error::Overflow`].
+///
+/// ```
+/// # use cyclic_data_types::stats::MinMaxWindow;
+/// let mut window: MinMaxWindow<3, i64, true> = MinMaxWindow::new();
+///
+/// for value in [5, 1, 3, 4, 2] {
+///     assert!(window.push(value).is_ok());
+/// }
+///
+/// // the window holds [3, 4, 2]
+/// assert_eq!(window.min(), Some(&2));
+/// assert_eq!(window.max(), Some(&4));
+/// ```
+pub struct MinMaxWindow<const SIZE: usize, T, const WRITE_OVER: bool> {
+    entries: List<SIZE, (u64, T), WRITE_OVER>,
+    pushed: u64,
+    min: MonotonicDeque<SIZE, false>,
+    max: MonotonicDeque<SIZE, true>,
+}
+
+impl<const SIZE: usize, T, const WRITE_OVER: bool> MinMaxWindow<SIZE, T, WRITE_OVER>
+where
+    T: Ord,
+{
+    /// Creates an empty window.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::MinMaxWindow;
+    /// let window: MinMaxWindow<3, i64, true> = MinMaxWindow::new();
+    ///
+    /// assert!(window.is_empty());
+    /// ```
+    ///
+    /// A window must be able to hold at least one value - which is checked at compile time.
+    ///
+    /// ```compile_fail
+    /// # use cyclic_data_types::stats::MinMaxWindow;
+    /// let window: MinMaxWindow<0, i64, true> = MinMaxWindow::new();
+    /// ```
+    pub fn new() -> Self {
+        const { assert!(SIZE > 0, "a MinMaxWindow requires a SIZE of at least 1") };
+
+        Self {
+            entries: List::empty(),
+            pushed: 0,
+            min: MonotonicDeque::new(),
+            max: MonotonicDeque::new(),
+        }
+    }
+
+    /// Pushes a value into the window - timestamped with its sample number. Which must not be mixed with [`MinMaxWindow::push_at`] on the same window.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::MinMaxWindow;
+    /// # use cyclic_data_types::error::Error;
+    /// let mut window: MinMaxWindow<2, i64, false> = MinMaxWindow::new();
+    ///
+    /// assert_eq!(window.push(1), Ok(()));
+    /// assert_eq!(window.push(2), Ok(()));
+    /// assert_eq!(window.push(3), Err(Error::Overflow));
+    ///
+    /// window.expire_before(1);
+    ///
+    /// assert_eq!(window.push(3), Ok(()));
+    /// assert_eq!(window.min(), Some(&2));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(())` if the push was successful
+    /// * [`Error::Overflow`] if the window is full and the `WRITE_OVER` flag is set to `false`
+    pub fn push(&mut self, value: T) -> Result<(), Error> {
+        self.push_at(self.pushed, value)
+    }
+
+    /// Pushes a value into the window with the given timestamp. Which must not be mixed with [`MinMaxWindow::push`] on the same window.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::MinMaxWindow;
+    /// let mut window: MinMaxWindow<8, i64, true> = MinMaxWindow::new();
+    ///
+    /// assert!(window.push_at(100, 7).is_ok());
+    /// assert!(window.push_at(250, 3).is_ok());
+    /// assert!(window.push_at(300, 5).is_ok());
+    ///
+    /// window.expire_before(200);
+    ///
+    /// assert_eq!(window.max(), Some(&5));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(())` if the push was successful
+    /// * [`Error::Overflow`] if the window is full and the `WRITE_OVER` flag is set to `false`
+    pub fn push_at(&mut self, timestamp: u64, value: T) -> Result<(), Error> {
+        self.entries.push_back((timestamp, value))?;
+
+        let seq = self.pushed;
+        self.pushed += 1;
+
+        self.expire_deques();
+
+        let value_at = |seq| &element_at(&self.entries, self.pushed, seq).1;
+
+        self.min.push(seq, value_at);
+        self.max.push(seq, value_at);
+
+        Ok(())
+    }
+
+    /// Removes every value with a timestamp less than `timestamp` from the window.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::MinMaxWindow;
+    /// let mut window: MinMaxWindow<4, i64, true> = MinMaxWindow::new();
+    ///
+    /// for value in [4, 3, 2, 1] {
+    ///     assert!(window.push(value).is_ok());
+    /// }
+    ///
+    /// window.expire_before(2);
+    ///
+    /// assert_eq!(window.len(), 2);
+    /// assert_eq!(window.max(), Some(&2));
+    /// ```
+    pub fn expire_before(&mut self, timestamp: u64) {
+        while matches!(self.entries.get(0), Some((oldest, _)) if *oldest < timestamp) {
+            self.entries.remove_front();
+        }
+
+        self.expire_deques();
+    }
+
+    /// Returns the minimum value in the window - or `None` if the window is empty.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::MinMaxWindow;
+    /// let mut window: MinMaxWindow<2, i64, true> = MinMaxWindow::new();
+    ///
+    /// assert_eq!(window.min(), None);
+    ///
+    /// assert!(window.push(1).is_ok());
+    /// assert!(window.push(3).is_ok());
+    /// assert!(window.push(2).is_ok());
+    ///
+    /// assert_eq!(window.min(), Some(&2));
+    /// ```
+    pub fn min(&self) -> Option<&T> {
+        self.min.front().map(|seq| self.value_at(seq))
+    }
+
+    /// Returns the maximum value in the window - or `None` if the window is empty.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::MinMaxWindow;
+    /// let mut window: MinMaxWindow<2, i64, true> = MinMaxWindow::new();
+    ///
+    /// assert_eq!(window.max(), None);
+    ///
+    /// assert!(window.push(3).is_ok());
+    /// assert!(window.push(1).is_ok());
+    /// assert!(window.push(2).is_ok());
+    ///
+    /// assert_eq!(window.max(), Some(&2));
+    /// ```
+    pub fn max(&self) -> Option<&T> {
+        self.max.front().map(|seq| self.value_at(seq))
+    }
+
+    /// Returns the number of values in the window.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::MinMaxWindow;
+    /// let mut window: MinMaxWindow<2, i64, true> = MinMaxWindow::new();
+    ///
+    /// assert!(window.push(1).is_ok());
+    /// assert!(window.push(2).is_ok());
+    /// assert!(window.push(3).is_ok());
+    ///
+    /// assert_eq!(window.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the window contains no values.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::MinMaxWindow;
+    /// let window: MinMaxWindow<2, i64, true> = MinMaxWindow::new();
+    ///
+    /// assert!(window.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.entries.len() == 0
+    }
+
+    /// Removes every value from the window. Sample numbers continue from the last pushed value.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::MinMaxWindow;
+    /// let mut window: MinMaxWindow<2, i64, true> = MinMaxWindow::new();
+    ///
+    /// assert!(window.push(1).is_ok());
+    /// window.clear();
+    ///
+    /// assert_eq!(window.max(), None);
+    /// ```
+    pub fn clear(&mut self) {
+        while self.entries.remove_front().is_some() {}
+
+        self.min.clear();
+        self.max.clear();
+    }
+
+    /// Returns the value in the window with the sample number `seq`
+    fn value_at(&self, seq: u64) -> &T {
+        &element_at(&self.entries, self.pushed, seq).1
+    }
+
+    /// Removes the values which have left the window from the deques
+    fn expire_deques(&mut self) {
+        let oldest = self.pushed - self.entries.len() as u64;
+
+        self.min.expire_before(oldest);
+        self.max.expire_before(oldest);
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Default for MinMaxWindow<SIZE, T, W>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const SIZE: usize, T, const W: bool> Debug for MinMaxWindow<SIZE, T, W>
+where
+    T: Ord + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MinMaxWindow")
+            .field("size", &self.len())
+            .field("min", &self.min())
+            .field("max", &self.max())
+            .finish()
+    }
+}
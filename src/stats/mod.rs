@@ -0,0 +1,379 @@
+//! The stats module contains accumulators which keep statistics over a sliding window of values - without iterating over the window.
+//!
+//! * [`WindowStats`] keeps the sum, mean, variance, minimum and maximum of a window of `f64` values.
+//! * [`MinMaxWindow`] keeps only the minimum and maximum of a window of [`Ord`] values - which can also be expired by timestamp.
+//!
+//! ```
+//! # use cyclic_data_types::stats::WindowStats;
+//! let mut latency: WindowStats<3> = WindowStats::new();
+//!
+//! for value in [4.0, 8.0, 6.0, 2.0] {
+//!     latency.push(value);
+//! }
+//!
+//! // the window holds [8.0, 6.0, 2.0]
+//! assert_eq!(latency.sum(), 16.0);
+//! assert_eq!(latency.min(), Some(2.0));
+//! assert_eq!(latency.max(), Some(8.0));
+//! ```
+
+use std::fmt::Debug;
+
+use crate::list::{Iter, List};
+
+use self::monotonic::{element_at, MonotonicDeque};
+
+pub use self::min_max::MinMaxWindow;
+
+mod min_max;
+mod monotonic;
+
+#[cfg(test)]
+mod tests;
+
+/// `WindowStats` is a write over ring of the last `SIZE` values - which keeps the running sum, mean, variance, minimum and maximum of the values in the ring.
+///
+/// Every push updates all statistics in O(1) amortised time.
+/// * The mean and variance are kept using Welford's algorithm - extended to remove the value leaving the window.
+/// * The minimum and maximum are kept using monotonic deques.
+///
+/// *NOTE:* The running sum is updated by adding and subtracting values. Therefore, over a long running window, the sum (and mean) may drift from the exact sum of the window by floating point rounding errors. While the window contains an infinite or `NaN` value, the sum, mean and variance are non-finite - once that value leaves the window, they are recomputed from the remaining values in O(SIZE). The minimum and maximum of a window containing `NaN` are unspecified.
+///
+/// ```
+/// # use cyclic_data_types::stats::WindowStats;
+/// let mut window: WindowStats<4> = WindowStats::new();
+///
+/// for value in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+///     window.push(value);
+/// }
+///
+/// // the window holds [5.0, 5.0, 7.0, 9.0]
+/// assert_eq!(window.mean(), Some(6.5));
+/// assert!((window.variance().unwrap() - 2.75).abs() < 1e-9);
+/// ```
+pub struct WindowStats<const SIZE: usize> {
+    values: List<SIZE, f64, true>,
+    pushed: u64,
+    sum: f64,
+    mean: f64,
+    m2: f64,
+    min: MonotonicDeque<SIZE, false>,
+    max: MonotonicDeque<SIZE, true>,
+}
+
+impl<const SIZE: usize> WindowStats<SIZE> {
+    /// Creates an empty window.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::WindowStats;
+    /// let window: WindowStats<4> = WindowStats::new();
+    ///
+    /// assert!(window.is_empty());
+    /// ```
+    ///
+    /// A window must be able to hold at least one value - which is checked at compile time.
+    ///
+    /// ```compile_fail
+    /// # use cyclic_data_types::stats::WindowStats;
+    /// let window: WindowStats<0> = WindowStats::new();
+    /// ```
+    pub fn new() -> Self {
+        const { assert!(SIZE > 0, "a WindowStats requires a SIZE of at least 1") };
+
+        Self {
+            values: List::empty(),
+            pushed: 0,
+            sum: 0.0,
+            mean: 0.0,
+            m2: 0.0,
+            min: MonotonicDeque::new(),
+            max: MonotonicDeque::new(),
+        }
+    }
+
+    /// Pushes a value into the window - returning the value which left the window if the window was full.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::WindowStats;
+    /// let mut window: WindowStats<2> = WindowStats::new();
+    ///
+    /// assert_eq!(window.push(1.0), None);
+    /// assert_eq!(window.push(2.0), None);
+    /// assert_eq!(window.push(3.0), Some(1.0));
+    /// ```
+    pub fn push(&mut self, value: f64) -> Option<f64> {
+        let evicted = self
+            .values
+            .push_back_displacing(value)
+            .expect("write-over List cannot overflow");
+
+        match evicted {
+            // infinity and NaN cannot be subtracted back out of the running statistics
+            Some(evicted) if !evicted.is_finite() => self.recompute(),
+            Some(evicted) => {
+                self.remove(evicted);
+                self.add(value);
+            }
+            None => self.add(value),
+        }
+
+        let seq = self.pushed;
+        self.pushed += 1;
+
+        let oldest = self.pushed.saturating_sub(SIZE as u64);
+        self.min.expire_before(oldest);
+        self.max.expire_before(oldest);
+
+        let value_at = |seq| element_at(&self.values, self.pushed, seq);
+
+        self.min.push(seq, value_at);
+        self.max.push(seq, value_at);
+
+        evicted
+    }
+
+    /// Returns the number of values in the window.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::WindowStats;
+    /// let mut window: WindowStats<2> = WindowStats::new();
+    ///
+    /// window.push(1.0);
+    /// window.push(2.0);
+    /// window.push(3.0);
+    ///
+    /// assert_eq!(window.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the window contains no values.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::WindowStats;
+    /// let window: WindowStats<2> = WindowStats::new();
+    ///
+    /// assert!(window.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.values.len() == 0
+    }
+
+    /// Returns the sum of the values in the window.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::WindowStats;
+    /// let mut window: WindowStats<2> = WindowStats::new();
+    ///
+    /// assert_eq!(window.sum(), 0.0);
+    ///
+    /// window.push(1.0);
+    /// window.push(2.0);
+    /// window.push(3.0);
+    ///
+    /// assert_eq!(window.sum(), 5.0);
+    /// ```
+    pub fn sum(&self) -> f64 {
+        self.sum
+    }
+
+    /// Returns the mean of the values in the window - or `None` if the window is empty.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::WindowStats;
+    /// let mut window: WindowStats<2> = WindowStats::new();
+    ///
+    /// assert_eq!(window.mean(), None);
+    ///
+    /// window.push(1.0);
+    /// window.push(2.0);
+    ///
+    /// assert_eq!(window.mean(), Some(1.5));
+    /// ```
+    pub fn mean(&self) -> Option<f64> {
+        (!self.is_empty()).then_some(self.mean)
+    }
+
+    /// Returns the population variance of the values in the window - or `None` if the window is empty.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::WindowStats;
+    /// let mut window: WindowStats<2> = WindowStats::new();
+    ///
+    /// window.push(1.0);
+    /// window.push(3.0);
+    ///
+    /// assert_eq!(window.variance(), Some(1.0));
+    /// ```
+    pub fn variance(&self) -> Option<f64> {
+        (!self.is_empty()).then(|| self.m2.max(0.0) / self.len() as f64)
+    }
+
+    /// Returns the sample variance of the values in the window - or `None` if the window contains less than two values.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::WindowStats;
+    /// let mut window: WindowStats<2> = WindowStats::new();
+    ///
+    /// window.push(1.0);
+    ///
+    /// assert_eq!(window.sample_variance(), None);
+    ///
+    /// window.push(3.0);
+    ///
+    /// assert_eq!(window.sample_variance(), Some(2.0));
+    /// ```
+    pub fn sample_variance(&self) -> Option<f64> {
+        (self.len() > 1).then(|| self.m2.max(0.0) / (self.len() - 1) as f64)
+    }
+
+    /// Returns the population standard deviation of the values in the window - or `None` if the window is empty.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::WindowStats;
+    /// let mut window: WindowStats<2> = WindowStats::new();
+    ///
+    /// window.push(1.0);
+    /// window.push(5.0);
+    ///
+    /// assert_eq!(window.std_dev(), Some(2.0));
+    /// ```
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    /// Returns the minimum value in the window - or `None` if the window is empty.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::WindowStats;
+    /// let mut window: WindowStats<2> = WindowStats::new();
+    ///
+    /// window.push(1.0);
+    /// window.push(3.0);
+    /// window.push(2.0);
+    ///
+    /// assert_eq!(window.min(), Some(2.0));
+    /// ```
+    pub fn min(&self) -> Option<f64> {
+        self.min
+            .front()
+            .map(|seq| *element_at(&self.values, self.pushed, seq))
+    }
+
+    /// Returns the maximum value in the window - or `None` if the window is empty.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::WindowStats;
+    /// let mut window: WindowStats<2> = WindowStats::new();
+    ///
+    /// window.push(3.0);
+    /// window.push(1.0);
+    /// window.push(2.0);
+    ///
+    /// assert_eq!(window.max(), Some(2.0));
+    /// ```
+    pub fn max(&self) -> Option<f64> {
+        self.max
+            .front()
+            .map(|seq| *element_at(&self.values, self.pushed, seq))
+    }
+
+    /// Returns an iterator over the values in the window - from the oldest to the newest.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::WindowStats;
+    /// let mut window: WindowStats<2> = WindowStats::new();
+    ///
+    /// window.push(1.0);
+    /// window.push(2.0);
+    /// window.push(3.0);
+    ///
+    /// assert_eq!(window.iter().copied().collect::<Vec<f64>>(), vec![2.0, 3.0]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, SIZE, f64, true> {
+        self.values.iter()
+    }
+
+    /// Removes every value from the window - resetting all statistics.
+    ///
+    /// ```
+    /// # use cyclic_data_types::stats::WindowStats;
+    /// let mut window: WindowStats<2> = WindowStats::new();
+    ///
+    /// window.push(1.0);
+    /// window.clear();
+    ///
+    /// assert_eq!(window.sum(), 0.0);
+    /// assert_eq!(window.max(), None);
+    /// ```
+    pub fn clear(&mut self) {
+        while self.values.remove_front().is_some() {}
+
+        self.sum = 0.0;
+        self.mean = 0.0;
+        self.m2 = 0.0;
+        self.min.clear();
+        self.max.clear();
+    }
+
+    /// Adds a value to the running statistics - after it has been pushed to the list
+    fn add(&mut self, value: f64) {
+        let delta = value - self.mean;
+
+        self.sum += value;
+        self.mean += delta / self.len() as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Recomputes the running statistics from the values in the list
+    fn recompute(&mut self) {
+        self.sum = 0.0;
+        self.mean = 0.0;
+        self.m2 = 0.0;
+
+        for (count, value) in self.values.iter().copied().enumerate() {
+            let delta = value - self.mean;
+
+            self.sum += value;
+            self.mean += delta / (count + 1) as f64;
+            self.m2 += delta * (value - self.mean);
+        }
+    }
+
+    /// Removes a value from the running statistics - after it has left the list
+    fn remove(&mut self, value: f64) {
+        let len = self.len() - 1;
+
+        self.sum -= value;
+
+        if len == 0 {
+            self.mean = 0.0;
+            self.m2 = 0.0;
+            return;
+        }
+
+        let delta = value - self.mean;
+
+        self.mean -= delta / len as f64;
+        self.m2 -= delta * (value - self.mean);
+    }
+}
+
+impl<const SIZE: usize> Default for WindowStats<SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const SIZE: usize> Debug for WindowStats<SIZE> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WindowStats")
+            .field("", &self.values)
+            .field("sum", &self.sum)
+            .field("mean", &self.mean())
+            .field("min", &self.min())
+            .field("max", &self.max())
+            .finish()
+    }
+}
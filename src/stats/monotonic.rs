@@ -0,0 +1,79 @@
+use crate::list::List;
+
+/// `MonotonicDeque` keeps the extreme (maximum if `MAX = true`, otherwise minimum) of a sliding window in O(1) amortised time.
+///
+/// The deque only holds the sequence numbers of values - the values themselves are stored once by the window, and are looked up by sequence number. A pushed value removes every value at the back of the deque it dominates - as they can never be the extreme of the window again. Therefore, the values in the deque are sorted from the most to the least extreme, and the front of the deque is the sequence number of the extreme of the window.
+///
+/// The window must never hold more than `SIZE` values. Which is ensured by expiring the values that have left the window before pushing.
+pub(crate) struct MonotonicDeque<const SIZE: usize, const MAX: bool> {
+    list: List<SIZE, u64, false>,
+}
+
+impl<const SIZE: usize, const MAX: bool> MonotonicDeque<SIZE, MAX> {
+    pub(crate) fn new() -> Self {
+        Self {
+            list: List::empty(),
+        }
+    }
+
+    /// Pushes the value with the sequence number `seq` - which must be greater than every sequence number pushed before. `value_at` looks up the value of every sequence number in the window (including `seq`).
+    pub(crate) fn push<'a, T: PartialOrd + 'a>(
+        &mut self,
+        seq: u64,
+        value_at: impl Fn(u64) -> &'a T,
+    ) {
+        let value = value_at(seq);
+
+        while let Some(back) = self.list.get(-1) {
+            let back = value_at(*back);
+            let dominated = match MAX {
+                true => back <= value,
+                false => back >= value,
+            };
+
+            if !dominated {
+                break;
+            }
+
+            self.list.remove_back();
+        }
+
+        // the window holds at most SIZE values - so the deque only fills up if values were never expired
+        if self.list.len() == SIZE {
+            self.list.remove_front();
+        }
+
+        self.list
+            .push_back(seq)
+            .expect("the deque was just made to have space");
+    }
+
+    /// Removes every sequence number less than `seq`
+    pub(crate) fn expire_before(&mut self, seq: u64) {
+        while matches!(self.list.get(0), Some(front) if *front < seq) {
+            self.list.remove_front();
+        }
+    }
+
+    /// Returns the sequence number of the extreme value of the window
+    pub(crate) fn front(&self) -> Option<u64> {
+        self.list.get(0).copied()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        while self.list.remove_front().is_some() {}
+    }
+}
+
+/// Returns the element of a window with the sequence number `seq` - where `window` holds the last elements of the `pushed` elements pushed so far.
+pub(crate) fn element_at<const SIZE: usize, T, const W: bool>(
+    window: &List<SIZE, T, W>,
+    pushed: u64,
+    seq: u64,
+) -> &T {
+    let oldest = pushed - window.len() as u64;
+
+    window
+        .get((seq - oldest) as isize)
+        .expect("the deques only hold sequence numbers in the window")
+}
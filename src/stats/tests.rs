@@ -0,0 +1,277 @@
+mod window_stats {
+    use crate::stats::WindowStats;
+
+    const SIZE: usize = 4;
+
+    /// Recomputes the statistics of the window by iterating over it
+    fn naive(values: &[f64]) -> (f64, f64, f64, f64, f64) {
+        let len = values.len() as f64;
+        let sum: f64 = values.iter().sum();
+        let mean = sum / len;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / len;
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        (sum, mean, variance, min, max)
+    }
+
+    #[test]
+    fn empty() {
+        let window: WindowStats<SIZE> = WindowStats::new();
+
+        assert_eq!(window.len(), 0);
+        assert_eq!(window.sum(), 0.0);
+        assert_eq!(window.mean(), None);
+        assert_eq!(window.variance(), None);
+        assert_eq!(window.sample_variance(), None);
+        assert_eq!(window.min(), None);
+        assert_eq!(window.max(), None);
+    }
+
+    #[test]
+    fn matches_naive() {
+        let mut window: WindowStats<SIZE> = WindowStats::new();
+        let mut pushed = Vec::new();
+
+        // deterministic pseudo random values
+        let mut seed: u64 = 42;
+        for _ in 0..200 {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let value = (seed >> 44) as f64 / 1_000.0 - 250.0;
+
+            let evicted = window.push(value);
+            pushed.push(value);
+
+            if pushed.len() > SIZE {
+                assert_eq!(evicted, Some(pushed[pushed.len() - SIZE - 1]));
+            } else {
+                assert_eq!(evicted, None);
+            }
+
+            let values = &pushed[pushed.len().saturating_sub(SIZE)..];
+            let (sum, mean, variance, min, max) = naive(values);
+
+            assert!((window.sum() - sum).abs() <= 1e-9 * sum.abs().max(1.0));
+            assert!((window.mean().unwrap() - mean).abs() <= 1e-9 * mean.abs().max(1.0));
+            assert!(
+                (window.variance().unwrap() - variance).abs() <= 1e-6 * variance.max(1.0),
+                "{} != {variance}",
+                window.variance().unwrap()
+            );
+            assert_eq!(window.min(), Some(min));
+            assert_eq!(window.max(), Some(max));
+            assert_eq!(window.iter().copied().collect::<Vec<f64>>(), values);
+        }
+    }
+
+    #[test]
+    fn monotonic_sequences() {
+        let mut window: WindowStats<SIZE> = WindowStats::new();
+
+        for i in 0..10usize {
+            window.push(i as f64);
+            assert_eq!(window.max(), Some(i as f64));
+            assert_eq!(window.min(), Some(i.saturating_sub(SIZE - 1) as f64));
+        }
+
+        for i in (0..10).rev() {
+            window.push(i as f64);
+        }
+        assert_eq!(window.min(), Some(0.0));
+        assert_eq!(window.max(), Some(3.0));
+    }
+
+    #[test]
+    fn repeated_values() {
+        let mut window: WindowStats<SIZE> = WindowStats::new();
+
+        for _ in 0..10 {
+            window.push(5.0);
+        }
+
+        assert_eq!(window.sum(), 20.0);
+        assert_eq!(window.mean(), Some(5.0));
+        assert_eq!(window.variance(), Some(0.0));
+        assert_eq!(window.min(), Some(5.0));
+        assert_eq!(window.max(), Some(5.0));
+    }
+
+    #[test]
+    fn single_value_window() {
+        let mut window: WindowStats<1> = WindowStats::new();
+
+        for i in 0..5 {
+            window.push(i as f64);
+
+            assert_eq!(window.sum(), i as f64);
+            assert_eq!(window.mean(), Some(i as f64));
+            assert_eq!(window.variance(), Some(0.0));
+            assert_eq!(window.min(), Some(i as f64));
+            assert_eq!(window.max(), Some(i as f64));
+        }
+    }
+
+    #[test]
+    fn recovers_from_non_finite() {
+        for bad in [f64::INFINITY, f64::NEG_INFINITY, f64::NAN] {
+            let mut window: WindowStats<SIZE> = WindowStats::new();
+
+            window.push(1.0);
+            window.push(bad);
+            assert!(!window.sum().is_finite());
+            assert!(!window.mean().unwrap().is_finite());
+
+            window.push(2.0);
+            window.push(3.0);
+            window.push(4.0);
+            assert!(!window.sum().is_finite());
+
+            // the non-finite value leaves the window
+            window.push(5.0);
+            window.push(6.0);
+
+            assert_eq!(
+                window.iter().copied().collect::<Vec<f64>>(),
+                vec![3.0, 4.0, 5.0, 6.0]
+            );
+            assert_eq!(window.sum(), 18.0);
+            assert_eq!(window.mean(), Some(4.5));
+            assert_eq!(window.variance(), Some(1.25));
+
+            // the statistics keep updating after being recomputed
+            window.push(7.0);
+            assert_eq!(window.sum(), 22.0);
+            assert_eq!(window.mean(), Some(5.5));
+        }
+    }
+
+    #[test]
+    fn clear() {
+        let mut window: WindowStats<SIZE> = WindowStats::new();
+
+        for i in 0..6 {
+            window.push(i as f64);
+        }
+        window.clear();
+
+        assert!(window.is_empty());
+        assert_eq!(window.mean(), None);
+        assert_eq!(window.min(), None);
+
+        window.push(2.0);
+        assert_eq!(window.sum(), 2.0);
+        assert_eq!(window.max(), Some(2.0));
+    }
+}
+
+mod min_max_window {
+    use crate::{error::Error, stats::MinMaxWindow};
+
+    const SIZE: usize = 4;
+
+    #[test]
+    fn matches_naive() {
+        let mut window: MinMaxWindow<SIZE, u64, true> = MinMaxWindow::new();
+        let mut pushed = Vec::new();
+
+        let mut seed: u64 = 7;
+        for _ in 0..200 {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let value = (seed >> 58) % 10;
+
+            assert_eq!(window.push(value), Ok(()));
+            pushed.push(value);
+
+            let values = &pushed[pushed.len().saturating_sub(SIZE)..];
+            assert_eq!(window.min(), values.iter().min());
+            assert_eq!(window.max(), values.iter().max());
+            assert_eq!(window.len(), values.len());
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        let mut window: MinMaxWindow<SIZE, i64, false> = MinMaxWindow::new();
+
+        for i in 0..4 {
+            assert_eq!(window.push(i), Ok(()));
+        }
+        assert_eq!(window.push(4), Err(Error::Overflow));
+        assert_eq!(window.max(), Some(&3));
+
+        window.expire_before(2);
+        assert_eq!(window.len(), 2);
+        assert_eq!(window.min(), Some(&2));
+
+        assert_eq!(window.push(-1), Ok(()));
+        assert_eq!(window.min(), Some(&-1));
+        assert_eq!(window.max(), Some(&3));
+    }
+
+    #[test]
+    fn timestamps() {
+        let mut window: MinMaxWindow<SIZE, &str, true> = MinMaxWindow::new();
+
+        assert!(window.push_at(10, "d").is_ok());
+        assert!(window.push_at(20, "a").is_ok());
+        assert!(window.push_at(20, "c").is_ok());
+        assert!(window.push_at(30, "b").is_ok());
+
+        window.expire_before(15);
+        assert_eq!(window.max(), Some(&"c"));
+        assert_eq!(window.min(), Some(&"a"));
+
+        window.expire_before(21);
+        assert_eq!(window.max(), Some(&"b"));
+        assert_eq!(window.min(), Some(&"b"));
+
+        window.expire_before(31);
+        assert!(window.is_empty());
+        assert_eq!(window.max(), None);
+    }
+
+    #[test]
+    fn write_over_and_expire() {
+        let mut window: MinMaxWindow<SIZE, i64, true> = MinMaxWindow::new();
+
+        for (timestamp, value) in [(1, 9), (2, 1), (3, 5), (4, 3), (5, 4), (6, 2)] {
+            assert!(window.push_at(timestamp, value).is_ok());
+        }
+
+        // the window holds [5, 3, 4, 2]
+        assert_eq!(window.max(), Some(&5));
+        assert_eq!(window.min(), Some(&2));
+
+        window.expire_before(4);
+        assert_eq!(window.max(), Some(&4));
+
+        window.clear();
+        assert_eq!(window.min(), None);
+        assert!(window.push(8).is_ok());
+        assert_eq!(window.min(), Some(&8));
+    }
+
+    #[test]
+    fn values_are_not_cloned() {
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Reading(u64);
+
+        let mut window: MinMaxWindow<SIZE, Reading, true> = MinMaxWindow::new();
+
+        for value in [3, 7, 1, 5, 4] {
+            assert!(window.push(Reading(value)).is_ok());
+        }
+
+        // the window holds [7, 1, 5, 4]
+        assert_eq!(window.min(), Some(&Reading(1)));
+        assert_eq!(window.max(), Some(&Reading(7)));
+
+        window.expire_before(3);
+        assert_eq!(window.min(), Some(&Reading(4)));
+        assert_eq!(window.max(), Some(&Reading(5)));
+    }
+}
@@ -0,0 +1,152 @@
+//! This module contains the [`Storage`] trait. Which separates the ring logic of a cyclic list from the memory its elements are stored in.
+//!
+//! As a result, a [`List`](crate::list::List) can run over an owned array, a boxed slice, a borrowed slice (such as a DMA region or a `static mut` buffer) - with the same `push_back`/`get`/`iter` API.
+//!
+//! Storage is implemented for slots of [`Option<T>`] and [`MaybeUninit<T>`] in the following containers.
+//! * `[_; N]`
+//! * `Box<[_]>`
+//! * `&mut [_]`
+//!
+//! ```
+//! # use cyclic_data_types::list::StorageList;
+//! use std::mem::MaybeUninit;
+//!
+//! let mut buffer: [MaybeUninit<i64>; 3] = [MaybeUninit::uninit(); 3];
+//!
+//! let mut list: StorageList<&mut [MaybeUninit<i64>], i64, true> =
+//!     StorageList::from_storage(&mut buffer[..]).unwrap();
+//!
+//! for i in 1..=4 {
+//!     assert!(list.push_back(i).is_ok());
+//! }
+//!
+//! assert_eq!(list.iter().copied().collect::<Vec<i64>>(), vec![2, 3, 4]);
+//! ```
+
+use std::mem::MaybeUninit;
+
+/// `Slot` defines a single slot of a [`Storage`] - which may or may not hold an element.
+///
+/// The list keeps track of which slots are occupied. Therefore, a slot does not need to know if it holds an element - and is only asked to read from an occupied slot and write to a vacant slot.
+///
+/// # Safety
+/// Implementations must hand back the element last written to the slot.
+pub unsafe trait Slot<T> {
+    /// Returns a reference to the element in the slot.
+    ///
+    /// # Safety
+    /// The slot must be occupied.
+    unsafe fn get(&self) -> &T;
+
+    /// Returns a mutable reference to the element in the slot.
+    ///
+    /// # Safety
+    /// The slot must be occupied.
+    unsafe fn get_mut(&mut self) -> &mut T;
+
+    /// Writes an element to the slot. Making the slot occupied.
+    ///
+    /// # Safety
+    /// The slot must be vacant.
+    unsafe fn write(&mut self, elem: T);
+
+    /// Moves the element out of the slot. Making the slot vacant.
+    ///
+    /// # Safety
+    /// The slot must be occupied.
+    unsafe fn take(&mut self) -> T;
+}
+
+unsafe impl<T> Slot<T> for Option<T> {
+    unsafe fn get(&self) -> &T {
+        self.as_ref().unwrap()
+    }
+
+    unsafe fn get_mut(&mut self) -> &mut T {
+        self.as_mut().unwrap()
+    }
+
+    unsafe fn write(&mut self, elem: T) {
+        *self = Some(elem);
+    }
+
+    unsafe fn take(&mut self) -> T {
+        Option::take(self).unwrap()
+    }
+}
+
+unsafe impl<T> Slot<T> for MaybeUninit<T> {
+    unsafe fn get(&self) -> &T {
+        unsafe { self.assume_init_ref() }
+    }
+
+    unsafe fn get_mut(&mut self) -> &mut T {
+        unsafe { self.assume_init_mut() }
+    }
+
+    unsafe fn write(&mut self, elem: T) {
+        MaybeUninit::write(self, elem);
+    }
+
+    unsafe fn take(&mut self) -> T {
+        unsafe { self.assume_init_read() }
+    }
+}
+
+/// `Storage` defines the slots a cyclic list stores its elements in. The capacity of the list is the number of slots.
+///
+/// Slots are handed out as a contiguous slice - so the list can move elements between slots (such as when sorting) without reading them.
+///
+/// # Safety
+/// Implementations must return the same slots, of the same length, for the lifetime of the storage.
+pub unsafe trait Storage<T> {
+    /// The type of slot an element is stored in
+    type Slot: Slot<T>;
+
+    /// Returns the slots of the storage
+    fn slots(&self) -> &[Self::Slot];
+
+    /// Returns the slots of the storage mutably
+    fn slots_mut(&mut self) -> &mut [Self::Slot];
+
+    /// Returns the number of slots in the storage
+    fn capacity(&self) -> usize {
+        self.slots().len()
+    }
+}
+
+unsafe impl<T, S: Slot<T>, const N: usize> Storage<T> for [S; N] {
+    type Slot = S;
+
+    fn slots(&self) -> &[S] {
+        self
+    }
+
+    fn slots_mut(&mut self) -> &mut [S] {
+        self
+    }
+}
+
+unsafe impl<T, S: Slot<T>> Storage<T> for Box<[S]> {
+    type Slot = S;
+
+    fn slots(&self) -> &[S] {
+        self
+    }
+
+    fn slots_mut(&mut self) -> &mut [S] {
+        self
+    }
+}
+
+unsafe impl<T, S: Slot<T>> Storage<T> for &mut [S] {
+    type Slot = S;
+
+    fn slots(&self) -> &[S] {
+        self
+    }
+
+    fn slots_mut(&mut self) -> &mut [S] {
+        self
+    }
+}
@@ -0,0 +1,137 @@
+use std::{fmt::Debug, time::Duration};
+
+use crate::error::Error;
+
+use super::{Clock, TimeBuckets};
+
+/// `RateLimiter` is a sliding window rate limiter backed by [`TimeBuckets`]. Which allows at most `limit` permits to be acquired within any `window` of time.
+///
+/// The window is split into `BUCKETS` buckets. As a result, permits leave the window one bucket at a time - so more buckets make the window slide more smoothly.
+///
+/// If the clock moves backwards, no time is treated as having elapsed - so permits are neither released early, nor lost.
+///
+/// ```
+/// # use cyclic_data_types::time::{ManualClock, RateLimiter};
+/// # use cyclic_data_types::error::Error;
+/// # use std::time::Duration;
+/// let clock = ManualClock::new();
+/// let mut limiter: RateLimiter<10, _> = RateLimiter::new(2, Duration::from_secs(1), &clock).unwrap();
+///
+/// assert_eq!(limiter.try_acquire(), Ok(()));
+/// assert_eq!(limiter.try_acquire(), Ok(()));
+/// assert_eq!(limiter.try_acquire(), Err(Error::Overflow));
+///
+/// clock.advance(Duration::from_secs(1));
+///
+/// assert_eq!(limiter.try_acquire(), Ok(()));
+/// ```
+pub struct RateLimiter<const BUCKETS: usize, C: Clock> {
+    buckets: TimeBuckets<BUCKETS, C>,
+    limit: u64,
+    window: Duration,
+}
+
+impl<const BUCKETS: usize, C: Clock> RateLimiter<BUCKETS, C> {
+    /// Creates a new rate limiter - allowing `limit` permits within any `window` of time.
+    ///
+    /// ```
+    /// # use cyclic_data_types::time::{MonotonicClock, RateLimiter};
+    /// # use cyclic_data_types::error::Error;
+    /// # use std::time::Duration;
+    /// let limiter: Result<RateLimiter<10, _>, Error> = RateLimiter::new(100, Duration::from_secs(1), MonotonicClock::new());
+    ///
+    /// assert!(limiter.is_ok());
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(RateLimiter)` if the rate limiter was created
+    /// * [`Error::InvalidSize`] if `window` is too small to be split into `BUCKETS` buckets
+    pub fn new(limit: u64, window: Duration, clock: C) -> Result<Self, Error> {
+        let buckets = u32::try_from(BUCKETS).map_err(|_| Error::InvalidSize)?;
+        let width = window.checked_div(buckets).ok_or(Error::InvalidSize)?;
+
+        Ok(Self {
+            buckets: TimeBuckets::new(width, clock)?,
+            limit,
+            window,
+        })
+    }
+
+    /// Acquires a single permit.
+    ///
+    /// ```
+    /// # use cyclic_data_types::time::{ManualClock, RateLimiter};
+    /// # use cyclic_data_types::error::Error;
+    /// # use std::time::Duration;
+    /// let clock = ManualClock::new();
+    /// let mut limiter: RateLimiter<10, _> = RateLimiter::new(1, Duration::from_secs(1), &clock).unwrap();
+    ///
+    /// assert_eq!(limiter.try_acquire(), Ok(()));
+    /// assert_eq!(limiter.try_acquire(), Err(Error::Overflow));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(())` if the permit was acquired
+    /// * [`Error::Overflow`] if acquiring the permit would exceed the limit
+    pub fn try_acquire(&mut self) -> Result<(), Error> {
+        self.try_acquire_n(1)
+    }
+
+    /// Acquires `permits` permits at once - either all permits are acquired, or none are.
+    ///
+    /// ```
+    /// # use cyclic_data_types::time::{ManualClock, RateLimiter};
+    /// # use cyclic_data_types::error::Error;
+    /// # use std::time::Duration;
+    /// let clock = ManualClock::new();
+    /// let mut limiter: RateLimiter<10, _> = RateLimiter::new(5, Duration::from_secs(1), &clock).unwrap();
+    ///
+    /// assert_eq!(limiter.try_acquire_n(3), Ok(()));
+    /// assert_eq!(limiter.try_acquire_n(3), Err(Error::Overflow));
+    /// assert_eq!(limiter.try_acquire_n(2), Ok(()));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(())` if the permits were acquired
+    /// * [`Error::Overflow`] if acquiring the permits would exceed the limit
+    pub fn try_acquire_n(&mut self, permits: u64) -> Result<(), Error> {
+        if permits > self.remaining() {
+            return Err(Error::Overflow);
+        }
+
+        self.buckets.record(permits);
+
+        Ok(())
+    }
+
+    /// Returns the number of permits which can currently be acquired.
+    ///
+    /// ```
+    /// # use cyclic_data_types::time::{ManualClock, RateLimiter};
+    /// # use std::time::Duration;
+    /// let clock = ManualClock::new();
+    /// let mut limiter: RateLimiter<4, _> = RateLimiter::new(4, Duration::from_secs(4), &clock).unwrap();
+    ///
+    /// assert!(limiter.try_acquire_n(3).is_ok());
+    /// assert_eq!(limiter.remaining(), 1);
+    ///
+    /// // every acquired permit has left the window
+    /// clock.advance(Duration::from_secs(4));
+    ///
+    /// assert_eq!(limiter.remaining(), 4);
+    /// ```
+    pub fn remaining(&self) -> u64 {
+        self.limit
+            .saturating_sub(self.buckets.sum_last(self.window))
+    }
+}
+
+impl<const BUCKETS: usize, C: Clock> Debug for RateLimiter<BUCKETS, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RateLimiter")
+            .field("limit", &self.limit)
+            .field("window", &self.window)
+            .field("remaining", &self.remaining())
+            .finish()
+    }
+}
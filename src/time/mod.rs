@@ -0,0 +1,416 @@
+//! The time module contains cyclic data types indexed by time - such as rolling counters and rate limiters.
+//!
+//! Time is read from a [`Clock`]. Which is injected into each type - allowing a [`ManualClock`] to be used where time needs to be deterministic (such as in tests), and a [`MonotonicClock`] to be used otherwise.
+//!
+//! ```
+//! # use cyclic_data_types::time::{ManualClock, TimeBuckets};
+//! # use std::time::Duration;
+//! let clock = ManualClock::new();
+//! let mut requests: TimeBuckets<60, _> = TimeBuckets::new(Duration::from_secs(1), &clock).unwrap();
+//!
+//! requests.record(1);
+//! clock.advance(Duration::from_secs(2));
+//! requests.record(1);
+//!
+//! assert_eq!(requests.sum_last(Duration::from_secs(1)), 1);
+//! assert_eq!(requests.sum_last(Duration::from_secs(60)), 2);
+//! ```
+
+use std::{
+    cell::Cell,
+    fmt::Debug,
+    time::{Duration, Instant},
+};
+
+use crate::{error::Error, list::List};
+
+pub use self::limiter::RateLimiter;
+
+mod limiter;
+
+#[cfg(test)]
+mod tests;
+
+/// `Clock` is the source of time for time indexed data types. The time returned should never decrease - a clock which moves backwards is treated as if no time has elapsed.
+pub trait Clock {
+    /// Returns the time elapsed since the origin of the clock
+    ///
+    /// ```
+    /// # use cyclic_data_types::time::{Clock, ManualClock};
+    /// # use std::time::Duration;
+    /// let clock = ManualClock::new();
+    ///
+    /// clock.advance(Duration::from_secs(1));
+    ///
+    /// assert_eq!(clock.now(), Duration::from_secs(1));
+    /// ```
+    fn now(&self) -> Duration;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> Duration {
+        (**self).now()
+    }
+}
+
+/// `MonotonicClock` reads the time from [`Instant`] - with its origin set to when the clock was created.
+///
+/// ```
+/// # use cyclic_data_types::time::{Clock, MonotonicClock};
+/// let clock = MonotonicClock::new();
+///
+/// let earlier = clock.now();
+///
+/// assert!(clock.now() >= earlier);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct MonotonicClock {
+    origin: Instant,
+}
+
+impl MonotonicClock {
+    /// Creates a new clock - with its origin set to now.
+    ///
+    /// ```
+    /// # use cyclic_data_types::time::{Clock, MonotonicClock};
+    /// # use std::time::Duration;
+    /// let clock = MonotonicClock::new();
+    ///
+    /// assert!(clock.now() < Duration::from_secs(1));
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            origin: Instant::now(),
+        }
+    }
+}
+
+impl Default for MonotonicClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MonotonicClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+}
+
+/// `ManualClock` is a clock which only moves when it is advanced. Allowing time indexed data types to be tested deterministically.
+///
+/// ```
+/// # use cyclic_data_types::time::{Clock, ManualClock};
+/// # use std::time::Duration;
+/// let clock = ManualClock::new();
+///
+/// assert_eq!(clock.now(), Duration::ZERO);
+///
+/// clock.advance(Duration::from_millis(1500));
+///
+/// assert_eq!(clock.now(), Duration::from_millis(1500));
+/// ```
+#[derive(Debug, Default)]
+pub struct ManualClock {
+    now: Cell<Duration>,
+}
+
+impl ManualClock {
+    /// Creates a new clock - starting at [`Duration::ZERO`].
+    ///
+    /// ```
+    /// # use cyclic_data_types::time::{Clock, ManualClock};
+    /// # use std::time::Duration;
+    /// let clock = ManualClock::new();
+    ///
+    /// assert_eq!(clock.now(), Duration::ZERO);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves the clock forward by `duration`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::time::{Clock, ManualClock};
+    /// # use std::time::Duration;
+    /// let clock = ManualClock::new();
+    ///
+    /// clock.advance(Duration::from_secs(2));
+    /// clock.advance(Duration::from_secs(3));
+    ///
+    /// assert_eq!(clock.now(), Duration::from_secs(5));
+    /// ```
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.now.get()
+    }
+}
+
+/// `TimeBuckets` is a write over ring of `BUCKETS` counters - where each counter (bucket) covers a fixed width of time. Timestamps are mapped to the bucket covering them, so the ring always holds the last `BUCKETS * width` of time.
+///
+/// As time moves forward, the ring advances to the bucket of the latest timestamp - writing over the oldest buckets and zeroing any buckets that were skipped.
+///
+/// ```
+/// # use cyclic_data_types::time::{ManualClock, TimeBuckets};
+/// # use std::time::Duration;
+/// let clock = ManualClock::new();
+/// let mut buckets: TimeBuckets<4, _> = TimeBuckets::new(Duration::from_secs(1), &clock).unwrap();
+///
+/// buckets.record(3);
+///
+/// clock.advance(Duration::from_secs(1));
+/// buckets.record(2);
+///
+/// clock.advance(Duration::from_secs(4));
+/// buckets.record(1);
+///
+/// // the first two seconds have left the ring
+/// assert_eq!(buckets.iter().copied().collect::<Vec<u64>>(), vec![0, 0, 0, 1]);
+/// ```
+pub struct TimeBuckets<const BUCKETS: usize, C: Clock> {
+    buckets: List<BUCKETS, u64, true>,
+    width: Duration,
+    current: u64,
+    clock: C,
+}
+
+impl<const BUCKETS: usize, C: Clock> TimeBuckets<BUCKETS, C> {
+    /// Creates a new ring of zeroed buckets - each covering `width` of time. The newest bucket covers the current time of `clock`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::time::{MonotonicClock, TimeBuckets};
+    /// # use cyclic_data_types::error::Error;
+    /// # use std::time::Duration;
+    /// let buckets: Result<TimeBuckets<60, _>, Error> = TimeBuckets::new(Duration::from_secs(1), MonotonicClock::new());
+    ///
+    /// assert!(buckets.is_ok());
+    ///
+    /// let buckets: Result<TimeBuckets<60, _>, Error> = TimeBuckets::new(Duration::ZERO, MonotonicClock::new());
+    ///
+    /// assert!(matches!(buckets, Err(Error::InvalidSize)));
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(TimeBuckets)` if the ring was created
+    /// * [`Error::InvalidSize`] if `width` is zero or `BUCKETS` is zero
+    pub fn new(width: Duration, clock: C) -> Result<Self, Error> {
+        if width.is_zero() || BUCKETS == 0 {
+            return Err(Error::InvalidSize);
+        }
+
+        let mut buckets = List::empty();
+        for _ in 0..BUCKETS {
+            buckets.push_back(0)?;
+        }
+
+        let mut time_buckets = Self {
+            buckets,
+            width,
+            current: 0,
+            clock,
+        };
+        time_buckets.current = time_buckets.bucket_of(time_buckets.clock.now());
+
+        Ok(time_buckets)
+    }
+
+    /// Adds `count` to the bucket covering the current time of the clock.
+    ///
+    /// ```
+    /// # use cyclic_data_types::time::{ManualClock, TimeBuckets};
+    /// # use std::time::Duration;
+    /// let clock = ManualClock::new();
+    /// let mut buckets: TimeBuckets<4, _> = TimeBuckets::new(Duration::from_secs(1), &clock).unwrap();
+    ///
+    /// buckets.record(2);
+    /// buckets.record(3);
+    ///
+    /// assert_eq!(buckets.sum_last(Duration::from_secs(1)), 5);
+    /// ```
+    ///
+    /// *NOTE:* If the clock is behind the newest bucket (because [`TimeBuckets::record_at`] was given a timestamp ahead of the clock, or the clock moved backwards) - `count` is added to the newest bucket instead.
+    ///
+    /// ```
+    /// # use cyclic_data_types::time::{ManualClock, TimeBuckets};
+    /// # use std::time::Duration;
+    /// let clock = ManualClock::new();
+    /// let mut buckets: TimeBuckets<2, _> = TimeBuckets::new(Duration::from_secs(1), &clock).unwrap();
+    ///
+    /// assert!(buckets.record_at(Duration::from_secs(5), 1).is_ok());
+    /// buckets.record(2);
+    ///
+    /// assert_eq!(buckets.iter().copied().collect::<Vec<u64>>(), vec![0, 3]);
+    /// ```
+    pub fn record(&mut self, count: u64) {
+        let bucket = self.bucket_of(self.clock.now());
+        self.advance_to(bucket);
+
+        if let Some(elem) = self.buckets.get_mut(-1) {
+            *elem += count;
+        }
+    }
+
+    /// Adds `count` to the bucket covering `timestamp`. Advancing the ring if `timestamp` is after the newest bucket.
+    ///
+    /// ```
+    /// # use cyclic_data_types::time::{ManualClock, TimeBuckets};
+    /// # use cyclic_data_types::error::Error;
+    /// # use std::time::Duration;
+    /// let clock = ManualClock::new();
+    /// let mut buckets: TimeBuckets<2, _> = TimeBuckets::new(Duration::from_secs(1), &clock).unwrap();
+    ///
+    /// assert_eq!(buckets.record_at(Duration::from_secs(5), 1), Ok(()));
+    /// assert_eq!(buckets.record_at(Duration::from_secs(4), 1), Ok(()));
+    /// assert_eq!(buckets.record_at(Duration::from_secs(3), 1), Err(Error::Overwritten));
+    ///
+    /// assert_eq!(buckets.iter().copied().collect::<Vec<u64>>(), vec![1, 1]);
+    /// ```
+    ///
+    /// # Returns
+    /// * `Ok(())` if the count was added
+    /// * [`Error::Overwritten`] if the bucket covering `timestamp` has already left the ring
+    pub fn record_at(&mut self, timestamp: Duration, count: u64) -> Result<(), Error> {
+        let bucket = self.bucket_of(timestamp);
+        self.advance_to(bucket);
+
+        let offset = (self.current - bucket) as usize;
+        if offset >= BUCKETS {
+            return Err(Error::Overwritten);
+        }
+
+        if let Some(elem) = self.buckets.get_mut(-1 - offset as isize) {
+            *elem += count;
+        }
+
+        Ok(())
+    }
+
+    /// Moves the ring forward to the current time of the clock - zeroing every bucket that was skipped.
+    ///
+    /// ```
+    /// # use cyclic_data_types::time::{ManualClock, TimeBuckets};
+    /// # use std::time::Duration;
+    /// let clock = ManualClock::new();
+    /// let mut buckets: TimeBuckets<3, _> = TimeBuckets::new(Duration::from_secs(1), &clock).unwrap();
+    ///
+    /// buckets.record(1);
+    /// clock.advance(Duration::from_secs(2));
+    /// buckets.advance();
+    ///
+    /// assert_eq!(buckets.iter().copied().collect::<Vec<u64>>(), vec![1, 0, 0]);
+    /// ```
+    pub fn advance(&mut self) {
+        let bucket = self.bucket_of(self.clock.now());
+
+        self.advance_to(bucket);
+    }
+
+    /// Returns the sum of the buckets covering the last `duration` of time (rounded up to whole buckets) - up to the current time of the clock.
+    ///
+    /// ```
+    /// # use cyclic_data_types::time::{ManualClock, TimeBuckets};
+    /// # use std::time::Duration;
+    /// let clock = ManualClock::new();
+    /// let mut buckets: TimeBuckets<10, _> = TimeBuckets::new(Duration::from_secs(1), &clock).unwrap();
+    ///
+    /// for _ in 0..5 {
+    ///     buckets.record(1);
+    ///     clock.advance(Duration::from_secs(1));
+    /// }
+    ///
+    /// assert_eq!(buckets.sum_last(Duration::from_secs(3)), 2);
+    /// assert_eq!(buckets.sum_last(Duration::from_secs(10)), 5);
+    /// ```
+    pub fn sum_last(&self, duration: Duration) -> u64 {
+        self.sum_last_at(self.clock.now(), duration)
+    }
+
+    /// Returns the sum of the buckets covering the last `duration` of time (rounded up to whole buckets) - up to `timestamp`.
+    ///
+    /// ```
+    /// # use cyclic_data_types::time::{ManualClock, TimeBuckets};
+    /// # use std::time::Duration;
+    /// let clock = ManualClock::new();
+    /// let mut buckets: TimeBuckets<10, _> = TimeBuckets::new(Duration::from_secs(1), &clock).unwrap();
+    ///
+    /// assert!(buckets.record_at(Duration::from_secs(1), 1).is_ok());
+    /// assert!(buckets.record_at(Duration::from_secs(2), 1).is_ok());
+    ///
+    /// assert_eq!(buckets.sum_last_at(Duration::from_secs(2), Duration::from_secs(1)), 1);
+    /// assert_eq!(buckets.sum_last_at(Duration::from_secs(3), Duration::from_secs(1)), 0);
+    /// ```
+    pub fn sum_last_at(&self, timestamp: Duration, duration: Duration) -> u64 {
+        let skipped = self.bucket_of(timestamp).saturating_sub(self.current);
+        let covered = duration.as_nanos().div_ceil(self.width.as_nanos()) as u64;
+
+        // the ring always holds BUCKETS buckets - so the newest buckets are skipped into from the front
+        let newest = covered.saturating_sub(skipped).min(BUCKETS as u64) as usize;
+
+        self.buckets.iter().skip(BUCKETS - newest).sum()
+    }
+
+    /// Returns the width of time covered by each bucket.
+    ///
+    /// ```
+    /// # use cyclic_data_types::time::{MonotonicClock, TimeBuckets};
+    /// # use std::time::Duration;
+    /// let buckets: TimeBuckets<60, _> = TimeBuckets::new(Duration::from_secs(1), MonotonicClock::new()).unwrap();
+    ///
+    /// assert_eq!(buckets.width(), Duration::from_secs(1));
+    /// ```
+    pub fn width(&self) -> Duration {
+        self.width
+    }
+
+    /// Returns an iterator over the buckets - from the oldest to the newest.
+    ///
+    /// ```
+    /// # use cyclic_data_types::time::{ManualClock, TimeBuckets};
+    /// # use std::time::Duration;
+    /// let clock = ManualClock::new();
+    /// let mut buckets: TimeBuckets<3, _> = TimeBuckets::new(Duration::from_secs(1), &clock).unwrap();
+    ///
+    /// buckets.record(1);
+    ///
+    /// assert_eq!(buckets.iter().copied().collect::<Vec<u64>>(), vec![0, 0, 1]);
+    /// ```
+    pub fn iter(&self) -> crate::list::Iter<'_, BUCKETS, u64, true> {
+        self.buckets.iter()
+    }
+
+    /// Returns the index of the bucket covering `timestamp`
+    fn bucket_of(&self, timestamp: Duration) -> u64 {
+        (timestamp.as_nanos() / self.width.as_nanos()) as u64
+    }
+
+    /// Advances the newest bucket to `bucket` - pushing a zeroed bucket for every bucket skipped
+    fn advance_to(&mut self, bucket: u64) {
+        if bucket <= self.current {
+            return;
+        }
+
+        for _ in 0..(bucket - self.current).min(BUCKETS as u64) {
+            self.buckets
+                .push_back(0)
+                .expect("write-over List cannot overflow");
+        }
+
+        self.current = bucket;
+    }
+}
+
+impl<const BUCKETS: usize, C: Clock> Debug for TimeBuckets<BUCKETS, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TimeBuckets")
+            .field("", &self.buckets)
+            .field("width", &self.width)
+            .field("current", &self.current)
+            .finish()
+    }
+}
@@ -0,0 +1,232 @@
+mod time_buckets {
+    use std::time::Duration;
+
+    use crate::{
+        error::Error,
+        time::{ManualClock, TimeBuckets},
+    };
+
+    const BUCKETS: usize = 4;
+    const SECOND: Duration = Duration::from_secs(1);
+
+    fn buckets(clock: &ManualClock) -> TimeBuckets<BUCKETS, &ManualClock> {
+        TimeBuckets::new(SECOND, clock).unwrap()
+    }
+
+    #[test]
+    fn invalid_width() {
+        let clock = ManualClock::new();
+
+        assert!(matches!(
+            TimeBuckets::<BUCKETS, _>::new(Duration::ZERO, &clock),
+            Err(Error::InvalidSize)
+        ));
+        assert!(matches!(
+            TimeBuckets::<0, _>::new(SECOND, &clock),
+            Err(Error::InvalidSize)
+        ));
+    }
+
+    #[test]
+    fn record() {
+        let clock = ManualClock::new();
+        let mut buckets = buckets(&clock);
+
+        for i in 0..4 {
+            buckets.record(i + 1);
+            clock.advance(SECOND);
+        }
+
+        assert_eq!(
+            buckets.iter().copied().collect::<Vec<u64>>(),
+            vec![1, 2, 3, 4]
+        );
+
+        // part way through a bucket
+        clock.advance(Duration::from_millis(500));
+        buckets.record(5);
+        assert_eq!(
+            buckets.iter().copied().collect::<Vec<u64>>(),
+            vec![2, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn zero_skipped_buckets() {
+        let clock = ManualClock::new();
+        let mut buckets = buckets(&clock);
+
+        for _ in 0..4 {
+            buckets.record(1);
+            clock.advance(SECOND);
+        }
+
+        clock.advance(SECOND);
+        buckets.record(7);
+        assert_eq!(
+            buckets.iter().copied().collect::<Vec<u64>>(),
+            vec![1, 1, 0, 7]
+        );
+
+        clock.advance(SECOND * 100);
+        buckets.advance();
+        assert_eq!(
+            buckets.iter().copied().collect::<Vec<u64>>(),
+            vec![0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn sum_last() {
+        let clock = ManualClock::new();
+        let mut buckets = buckets(&clock);
+
+        for i in 0..4 {
+            buckets.record(i + 1);
+            clock.advance(SECOND);
+        }
+        clock.advance(Duration::from_millis(10));
+
+        // the current bucket has not been recorded to yet
+        assert_eq!(buckets.sum_last(SECOND), 0);
+        assert_eq!(buckets.sum_last(SECOND * 2), 4);
+        assert_eq!(buckets.sum_last(Duration::from_millis(1500)), 4);
+        assert_eq!(buckets.sum_last(SECOND * 4), 4 + 3 + 2);
+        assert_eq!(buckets.sum_last(SECOND * 100), 4 + 3 + 2 + 1);
+
+        clock.advance(SECOND * 2);
+        assert_eq!(buckets.sum_last(SECOND * 4), 4);
+
+        clock.advance(SECOND);
+        assert_eq!(buckets.sum_last(SECOND * 4), 0);
+        assert_eq!(buckets.sum_last(Duration::ZERO), 0);
+    }
+
+    #[test]
+    fn record_at() {
+        let clock = ManualClock::new();
+        let mut buckets = buckets(&clock);
+
+        assert_eq!(buckets.record_at(SECOND * 10, 1), Ok(()));
+        assert_eq!(buckets.record_at(SECOND * 7, 1), Ok(()));
+        assert_eq!(buckets.record_at(SECOND * 6, 1), Err(Error::Overwritten));
+
+        assert_eq!(
+            buckets.iter().copied().collect::<Vec<u64>>(),
+            vec![1, 0, 0, 1]
+        );
+        assert_eq!(buckets.sum_last_at(SECOND * 10, SECOND * 4), 2);
+        assert_eq!(buckets.sum_last_at(SECOND * 12, SECOND * 4), 1);
+    }
+
+    #[test]
+    fn record_behind_newest_bucket() {
+        let clock = ManualClock::new();
+        let mut buckets = buckets(&clock);
+
+        // the ring has moved further ahead of the clock than it can hold
+        assert_eq!(buckets.record_at(SECOND * 10, 1), Ok(()));
+        buckets.record(2);
+
+        assert_eq!(
+            buckets.iter().copied().collect::<Vec<u64>>(),
+            vec![0, 0, 0, 3]
+        );
+
+        // the clock catches up with the ring
+        clock.advance(SECOND * 11);
+        buckets.record(1);
+
+        assert_eq!(
+            buckets.iter().copied().collect::<Vec<u64>>(),
+            vec![0, 0, 3, 1]
+        );
+    }
+}
+
+mod rate_limiter {
+    use std::{cell::Cell, time::Duration};
+
+    use crate::{
+        error::Error,
+        time::{Clock, ManualClock, RateLimiter},
+    };
+
+    /// A clock which can be set to any time - including backwards
+    #[derive(Default)]
+    struct SetClock(Cell<Duration>);
+
+    impl Clock for SetClock {
+        fn now(&self) -> Duration {
+            self.0.get()
+        }
+    }
+
+    #[test]
+    fn invalid_window() {
+        let clock = ManualClock::new();
+
+        assert!(matches!(
+            RateLimiter::<10, _>::new(1, Duration::ZERO, &clock),
+            Err(Error::InvalidSize)
+        ));
+        assert!(matches!(
+            RateLimiter::<10, _>::new(1, Duration::from_nanos(5), &clock),
+            Err(Error::InvalidSize)
+        ));
+    }
+
+    #[test]
+    fn sliding_window() {
+        let clock = ManualClock::new();
+        let mut limiter: RateLimiter<4, _> =
+            RateLimiter::new(4, Duration::from_secs(4), &clock).unwrap();
+
+        assert_eq!(limiter.try_acquire_n(2), Ok(()));
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(limiter.try_acquire_n(2), Ok(()));
+        assert_eq!(limiter.try_acquire(), Err(Error::Overflow));
+        assert_eq!(limiter.remaining(), 0);
+
+        // the first two permits are still within the window
+        clock.advance(Duration::from_secs(2));
+        assert_eq!(limiter.try_acquire(), Err(Error::Overflow));
+
+        // the first two permits have left the window
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(limiter.remaining(), 2);
+        assert_eq!(limiter.try_acquire(), Ok(()));
+        assert_eq!(limiter.try_acquire(), Ok(()));
+        assert_eq!(limiter.try_acquire(), Err(Error::Overflow));
+    }
+
+    #[test]
+    fn all_or_nothing() {
+        let clock = ManualClock::new();
+        let mut limiter: RateLimiter<10, _> =
+            RateLimiter::new(5, Duration::from_secs(1), &clock).unwrap();
+
+        assert_eq!(limiter.try_acquire_n(6), Err(Error::Overflow));
+        assert_eq!(limiter.remaining(), 5);
+        assert_eq!(limiter.try_acquire_n(5), Ok(()));
+    }
+
+    #[test]
+    fn clock_moves_backwards() {
+        let clock = SetClock::default();
+        let mut limiter: RateLimiter<4, _> =
+            RateLimiter::new(4, Duration::from_secs(4), &clock).unwrap();
+
+        clock.0.set(Duration::from_secs(10));
+        assert_eq!(limiter.try_acquire_n(2), Ok(()));
+
+        // no time has elapsed - so the permits are still within the window
+        clock.0.set(Duration::ZERO);
+        assert_eq!(limiter.remaining(), 2);
+        assert_eq!(limiter.try_acquire_n(2), Ok(()));
+        assert_eq!(limiter.try_acquire(), Err(Error::Overflow));
+
+        clock.0.set(Duration::from_secs(14));
+        assert_eq!(limiter.remaining(), 4);
+    }
+}
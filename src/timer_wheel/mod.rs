@@ -0,0 +1,356 @@
+//! The timer_wheel module contains a hierarchical timing wheel. Which schedules items to expire after a delay (measured in ticks) - with O(`LEVELS`) scheduling, and O(`CAP`) cancelling and work per tick.
+//!
+//! ```
+//! # use cyclic_data_types::timer_wheel::TimerWheel;
+//! let mut wheel: TimerWheel<8, 4, &str, 2> = TimerWheel::new();
+//!
+//! let retry = wheel.schedule(2, "retry").unwrap();
+//! let timeout = wheel.schedule(30, "timeout").unwrap();
+//!
+//! assert_eq!(wheel.tick().len(), 0);
+//! assert_eq!(wheel.tick().iter().copied().collect::<Vec<&str>>(), vec!["retry"]);
+//!
+//! assert_eq!(wheel.cancel(timeout), Some("timeout"));
+//! assert_eq!(wheel.cancel(retry), None);
+//! ```
+
+use std::{array, fmt::Debug, mem};
+
+use crate::{error::Error, list::List};
+
+#[cfg(test)]
+mod tests;
+
+struct Entry<T> {
+    id: u64,
+    deadline: u64,
+    item: T,
+}
+
+/// `TimerHandle` identifies a scheduled item - allowing the item to be cancelled using [`TimerWheel::cancel`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimerHandle {
+    id: u64,
+    deadline: u64,
+}
+
+impl TimerHandle {
+    /// Returns the tick the item expires on.
+    ///
+    /// ```
+    /// # use cyclic_data_types::timer_wheel::TimerWheel;
+    /// let mut wheel: TimerWheel<8, 4, i64, 1> = TimerWheel::new();
+    ///
+    /// wheel.tick();
+    /// let handle = wheel.schedule(3, 1).unwrap();
+    ///
+    /// assert_eq!(handle.deadline(), 4);
+    /// ```
+    pub fn deadline(&self) -> u64 {
+        self.deadline
+    }
+}
+
+/// `TimerWheel` is a hierarchical timing wheel - made of `LEVELS` wheels, each with `SLOTS` slots. Where each slot is a cyclic [`List`] holding at most `CAP` items.
+///
+/// # Generics
+///
+/// 1. `const SLOTS: usize`
+///
+/// SLOTS is the number of slots in each wheel. A slot of the first wheel covers a single tick, while a slot of each following (overflow) wheel covers a full rotation of the wheel before it. Therefore, the wheels can schedule a delay of up to `SLOTS.pow(LEVELS) - 1` ticks.
+///
+/// 2. `const CAP: usize`
+///
+/// CAP is the maximum number of items held by a single slot.
+///
+/// 3. `T: Sized`
+///
+/// T is the type of item scheduled.
+///
+/// 4. `const LEVELS: usize`
+///
+/// LEVELS is the number of wheels. Items scheduled beyond the first wheel's rotation are held in the overflow wheels - and cascade down to the lower wheels as their expiry approaches.
+///
+/// *NOTE:* A slot of an overflow wheel is only cascaded once every lower wheel has emptied - and its items are spread across the (then empty) slots below it. As a result, cascading never overflows a slot, and a single tick never expires more than `CAP` items.
+///
+/// ```
+/// # use cyclic_data_types::timer_wheel::TimerWheel;
+/// let mut wheel: TimerWheel<4, 2, i64, 3> = TimerWheel::new();
+///
+/// assert!(wheel.schedule(1, 1).is_ok());
+/// assert!(wheel.schedule(10, 10).is_ok());
+/// assert!(wheel.schedule(63, 63).is_ok());
+///
+/// let mut expired = Vec::new();
+/// for _ in 0..63 {
+///     expired.extend(wheel.tick().iter().copied());
+/// }
+///
+/// assert_eq!(expired, vec![1, 10, 63]);
+/// ```
+pub struct TimerWheel<const SLOTS: usize, const CAP: usize, T, const LEVELS: usize> {
+    levels: [[List<CAP, Entry<T>, false>; SLOTS]; LEVELS],
+    now: u64,
+    next_id: u64,
+    len: usize,
+}
+
+impl<const SLOTS: usize, const CAP: usize, T, const LEVELS: usize>
+    TimerWheel<SLOTS, CAP, T, LEVELS>
+{
+    /// Creates an empty timer wheel - starting at tick 0.
+    ///
+    /// ```
+    /// # use cyclic_data_types::timer_wheel::TimerWheel;
+    /// let wheel: TimerWheel<8, 4, i64, 2> = TimerWheel::new();
+    ///
+    /// assert_eq!(wheel.now(), 0);
+    /// assert!(wheel.is_empty());
+    /// ```
+    ///
+    /// A wheel must have at least one slot and one level - which is checked at compile time.
+    ///
+    /// ```compile_fail
+    /// # use cyclic_data_types::timer_wheel::TimerWheel;
+    /// let wheel: TimerWheel<8, 4, i64, 0> = TimerWheel::new();
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use cyclic_data_types::timer_wheel::TimerWheel;
+    /// let wheel: TimerWheel<0, 4, i64, 2> = TimerWheel::new();
+    /// ```
+    pub fn new() -> Self {
+        const {
+            assert!(
+                SLOTS > 0 && LEVELS > 0,
+                "a TimerWheel requires at least one slot and one level"
+            )
+        };
+
+        Self {
+            levels: array::from_fn(|_| array::from_fn(|_| List::empty())),
+            now: 0,
+            next_id: 0,
+            len: 0,
+        }
+    }
+
+    /// Schedules an item to expire after `delay` ticks. A delay of 0 is treated as a delay of 1 - so the item expires on the next tick.
+    ///
+    /// ```
+    /// # use cyclic_data_types::timer_wheel::TimerWheel;
+    /// # use cyclic_data_types::error::Error;
+    /// let mut wheel: TimerWheel<4, 1, i64, 2> = TimerWheel::new();
+    ///
+    /// assert!(wheel.schedule(1, 1).is_ok());
+    /// assert!(wheel.schedule(15, 15).is_ok());
+    ///
+    /// // the slot of the first tick is full
+    /// assert_eq!(wheel.schedule(1, 2), Err(Error::Overflow));
+    /// // the delay is longer than the wheels can hold
+    /// assert_eq!(wheel.schedule(16, 16), Err(Error::Overflow));
+    /// ```
+    ///
+    /// # Returns
+    /// * A [`TimerHandle`] to the scheduled item
+    /// * [`Error::Overflow`] if the slot of the item is full, or the delay is longer than the wheels can hold
+    pub fn schedule(&mut self, delay: u64, item: T) -> Result<TimerHandle, Error> {
+        let deadline = self.now.checked_add(delay.max(1)).ok_or(Error::Overflow)?;
+        let handle = TimerHandle {
+            id: self.next_id,
+            deadline,
+        };
+
+        self.place(Entry {
+            id: handle.id,
+            deadline,
+            item,
+        })?;
+
+        self.next_id += 1;
+        self.len += 1;
+
+        Ok(handle)
+    }
+
+    /// Cancels a scheduled item - returning the item if it has not yet expired or been cancelled.
+    ///
+    /// The deadline of the handle locates the slot holding the item, and the slot is scanned for the item. Therefore, cancelling is O(`CAP`).
+    ///
+    /// ```
+    /// # use cyclic_data_types::timer_wheel::TimerWheel;
+    /// let mut wheel: TimerWheel<8, 4, i64, 2> = TimerWheel::new();
+    ///
+    /// let handle = wheel.schedule(20, 1).unwrap();
+    ///
+    /// assert_eq!(wheel.cancel(handle), Some(1));
+    /// assert_eq!(wheel.cancel(handle), None);
+    /// ```
+    pub fn cancel(&mut self, handle: TimerHandle) -> Option<T> {
+        let (level, slot) = self.slot_of(handle.deadline)?;
+        let slot = &mut self.levels[level][slot];
+
+        let index = slot.iter().position(|entry| entry.id == handle.id)?;
+
+        // the order of a slot does not matter - so the last entry is swapped into the cancelled entry's place
+        let last = slot.remove_back()?;
+        let entry = match index == slot.len() {
+            true => last,
+            false => mem::replace(&mut slot[index], last),
+        };
+
+        self.len -= 1;
+
+        Some(entry.item)
+    }
+
+    /// Moves the wheel forward by a single tick - returning every item which expires on the new tick.
+    ///
+    /// ```
+    /// # use cyclic_data_types::timer_wheel::TimerWheel;
+    /// let mut wheel: TimerWheel<8, 4, i64, 2> = TimerWheel::new();
+    ///
+    /// assert!(wheel.schedule(1, 1).is_ok());
+    /// assert!(wheel.schedule(1, 2).is_ok());
+    ///
+    /// let expired = wheel.tick();
+    ///
+    /// assert_eq!(expired.iter().copied().collect::<Vec<i64>>(), vec![1, 2]);
+    /// assert_eq!(wheel.now(), 1);
+    /// ```
+    pub fn tick(&mut self) -> List<CAP, T, false> {
+        self.now += 1;
+
+        let mut expired = List::empty();
+
+        // cascade the overflow wheels whose slot has changed - from the highest wheel down
+        for level in (1..LEVELS).rev() {
+            let Some(span) = Self::span(level) else {
+                continue;
+            };
+
+            if !self.now.is_multiple_of(span) {
+                continue;
+            }
+
+            let slot = ((self.now / span) % SLOTS as u64) as usize;
+            while let Some(entry) = self.levels[level][slot].remove_front() {
+                match entry.deadline == self.now {
+                    true => expired
+                        .push_back(entry.item)
+                        .map(|_| ())
+                        .expect("a tick never expires more than CAP items"),
+                    false => self
+                        .place(entry)
+                        .expect("cascaded items are spread across empty slots"),
+                };
+            }
+        }
+
+        let slot = (self.now % SLOTS as u64) as usize;
+        while let Some(entry) = self.levels[0][slot].remove_front() {
+            expired
+                .push_back(entry.item)
+                .expect("a tick never expires more than CAP items");
+        }
+
+        self.len -= expired.len();
+
+        expired
+    }
+
+    /// Returns the current tick of the wheel.
+    ///
+    /// ```
+    /// # use cyclic_data_types::timer_wheel::TimerWheel;
+    /// let mut wheel: TimerWheel<8, 4, i64, 2> = TimerWheel::new();
+    ///
+    /// wheel.tick();
+    /// wheel.tick();
+    ///
+    /// assert_eq!(wheel.now(), 2);
+    /// ```
+    pub fn now(&self) -> u64 {
+        self.now
+    }
+
+    /// Returns the number of scheduled items.
+    ///
+    /// ```
+    /// # use cyclic_data_types::timer_wheel::TimerWheel;
+    /// let mut wheel: TimerWheel<8, 4, i64, 2> = TimerWheel::new();
+    ///
+    /// assert!(wheel.schedule(1, 1).is_ok());
+    /// assert!(wheel.schedule(40, 2).is_ok());
+    ///
+    /// assert_eq!(wheel.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no items are scheduled.
+    ///
+    /// ```
+    /// # use cyclic_data_types::timer_wheel::TimerWheel;
+    /// let wheel: TimerWheel<8, 4, i64, 2> = TimerWheel::new();
+    ///
+    /// assert!(wheel.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of ticks covered by a single slot of the wheel at `level` - or `None` if the span does not fit into a `u64`
+    fn span(level: usize) -> Option<u64> {
+        (SLOTS as u64).checked_pow(u32::try_from(level).ok()?)
+    }
+
+    /// Returns the wheel and slot which currently hold an item expiring on `deadline`. The item is held by the lowest wheel which can hold `deadline`.
+    fn slot_of(&self, deadline: u64) -> Option<(usize, usize)> {
+        if deadline <= self.now {
+            return None;
+        }
+
+        (0..LEVELS).find_map(|level| {
+            let span = Self::span(level)?;
+
+            // the highest wheel holds any deadline within a single rotation of the current tick - while the lower wheels only hold the rotation of the current tick
+            let held = match (Self::span(level + 1), level + 1 == LEVELS) {
+                (Some(rotation), true) => deadline - self.now < rotation,
+                (Some(rotation), false) => deadline / rotation == self.now / rotation,
+                (None, _) => true,
+            };
+
+            held.then(|| (level, ((deadline / span) % SLOTS as u64) as usize))
+        })
+    }
+
+    /// Places an entry into the slot which should hold it - returning [`Error::Overflow`] if the slot is full or the deadline is beyond the wheels
+    fn place(&mut self, entry: Entry<T>) -> Result<(), Error> {
+        let (level, slot) = self.slot_of(entry.deadline).ok_or(Error::Overflow)?;
+
+        self.levels[level][slot].push_back(entry)?;
+
+        Ok(())
+    }
+}
+
+impl<const SLOTS: usize, const CAP: usize, T, const LEVELS: usize> Default
+    for TimerWheel<SLOTS, CAP, T, LEVELS>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const SLOTS: usize, const CAP: usize, T, const LEVELS: usize> Debug
+    for TimerWheel<SLOTS, CAP, T, LEVELS>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TimerWheel")
+            .field("now", &self.now)
+            .field("size", &self.len)
+            .finish()
+    }
+}
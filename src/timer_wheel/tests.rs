@@ -0,0 +1,126 @@
+mod timer_wheel {
+    use crate::{error::Error, timer_wheel::TimerWheel};
+
+    const SLOTS: usize = 4;
+    const CAP: usize = 2;
+    const LEVELS: usize = 3;
+
+    type Wheel = TimerWheel<SLOTS, CAP, u64, LEVELS>;
+
+    fn run(wheel: &mut Wheel, ticks: u64) -> Vec<(u64, u64)> {
+        let mut expired = Vec::new();
+
+        for _ in 0..ticks {
+            let now = wheel.now() + 1;
+            expired.extend(wheel.tick().iter().map(|item| (now, *item)));
+        }
+
+        expired
+    }
+
+    #[test]
+    fn expires_on_deadline() {
+        let mut wheel = Wheel::new();
+
+        for delay in [1, 3, 4, 5, 15, 16, 17, 40, 63] {
+            assert!(wheel.schedule(delay, delay).is_ok());
+        }
+
+        let expired = run(&mut wheel, 64);
+
+        assert_eq!(
+            expired,
+            vec![1, 3, 4, 5, 15, 16, 17, 40, 63]
+                .into_iter()
+                .map(|delay| (delay, delay))
+                .collect::<Vec<(u64, u64)>>()
+        );
+        assert!(wheel.is_empty());
+    }
+
+    #[test]
+    fn expires_from_every_tick() {
+        // scheduling from every tick crosses every cascade of the overflow wheels
+        for start in 0..64 {
+            for delay in 1..64 {
+                let mut wheel = Wheel::new();
+                run(&mut wheel, start);
+
+                let handle = wheel.schedule(delay, delay).unwrap();
+
+                assert_eq!(handle.deadline(), start + delay);
+                assert_eq!(
+                    run(&mut wheel, delay + 1),
+                    vec![(start + delay, delay)],
+                    "start {start} delay {delay}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn zero_delay() {
+        let mut wheel = Wheel::new();
+
+        assert!(wheel.schedule(0, 7).is_ok());
+
+        assert_eq!(run(&mut wheel, 1), vec![(1, 7)]);
+    }
+
+    #[test]
+    fn overflow() {
+        let mut wheel = Wheel::new();
+
+        assert!(wheel.schedule(64, 0).is_err());
+        assert_eq!(wheel.schedule(64, 0), Err(Error::Overflow));
+
+        assert!(wheel.schedule(2, 1).is_ok());
+        assert!(wheel.schedule(2, 2).is_ok());
+        assert_eq!(wheel.schedule(2, 3), Err(Error::Overflow));
+
+        assert_eq!(wheel.len(), 2);
+    }
+
+    #[test]
+    fn cascade_fills_slot() {
+        let mut wheel = Wheel::new();
+
+        // both items are held by the same overflow slot - and cascade into the same slot
+        assert!(wheel.schedule(21, 1).is_ok());
+        assert!(wheel.schedule(21, 2).is_ok());
+
+        assert_eq!(run(&mut wheel, 21), vec![(21, 1), (21, 2)]);
+    }
+
+    #[test]
+    fn cancel() {
+        let mut wheel = Wheel::new();
+
+        let first = wheel.schedule(30, 1).unwrap();
+        let second = wheel.schedule(30, 2).unwrap();
+        let third = wheel.schedule(2, 3).unwrap();
+
+        run(&mut wheel, 17);
+
+        assert_eq!(wheel.cancel(first), Some(1));
+        assert_eq!(wheel.cancel(first), None);
+        assert_eq!(wheel.cancel(third), None);
+        assert_eq!(wheel.len(), 1);
+
+        assert_eq!(run(&mut wheel, 20), vec![(30, 2)]);
+        assert_eq!(wheel.cancel(second), None);
+    }
+
+    #[test]
+    fn cancel_frees_slot() {
+        let mut wheel = Wheel::new();
+
+        let first = wheel.schedule(1, 1).unwrap();
+        assert!(wheel.schedule(1, 2).is_ok());
+
+        assert_eq!(wheel.cancel(first), Some(1));
+        assert!(wheel.schedule(1, 3).is_ok());
+
+        assert_eq!(run(&mut wheel, 1), vec![(1, 2), (1, 3)]);
+    }
+}